@@ -406,6 +406,16 @@ macro_rules! gate_doc { ($($name:ident => $feature:ident)*) => {
                     let msg = "`#[doc(tuple_variadic)]` is meant for internal use only";
                     gate_feature_post!(self, rustdoc_internals, attr.span, msg);
                 }
+
+                if nested_meta.has_name(sym::no_synthetic_impls) {
+                    let msg = "`#[doc(no_synthetic_impls)]` is experimental";
+                    gate_feature_post!(self, rustdoc_internals, attr.span, msg);
+                }
+
+                if nested_meta.has_name(sym::item_order) {
+                    let msg = "`#[doc(item_order)]` is experimental";
+                    gate_feature_post!(self, rustdoc_internals, attr.span, msg);
+                }
             }
         }
 
@@ -6,22 +6,17 @@
 use rustc_hir as hir;
 use rustc_hir::def_id::DefId;
 use rustc_hir::{AsyncGeneratorKind, GeneratorKind};
-use rustc_infer::infer::TyCtxtInferExt;
-use rustc_infer::traits::ObligationCause;
 use rustc_middle::mir::tcx::PlaceTy;
 use rustc_middle::mir::{
     self, AggregateKind, BindingForm, BorrowKind, ClearCrossCrate, ConstraintCategory,
     FakeReadCause, LocalDecl, LocalInfo, LocalKind, Location, Operand, Place, PlaceRef,
     ProjectionElem, Rvalue, Statement, StatementKind, Terminator, TerminatorKind, VarBindingForm,
 };
-use rustc_middle::ty::{
-    self, subst::Subst, suggest_constraining_type_params, EarlyBinder, PredicateKind, Ty,
-};
+use rustc_middle::ty::{self, subst::Subst, suggest_bounds_satisfying_predicate, EarlyBinder, Ty};
 use rustc_mir_dataflow::move_paths::{InitKind, MoveOutIndex, MovePathIndex};
 use rustc_span::symbol::sym;
 use rustc_span::{BytePos, Span};
 use rustc_trait_selection::infer::InferCtxtExt;
-use rustc_trait_selection::traits::TraitEngineExt as _;
 
 use crate::borrow_set::TwoPhaseActivation;
 use crate::borrowck_errors;
@@ -293,7 +288,7 @@ pub(crate) fn report_use_of_moved_or_uninitialized(
                 // something that already has `Fn`-like bounds (or is a closure), so we can't
                 // restrict anyways.
             } else {
-                self.suggest_adding_copy_bounds(&mut err, ty, span);
+                self.suggest_adding_copy_bounds(&mut err, ty, &move_site_vec);
             }
 
             if needs_note {
@@ -405,63 +400,65 @@ fn suggest_adding_copy_bounds(
         &self,
         err: &mut DiagnosticBuilder<'tcx, ErrorGuaranteed>,
         ty: Ty<'tcx>,
-        span: Span,
+        move_sites: &[MoveSite],
     ) {
         let tcx = self.infcx.tcx;
-        let generics = tcx.generics_of(self.mir_def_id());
-
         let Some(hir_generics) = tcx
             .typeck_root_def_id(self.mir_def_id().to_def_id())
             .as_local()
             .and_then(|def_id| tcx.hir().get_generics(def_id))
         else { return; };
+        let Some(copy_did) = tcx.lang_items().copy_trait() else { return };
         // Try to find predicates on *generic params* that would allow copying `ty`
-        let predicates: Result<Vec<_>, _> = tcx.infer_ctxt().enter(|infcx| {
-            let mut fulfill_cx = <dyn rustc_infer::traits::TraitEngine<'_>>::new(infcx.tcx);
-
-            let copy_did = infcx.tcx.lang_items().copy_trait().unwrap();
-            let cause = ObligationCause::new(
-                span,
-                self.mir_hir_id(),
-                rustc_infer::traits::ObligationCauseCode::MiscObligation,
-            );
-            fulfill_cx.register_bound(
-                &infcx,
-                self.param_env,
-                // Erase any region vids from the type, which may not be resolved
-                infcx.tcx.erase_regions(ty),
-                copy_did,
-                cause,
-            );
-            // Select all, including ambiguous predicates
-            let errors = fulfill_cx.select_all_or_error(&infcx);
-
-            // Only emit suggestion if all required predicates are on generic
-            errors
-                .into_iter()
-                .map(|err| match err.obligation.predicate.kind().skip_binder() {
-                    PredicateKind::Trait(predicate) => match predicate.self_ty().kind() {
-                        ty::Param(param_ty) => Ok((
-                            generics.type_param(param_ty, tcx),
-                            predicate.trait_ref.print_only_trait_path().to_string(),
-                        )),
-                        _ => Err(()),
-                    },
-                    _ => Err(()),
-                })
-                .collect()
+        let trait_pred = ty::Binder::dummy(ty::TraitPredicate {
+            // Erase any region vids from the type, which may not be resolved
+            trait_ref: ty::TraitRef::new(copy_did, tcx.mk_substs_trait(tcx.erase_regions(ty), &[])),
+            constness: ty::BoundConstness::NotConst,
+            polarity: ty::ImplPolarity::Positive,
         });
+        if suggest_bounds_satisfying_predicate(tcx, hir_generics, err, self.param_env, trait_pred) {
+            self.suggest_cloning_moved_value(err, ty, move_sites);
+        }
+    }
 
-        if let Ok(predicates) = predicates {
-            suggest_constraining_type_params(
-                tcx,
-                hir_generics,
-                err,
-                predicates
-                    .iter()
-                    .map(|(param, constraint)| (param.name.as_str(), &**constraint, None)),
-            );
+    /// Suggests cloning the value where it's moved, as an alternative to the `Copy` bound, when
+    /// the type is already `Clone`.
+    fn suggest_cloning_moved_value(
+        &self,
+        err: &mut DiagnosticBuilder<'tcx, ErrorGuaranteed>,
+        ty: Ty<'tcx>,
+        move_sites: &[MoveSite],
+    ) {
+        let tcx = self.infcx.tcx;
+        let Some(clone_did) = tcx.lang_items().clone_trait() else { return };
+        let ty = tcx.erase_regions(ty);
+        if !self
+            .infcx
+            .type_implements_trait(clone_did, ty, ty::List::empty(), self.param_env)
+            .must_apply_modulo_regions()
+        {
+            return;
         }
+        let mut sugg = Vec::with_capacity(move_sites.len());
+        for move_site in move_sites {
+            let move_out = self.move_data.moves[move_site.moi];
+            let moved_place = &self.move_data.move_paths[move_out.path].place;
+            // Only the value itself can be cloned in place, not the one moved into a closure, by a
+            // pattern or by a method taking `self`.
+            let UseSpans::OtherUse(span) = self.move_spans(moved_place.as_ref(), move_out.source)
+            else {
+                return;
+            };
+            if span.from_expansion() {
+                return;
+            }
+            sugg.push((span.shrink_to_hi(), ".clone()".to_string()));
+        }
+        err.multipart_suggestion_verbose(
+            "or clone the value instead of moving it",
+            sugg,
+            Applicability::MaybeIncorrect,
+        );
     }
 
     pub(crate) fn report_move_out_while_borrowed(
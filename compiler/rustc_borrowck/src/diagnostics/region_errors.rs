@@ -723,6 +723,9 @@ fn report_general_error(
     /// LL |     fn iter_values_anon(&self) -> impl Iterator<Item=u32> + 'a {
     ///    |                                   ^^^^^^^^^^^^^^^^^^^^^^^^^^^^
     /// ```
+    ///
+    /// Without an `impl Trait` or `dyn Trait`, the `'static` lifetimes of the return type are
+    /// suggested to be replaced by a named lifetime shared with the argument.
     fn add_static_impl_trait_suggestion(
         &self,
         diag: &mut Diagnostic,
@@ -738,16 +741,10 @@ fn add_static_impl_trait_suggestion(
                 return;
             }
 
-            let fn_returns = self
-                .infcx
-                .tcx
-                .is_suitable_region(f)
-                .map(|r| self.infcx.tcx.return_type_impl_or_dyn_traits(r.def_id))
-                .unwrap_or_default();
-
-            if fn_returns.is_empty() {
+            let Some(suitable_region) = self.infcx.tcx.is_suitable_region(f) else {
                 return;
-            }
+            };
+            let fn_returns = self.infcx.tcx.return_type_impl_or_dyn_traits(suitable_region.def_id);
 
             let param = if let Some(param) = find_param_with_region(self.infcx.tcx, f, outlived_f) {
                 param
@@ -755,6 +752,19 @@ fn add_static_impl_trait_suggestion(
                 return;
             };
 
+            if fn_returns.is_empty() {
+                // Like in `fn foo(x: &u32) -> &'static u32`.
+                if !f.has_name() {
+                    nice_region_error::suggest_named_lifetime_param(
+                        self.infcx.tcx,
+                        diag,
+                        suitable_region.def_id,
+                        param.param_ty_span,
+                    );
+                }
+                return;
+            }
+
             let lifetime = if f.has_name() { fr_name.to_string() } else { "'_".to_string() };
 
             let arg = match param.param.pat.simple_ident() {
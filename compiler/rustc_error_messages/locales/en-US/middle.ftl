@@ -0,0 +1,28 @@
+middle-suggest-arbitrary-trait-bound =
+    consider {$has_where_clause ->
+        [true] extending the
+        *[false] introducing a
+    } `where` clause, but there might be an alternative better way to express this requirement
+
+middle-type-param-needs-sized = this type parameter needs to be `{$sized_constraint}`
+
+middle-type-params-declared-by-macro =
+    the type parameters are declared in the expansion of `{$macro_call}`, which would have to be changed to restrict them
+
+middle-restrict-type-params = consider restricting type parameters
+
+middle-restrict-bound-further = consider further restricting this bound
+
+middle-restrict-type = consider restricting type parameter `{$param_name}`
+
+middle-restrict-type-further = consider further restricting type parameter `{$param_name}`
+
+middle-restrict-assoc-type = consider restricting the associated type `{$param_name}`
+
+middle-restrict-self-in-method = consider further restricting `Self`
+
+middle-restriction-suggested-above =
+    restricting type parameter `{$param_name}` with `{$constraint}` as suggested above would also fix this error
+
+middle-remove-maybe-sized =
+    consider removing the `?Sized` bound to make the type parameter `Sized`
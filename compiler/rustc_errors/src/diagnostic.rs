@@ -63,6 +63,12 @@ fn into_diagnostic_arg(self) -> DiagnosticArgValue<'static> {
     }
 }
 
+impl IntoDiagnosticArg for bool {
+    fn into_diagnostic_arg(self) -> DiagnosticArgValue<'static> {
+        self.to_string().into_diagnostic_arg()
+    }
+}
+
 impl IntoDiagnosticArg for usize {
     fn into_diagnostic_arg(self) -> DiagnosticArgValue<'static> {
         DiagnosticArgValue::Number(self)
@@ -108,6 +114,10 @@ pub struct Diagnostic {
     /// If diagnostic is from Lint, custom hash function ignores notes
     /// otherwise hash is based on the all the fields
     pub is_lint: bool,
+
+    /// Data about the diagnostic for tools, as keys and values serialized to JSON, which is only
+    /// part of the JSON output.
+    tool_metadata: Vec<(Cow<'static, str>, String)>,
 }
 
 #[derive(Clone, Debug, PartialEq, Eq, Hash, Encodable, Decodable)]
@@ -193,6 +203,7 @@ pub fn new_with_code<M: Into<DiagnosticMessage>>(
             args: vec![],
             sort_span: DUMMY_SP,
             is_lint: false,
+            tool_metadata: vec![],
         }
     }
 
@@ -837,6 +848,25 @@ pub fn set_arg(
         self
     }
 
+    pub fn tool_metadata(&self) -> &[(Cow<'static, str>, String)] {
+        &self.tool_metadata
+    }
+
+    /// Adds `value` under `key` to the `tool_metadata` object of the JSON output, so that tools
+    /// can get structured data about the diagnostic without parsing its messages. The other
+    /// outputs don't show it.
+    pub fn set_tool_metadata(
+        &mut self,
+        key: impl Into<Cow<'static, str>>,
+        value: &impl serde::Serialize,
+    ) -> &mut Self {
+        let key = key.into();
+        let value = serde_json::to_string(value).expect("tool metadata can't be serialized");
+        self.tool_metadata.retain(|(existing, _)| *existing != key);
+        self.tool_metadata.push((key, value));
+        self
+    }
+
     pub fn styled_message(&self) -> &[(DiagnosticMessage, Style)] {
         &self.message
     }
@@ -216,6 +216,9 @@ struct Diagnostic {
     children: Vec<Diagnostic>,
     /// The message as rustc would render it.
     rendered: Option<String>,
+    /// Data about the diagnostic for tools, see `Diagnostic::set_tool_metadata`.
+    #[serde(skip_serializing_if = "serde_json::Map::is_empty")]
+    tool_metadata: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Serialize)]
@@ -319,6 +322,7 @@ fn from_errors_diagnostic(diag: &crate::Diagnostic, je: &JsonEmitter) -> Diagnos
                 spans: DiagnosticSpan::from_suggestion(sugg, &args, je),
                 children: vec![],
                 rendered: None,
+                tool_metadata: Default::default(),
             }
         });
 
@@ -366,6 +370,11 @@ fn flush(&mut self) -> io::Result<()> {
                 .chain(sugg)
                 .collect(),
             rendered: Some(output),
+            tool_metadata: diag
+                .tool_metadata()
+                .iter()
+                .map(|(key, value)| (key.to_string(), serde_json::from_str(value).unwrap()))
+                .collect(),
         }
     }
 
@@ -386,6 +395,7 @@ fn from_sub_diagnostic(
                 .unwrap_or_else(|| DiagnosticSpan::from_multispan(&diag.span, args, je)),
             children: vec![],
             rendered: None,
+            tool_metadata: Default::default(),
         }
     }
 }
@@ -40,8 +40,8 @@ fn flush(&mut self) -> io::Result<()> {
     }
 }
 
-/// Test the span yields correct positions in JSON.
-fn test_positions(code: &str, span: (u32, u32), expected_output: SpanTestData) {
+/// Emits the diagnostics of `emit` for a file containing `code` and returns the JSON output.
+fn json_output(code: &str, emit: impl FnOnce(&Handler)) -> String {
     rustc_span::create_default_session_globals_then(|| {
         let sm = Lrc::new(SourceMap::new(FilePathMapping::empty()));
         sm.new_source_file(Path::new("test.rs").to_owned().into(), code.to_owned());
@@ -61,20 +61,27 @@ fn test_positions(code: &str, span: (u32, u32), expected_output: SpanTestData) {
             false,
         );
 
-        let span = Span::with_root_ctxt(BytePos(span.0), BytePos(span.1));
         let handler = Handler::with_emitter(true, None, Box::new(je));
-        handler.span_err(span, "foo");
+        emit(&handler);
 
         let bytes = output.lock().unwrap();
-        let actual_output = str::from_utf8(&bytes).unwrap();
-        let actual_output: TestData = serde_json::from_str(actual_output).unwrap();
-        let spans = actual_output.spans;
-        assert_eq!(spans.len(), 1);
-
-        assert_eq!(expected_output, spans[0])
+        str::from_utf8(&bytes).unwrap().to_owned()
     })
 }
 
+/// Test the span yields correct positions in JSON.
+fn test_positions(code: &str, span: (u32, u32), expected_output: SpanTestData) {
+    let span = Span::with_root_ctxt(BytePos(span.0), BytePos(span.1));
+    let actual_output = json_output(code, |handler| {
+        handler.span_err(span, "foo");
+    });
+    let actual_output: TestData = serde_json::from_str(&actual_output).unwrap();
+    let spans = actual_output.spans;
+    assert_eq!(spans.len(), 1);
+
+    assert_eq!(expected_output, spans[0])
+}
+
 #[test]
 fn empty() {
     test_positions(
@@ -202,3 +209,21 @@ fn span_after_crlf() {
         },
     )
 }
+
+#[test]
+fn tool_metadata() {
+    let span = Span::with_root_ctxt(BytePos(0), BytePos(3));
+    let output = json_output("foo", |handler| {
+        let mut err = handler.struct_span_err(span, "foo");
+        err.set_tool_metadata("arms", &[0, 2]);
+        err.emit();
+    });
+    let output: serde_json::Value = serde_json::from_str(&output).unwrap();
+    assert_eq!(output["tool_metadata"], serde_json::json!({ "arms": [0, 2] }));
+
+    let output = json_output("foo", |handler| {
+        handler.span_err(span, "foo");
+    });
+    let output: serde_json::Value = serde_json::from_str(&output).unwrap();
+    assert!(output.get("tool_metadata").is_none());
+}
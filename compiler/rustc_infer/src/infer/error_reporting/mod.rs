@@ -67,8 +67,8 @@
 use rustc_middle::dep_graph::DepContext;
 use rustc_middle::ty::print::with_no_trimmed_paths;
 use rustc_middle::ty::{
-    self, error::TypeError, Binder, List, Region, Subst, Ty, TyCtxt, TypeFoldable,
-    TypeSuperFoldable,
+    self, error::TypeError, outlives_bound_suggestion, Binder, List, Region, Subst, Ty, TyCtxt,
+    TypeFoldable, TypeSuperFoldable,
 };
 use rustc_span::{sym, symbol::kw, BytePos, DesugaringKind, Pos, Span};
 use rustc_target::spec::abi;
@@ -1579,7 +1579,8 @@ enum Mismatch<'a> {
                         expected: ty::Term::Ty(expected),
                         found: ty::Term::Ty(found),
                     }) => {
-                        let is_simple_err = expected.is_simple_text() && found.is_simple_text();
+                        let is_simple_err =
+                            expected.is_simple_text(self.tcx) && found.is_simple_text(self.tcx);
                         OpaqueTypesVisitor::visit_expected_found(self.tcx, expected, found, span)
                             .report(diag);
 
@@ -1666,7 +1667,8 @@ enum Mismatch<'a> {
                         (true, _) => format!(" ({})", ty.sort_string(self.tcx)),
                         (false, _) => "".to_string(),
                     };
-                    if !(values.expected.is_simple_text() && values.found.is_simple_text())
+                    if !(values.expected.is_simple_text(self.tcx)
+                        && values.found.is_simple_text(self.tcx))
                         || (exp_found.map_or(false, |ef| {
                             // This happens when the type error is a subset of the expectation,
                             // like when you have two references but one is `usize` and the other
@@ -2318,30 +2320,12 @@ pub fn construct_generic_bound_failure(
             _ => span,
         };
 
-        // type_param_span is (span, has_bounds)
-        let type_param_span = match (generics, bound_kind) {
+        let type_param = match (generics, bound_kind) {
             (Some((_, ref generics, _)), GenericKind::Param(ref param)) => {
                 // Account for the case where `param` corresponds to `Self`,
                 // which doesn't have the expected type argument.
                 if !(generics.has_self && param.index == 0) {
-                    let type_param = generics.type_param(param, self.tcx);
-                    type_param.def_id.as_local().map(|def_id| {
-                        // Get the `hir::Param` to verify whether it already has any bounds.
-                        // We do this to avoid suggesting code that ends up as `T: 'a'b`,
-                        // instead we suggest `T: 'a + 'b` in that case.
-                        let hir_id = self.tcx.hir().local_def_id_to_hir_id(def_id);
-                        let ast_generics = self.tcx.hir().get_generics(hir_id.owner);
-                        let bounds =
-                            ast_generics.and_then(|g| g.bounds_span_for_suggestions(def_id));
-                        // `sp` only covers `T`, change it so that it covers
-                        // `T:` when appropriate
-                        if let Some(span) = bounds {
-                            (span, true)
-                        } else {
-                            let sp = self.tcx.def_span(def_id);
-                            (sp.shrink_to_hi(), false)
-                        }
-                    })
+                    generics.type_param(param, self.tcx).def_id.as_local()
                 } else {
                     None
                 }
@@ -2395,15 +2379,16 @@ pub fn construct_generic_bound_failure(
         }
 
         fn binding_suggestion<'tcx, S: fmt::Display>(
+            tcx: TyCtxt<'tcx>,
             err: &mut Diagnostic,
-            type_param_span: Option<(Span, bool)>,
+            type_param: Option<LocalDefId>,
             bound_kind: GenericKind<'tcx>,
             sub: S,
         ) {
             let msg = "consider adding an explicit lifetime bound";
-            if let Some((sp, has_lifetimes)) = type_param_span {
-                let suggestion =
-                    if has_lifetimes { format!(" + {}", sub) } else { format!(": {}", sub) };
+            let bound_suggestion = type_param
+                .and_then(|def_id| outlives_bound_suggestion(tcx, def_id, &sub.to_string()));
+            if let Some((sp, suggestion)) = bound_suggestion {
                 err.span_suggestion_verbose(
                     sp,
                     &format!("{}...", msg),
@@ -2416,25 +2401,21 @@ fn binding_suggestion<'tcx, S: fmt::Display>(
             }
         }
 
-        let new_binding_suggestion =
-            |err: &mut Diagnostic, type_param_span: Option<(Span, bool)>| {
-                let msg = "consider introducing an explicit lifetime bound";
-                if let Some((sp, has_lifetimes)) = type_param_span {
-                    let suggestion = if has_lifetimes {
-                        format!(" + {}", new_lt)
-                    } else {
-                        format!(": {}", new_lt)
-                    };
-                    let mut sugg =
-                        vec![(sp, suggestion), (span.shrink_to_hi(), format!(" + {}", new_lt))];
-                    if let Some(lt) = add_lt_sugg {
-                        sugg.push(lt);
-                        sugg.rotate_right(1);
-                    }
-                    // `MaybeIncorrect` due to issue #41966.
-                    err.multipart_suggestion(msg, sugg, Applicability::MaybeIncorrect);
+        let new_binding_suggestion = |err: &mut Diagnostic, type_param: Option<LocalDefId>| {
+            let msg = "consider introducing an explicit lifetime bound";
+            let bound_suggestion =
+                type_param.and_then(|def_id| outlives_bound_suggestion(self.tcx, def_id, &new_lt));
+            if let Some((sp, suggestion)) = bound_suggestion {
+                let mut sugg =
+                    vec![(sp, suggestion), (span.shrink_to_hi(), format!(" + {}", new_lt))];
+                if let Some(lt) = add_lt_sugg {
+                    sugg.push(lt);
+                    sugg.rotate_right(1);
                 }
-            };
+                // `MaybeIncorrect` due to issue #41966.
+                err.multipart_suggestion(msg, sugg, Applicability::MaybeIncorrect);
+            }
+        };
 
         #[derive(Debug)]
         enum SubOrigin<'hir> {
@@ -2537,7 +2518,7 @@ enum SubOrigin<'hir> {
                 // for the bound is not suitable for suggestions when `-Zverbose` is set because it
                 // uses `Debug` output, so we handle it specially here so that suggestions are
                 // always correct.
-                binding_suggestion(&mut err, type_param_span, bound_kind, name);
+                binding_suggestion(self.tcx, &mut err, type_param, bound_kind, name);
                 err
             }
 
@@ -2550,7 +2531,7 @@ enum SubOrigin<'hir> {
                     "{} may not live long enough",
                     labeled_user_string
                 );
-                binding_suggestion(&mut err, type_param_span, bound_kind, "'static");
+                binding_suggestion(self.tcx, &mut err, type_param, bound_kind, "'static");
                 err
             }
 
@@ -2581,10 +2562,10 @@ enum SubOrigin<'hir> {
                         // suggest:
                         // fn get_later<'a, G: 'a, T>(g: G, dest: &mut T) -> impl FnOnce() + '_ + 'a
                         ty::Closure(_, _substs) | ty::Opaque(_, _substs) if return_impl_trait => {
-                            new_binding_suggestion(&mut err, type_param_span);
+                            new_binding_suggestion(&mut err, type_param);
                         }
                         _ => {
-                            binding_suggestion(&mut err, type_param_span, bound_kind, new_lt);
+                            binding_suggestion(self.tcx, &mut err, type_param, bound_kind, new_lt);
                         }
                     }
                 }
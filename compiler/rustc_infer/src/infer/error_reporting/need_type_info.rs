@@ -1,6 +1,10 @@
 use crate::infer::type_variable::TypeVariableOriginKind;
 use crate::infer::InferCtxt;
-use rustc_errors::{pluralize, struct_span_err, Applicability, DiagnosticBuilder, ErrorGuaranteed};
+use rustc_data_structures::fx::FxIndexSet;
+use rustc_errors::{
+    pluralize, struct_span_err, Applicability, Diagnostic, DiagnosticBuilder, ErrorGuaranteed,
+    MultiSpan,
+};
 use rustc_hir as hir;
 use rustc_hir::def::Res;
 use rustc_hir::def::{CtorOf, DefKind, Namespace};
@@ -12,7 +16,7 @@
 use rustc_middle::ty::adjustment::{Adjust, Adjustment, AutoBorrow, AutoBorrowMutability};
 use rustc_middle::ty::print::{FmtPrinter, PrettyPrinter, Print, Printer};
 use rustc_middle::ty::subst::{GenericArg, GenericArgKind, Subst, SubstsRef};
-use rustc_middle::ty::{self, DefIdTree, InferConst};
+use rustc_middle::ty::{self, DefIdTree, InferConst, InferVar, InferVarCollector};
 use rustc_middle::ty::{Ty, TyCtxt, TypeckResults};
 use rustc_span::symbol::{kw, Ident};
 use rustc_span::{BytePos, Span};
@@ -295,6 +299,40 @@ pub fn extract_inference_diagnostics_data(
         }
     }
 
+    /// Returns the inference variables in `ty`, along with the span they were created for when
+    /// it's known, to point at each part of `ty` which couldn't be inferred.
+    pub fn infer_var_origins(&self, ty: Ty<'tcx>) -> Vec<(InferVar<'tcx>, Option<Span>)> {
+        let vars = InferVarCollector::collect(self.resolve_vars_if_possible(ty));
+        let mut inner = self.inner.borrow_mut();
+        vars.into_iter()
+            .map(|var| {
+                let span = match var {
+                    InferVar::Ty(ty::TyVar(vid)) => inner.type_variables().var_origin(vid).span,
+                    InferVar::Const(InferConst::Var(vid)) => {
+                        inner.const_unification_table().probe_value(vid).origin.span
+                    }
+                    _ => return (var, None),
+                };
+                (var, Some(span).filter(|span| !span.is_dummy()))
+            })
+            .collect()
+    }
+
+    /// Points at each of the parts of `ty` which couldn't be inferred, when there are several of
+    /// them, as the suggested annotation has to specify all of them.
+    fn note_infer_var_origins(&self, err: &mut Diagnostic, ty: Ty<'tcx>) {
+        let spans: FxIndexSet<Span> =
+            self.infer_var_origins(ty).into_iter().filter_map(|(_, span)| span).collect();
+        if spans.len() < 2 {
+            return;
+        }
+        let mut multi_span = MultiSpan::from_spans(spans.iter().copied().collect());
+        for span in spans {
+            multi_span.push_span_label(span, "cannot infer this type");
+        }
+        err.span_note(multi_span, "several parts of this type couldn't be inferred");
+    }
+
     /// Used as a fallback in [InferCtxt::emit_inference_failure_err]
     /// in case we weren't able to get a better error.
     fn bad_inference_failure_err(
@@ -368,6 +406,7 @@ pub fn emit_inference_failure_err(
                     format!(": {}", ty_to_string(self, ty)),
                     Applicability::HasPlaceholders,
                 );
+                self.note_infer_var_origins(&mut err, ty);
             }
             InferSourceKind::ClosureArg { insert_span, ty } => {
                 err.span_suggestion_verbose(
@@ -379,6 +418,7 @@ pub fn emit_inference_failure_err(
                     format!(": {}", ty_to_string(self, ty)),
                     Applicability::HasPlaceholders,
                 );
+                self.note_infer_var_origins(&mut err, ty);
             }
             InferSourceKind::GenericArg {
                 insert_span,
@@ -11,7 +11,7 @@
 use rustc_errors::{struct_span_err, Applicability, Diagnostic, ErrorGuaranteed};
 use rustc_hir as hir;
 use rustc_hir::{GenericParamKind, Ty};
-use rustc_middle::ty::Region;
+use rustc_middle::ty::{new_lifetime_param_suggestion, Region};
 use rustc_span::symbol::kw;
 
 impl<'a, 'tcx> NiceRegionError<'a, 'tcx> {
@@ -214,14 +214,7 @@ pub fn suggest_adding_lifetime_params<'tcx>(
         vec![make_suggestion(lifetime_sub.span), make_suggestion(lifetime_sup.span)];
 
     if introduce_new {
-        let new_param_suggestion =
-            if let Some(first) = generics.params.iter().find(|p| !p.name.ident().span.is_empty()) {
-                (first.span.shrink_to_lo(), format!("{}, ", suggestion_param_name))
-            } else {
-                (generics.span, format!("<{}>", suggestion_param_name))
-            };
-
-        suggestions.push(new_param_suggestion);
+        suggestions.push(new_lifetime_param_suggestion(generics, &suggestion_param_name));
     }
 
     let mut sugg = String::from("consider introducing a named lifetime parameter");
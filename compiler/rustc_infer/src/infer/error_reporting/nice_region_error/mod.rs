@@ -16,7 +16,10 @@
 
 pub use different_lifetimes::suggest_adding_lifetime_params;
 pub use find_anon_type::find_anon_type;
-pub use static_impl_trait::{suggest_new_region_bound, HirTraitObjectVisitor, TraitObjectVisitor};
+pub use static_impl_trait::{
+    suggest_named_lifetime_param, suggest_new_region_bound, HirTraitObjectVisitor,
+    TraitObjectVisitor,
+};
 pub use util::find_param_with_region;
 
 impl<'cx, 'tcx> InferCtxt<'cx, 'tcx> {
@@ -6,7 +6,7 @@
 use crate::traits::{ObligationCauseCode, UnifyReceiverContext};
 use rustc_data_structures::stable_set::FxHashSet;
 use rustc_errors::{struct_span_err, Applicability, Diagnostic, ErrorGuaranteed, MultiSpan};
-use rustc_hir::def_id::DefId;
+use rustc_hir::def_id::{DefId, LocalDefId};
 use rustc_hir::intravisit::{walk_ty, Visitor};
 use rustc_hir::{self as hir, GenericBound, Item, ItemKind, Lifetime, LifetimeName, Node, TyKind};
 use rustc_middle::ty::{
@@ -266,6 +266,9 @@ pub(super) fn try_report_static_impl_trait(&self) -> Option<ErrorGuaranteed> {
             None => "the argument".to_string(),
         };
         let captures = format!("captures data from {}", arg);
+        if fn_returns.is_empty() && !sup_r.has_name() {
+            suggest_named_lifetime_param(tcx, &mut err, anon_reg_sup.def_id, param.param_ty_span);
+        }
         suggest_new_region_bound(
             tcx,
             &mut err,
@@ -281,6 +284,55 @@ pub(super) fn try_report_static_impl_trait(&self) -> Option<ErrorGuaranteed> {
     }
 }
 
+/// Suggests introducing a named lifetime parameter in the function `fn_def_id`, for the argument
+/// whose type is at `param_ty_span` when its lifetime is anonymous, and in place of the `'static`
+/// and implicit trait object lifetimes of the return type, so that the returned value can borrow
+/// from the argument.
+///
+/// ```text
+/// help: consider introducing a named lifetime parameter
+///    |
+/// LL | fn foo<'a>(x: &'a u32) -> &'a u32 {
+///    |       ++++     ++          ~~
+/// ```
+pub fn suggest_named_lifetime_param(
+    tcx: TyCtxt<'_>,
+    err: &mut Diagnostic,
+    fn_def_id: LocalDefId,
+    param_ty_span: Span,
+) {
+    let hir_id = tcx.hir().local_def_id_to_hir_id(fn_def_id);
+    let (Some(fn_decl), Some(generics)) =
+        (tcx.hir().fn_decl_by_hir_id(hir_id), tcx.hir().get_generics(fn_def_id))
+    else {
+        return;
+    };
+    let hir::FnRetTy::Return(ret_ty) = fn_decl.output else {
+        return;
+    };
+    let Some(hir::Ty { kind: TyKind::Rptr(lifetime, _), .. }) =
+        fn_decl.inputs.iter().find(|ty| ty.span == param_ty_span)
+    else {
+        return;
+    };
+    if !lifetime.name.is_anonymous() {
+        return;
+    }
+    let mut v = StaticLifetimeVisitor(vec![], tcx.hir());
+    v.visit_ty(ret_ty);
+    if v.0.is_empty() {
+        return;
+    }
+    v.0.push(lifetime.span);
+    if let Some(suggestion) = v.named_lifetime_suggestion(tcx, generics) {
+        err.multipart_suggestion_verbose(
+            "consider introducing a named lifetime parameter",
+            suggestion,
+            Applicability::MaybeIncorrect,
+        );
+    }
+}
+
 pub fn suggest_new_region_bound(
     tcx: TyCtxt<'_>,
     err: &mut Diagnostic,
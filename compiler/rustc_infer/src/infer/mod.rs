@@ -281,6 +281,10 @@ pub struct InferCtxt<'a, 'tcx> {
 
     pub reported_closure_mismatch: RefCell<FxHashSet<(Span, Option<Span>)>>,
 
+    /// The bounds, as the type param and the printed trait path, which were suggested by a trait
+    /// error, so that the other errors they would fix don't suggest them again.
+    pub suggested_param_constraints: RefCell<FxHashSet<(DefId, String)>>,
+
     /// When an error occurs, we want to avoid reporting "derived"
     /// errors that are due to this original failure. Normally, we
     /// handle this with the `err_count_on_creation` count, which
@@ -597,6 +601,7 @@ pub fn enter<R>(&mut self, f: impl for<'a> FnOnce(InferCtxt<'a, 'tcx>) -> R) ->
             evaluation_cache: Default::default(),
             reported_trait_errors: Default::default(),
             reported_closure_mismatch: Default::default(),
+            suggested_param_constraints: Default::default(),
             tainted_by_errors_flag: Cell::new(false),
             err_count_on_creation: tcx.sess.err_count(),
             in_snapshot: Cell::new(false),
@@ -233,7 +233,7 @@ fn elaborate(&mut self, obligation: &PredicateObligation<'tcx>) {
                             }
 
                             Component::Param(p) => {
-                                let ty = tcx.mk_ty_param(p.index, p.name);
+                                let ty = tcx.mk_ty(ty::Param(p));
                                 Some(ty::PredicateKind::TypeOutlives(ty::OutlivesPredicate(
                                     ty, r_min,
                                 )))
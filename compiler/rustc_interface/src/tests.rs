@@ -646,6 +646,7 @@ macro_rules! untracked {
     untracked!(assert_incr_state, Some(String::from("loaded")));
     untracked!(deduplicate_diagnostics, false);
     untracked!(dep_tasks, true);
+    untracked!(diagnostic_arg_type_length_limit, 4);
     untracked!(dlltool, Some(PathBuf::from("custom_dlltool.exe")));
     untracked!(dont_buffer_diagnostics, true);
     untracked!(dump_dep_graph, true);
@@ -696,6 +697,7 @@ macro_rules! untracked {
     untracked!(time_passes, true);
     untracked!(trace_macros, true);
     untracked!(trim_diagnostic_paths, false);
+    untracked!(type_label_length, Some(80));
     untracked!(ui_testing, true);
     untracked!(unpretty, Some("expanded".to_string()));
     untracked!(unstable_options, true);
@@ -713,6 +715,7 @@ macro_rules! tracked {
 
     // Make sure that changing a [TRACKED] option changes the hash.
     // This list is in alphabetical order.
+    tracked!(allow_doc_primitive, true);
     tracked!(allow_features, Some(vec![String::from("lang_items")]));
     tracked!(always_encode_mir, true);
     tracked!(asm_comments, true);
@@ -529,6 +529,10 @@ pub fn crate_source_untracked(&self, cnum: CrateNum) -> Lrc<CrateSource> {
         self.get_crate_data(cnum).source.clone()
     }
 
+    pub fn crate_dependencies_untracked(&self, cnum: CrateNum) -> Vec<CrateNum> {
+        self.get_crate_data(cnum).dependencies().clone()
+    }
+
     pub fn get_span_untracked(&self, def_id: DefId, sess: &Session) -> Span {
         self.get_crate_data(def_id.krate).get_span(def_id.index, sess)
     }
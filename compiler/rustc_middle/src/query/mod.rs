@@ -1947,6 +1947,20 @@
         }
     }
 
+    /// The bounds on type params and their associated types which, added to the param env, make
+    /// the trait predicate hold, found by asking the trait solver which nested obligations fail.
+    /// These are the bounds to suggest when the predicate doesn't hold, like `T: Clone` for
+    /// `Vec<T>: Clone`.
+    ///
+    /// Returns `None` when a failing obligation isn't about a type param or one of its associated
+    /// types, so that no bound can make the predicate hold. The predicate must not contain
+    /// inference variables.
+    query bounds_satisfying_predicate(
+        key: ty::ParamEnvAnd<'tcx, ty::PolyTraitPredicate<'tcx>>
+    ) -> Option<&'tcx ty::List<ty::Predicate<'tcx>>> {
+        desc { "computing the bounds which would make `{}` hold", key.value }
+    }
+
     query method_autoderef_steps(
         goal: CanonicalTyGoal<'tcx>
     ) -> MethodAutoderefStepsResult<'tcx> {
@@ -1,48 +1,74 @@
 //! Diagnostics related methods for `Ty`.
 
+use std::borrow::Cow;
 use std::ops::ControlFlow;
 
+use crate::ty::print::{FmtPrinter, Print};
+use crate::ty::subst::{Subst, SubstsRef};
 use crate::ty::{
-    fold::TypeFoldable, Const, ConstKind, DefIdTree, ExistentialPredicate, InferTy,
-    PolyTraitPredicate, Ty, TyCtxt, TypeSuperFoldable, TypeVisitor,
+    self,
+    fold::{FallibleTypeFolder, TypeFoldable},
+    Const, ConstKind, DefIdTree, ExistentialPredicate, InferTy, PolyTraitPredicate, Ty, TyCtxt,
+    TypeSuperFoldable, TypeVisitor,
 };
 
-use rustc_data_structures::fx::FxHashMap;
-use rustc_errors::{Applicability, Diagnostic, DiagnosticArgValue, IntoDiagnosticArg};
+use rustc_data_structures::fx::{FxHashSet, FxIndexMap, FxIndexSet};
+use rustc_errors::{
+    fluent, Applicability, Diagnostic, DiagnosticArgValue, DiagnosticMessage, IntoDiagnosticArg,
+};
 use rustc_hir as hir;
-use rustc_hir::def_id::DefId;
+use rustc_hir::def::{DefKind, Namespace};
+use rustc_hir::def_id::{DefId, LocalDefId};
 use rustc_hir::WherePredicate;
-use rustc_span::Span;
+use rustc_session::Limit;
+use rustc_span::{Span, Symbol};
 use rustc_type_ir::sty::TyKind::*;
 
-impl<'tcx> IntoDiagnosticArg for Ty<'tcx> {
-    fn into_diagnostic_arg(self) -> DiagnosticArgValue<'static> {
-        format!("{}", self).into_diagnostic_arg()
-    }
+macro_rules! into_diagnostic_arg_with_type_length_limit {
+    ($($ty:ty),+ $(,)?) => {
+        $(impl<'tcx> IntoDiagnosticArg for $ty {
+            fn into_diagnostic_arg(self) -> DiagnosticArgValue<'static> {
+                ty::tls::with(|tcx| {
+                    // Huge types are cut short with `…`, so that they don't make the message
+                    // unreadable.
+                    let limit = tcx.sess.opts.debugging_opts.diagnostic_arg_type_length_limit;
+                    let limit = Limit(limit);
+                    let printer = FmtPrinter::new_with_limit(tcx, Namespace::TypeNS, limit);
+                    tcx.lift(self)
+                        .expect("could not lift for printing")
+                        .print(printer)
+                        .expect("could not print diagnostic argument")
+                        .into_truncated_buffer()
+                        .into_diagnostic_arg()
+                })
+            }
+        })+
+    };
 }
 
-impl<'tcx> Ty<'tcx> {
-    /// Similar to `Ty::is_primitive`, but also considers inferred numeric values to be primitive.
-    pub fn is_primitive_ty(self) -> bool {
-        matches!(
-            self.kind(),
-            Bool | Char
-                | Str
-                | Int(_)
-                | Uint(_)
-                | Float(_)
-                | Infer(
-                    InferTy::IntVar(_)
-                        | InferTy::FloatVar(_)
-                        | InferTy::FreshIntTy(_)
-                        | InferTy::FreshFloatTy(_)
-                )
-        )
-    }
+into_diagnostic_arg_with_type_length_limit! {
+    Ty<'tcx>,
+    ty::PolyTraitRef<'tcx>,
+    ty::Predicate<'tcx>,
+    ty::GenericArg<'tcx>,
+}
 
-    /// Whether the type is succinctly representable as a type instead of just referred to with a
-    /// description in error messages. This is used in the main error message.
-    pub fn is_simple_ty(self) -> bool {
+/// How a type is referred to in error messages, from the most to the least succinct.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum TyDiagnosticClass {
+    /// A primitive type, including an inferred integer or float.
+    Primitive,
+    /// A type built from primitive types, like `()`, `&str` or `[u8; 4]`, which is always printed.
+    Simple,
+    /// An ADT or a reference to one, which is printed when its path is short enough.
+    SimpleNominal,
+    /// A type which is described instead of printed, like "tuple" or "reference".
+    Complex,
+}
+
+impl<'tcx> Ty<'tcx> {
+    /// Classifies the type for deciding whether error messages print it or describe it.
+    pub fn diagnostic_class(self) -> TyDiagnosticClass {
         match self.kind() {
             Bool
             | Char
@@ -55,27 +81,66 @@ pub fn is_simple_ty(self) -> bool {
                 | InferTy::FloatVar(_)
                 | InferTy::FreshIntTy(_)
                 | InferTy::FreshFloatTy(_),
-            ) => true,
-            Ref(_, x, _) | Array(x, _) | Slice(x) => x.peel_refs().is_simple_ty(),
-            Tuple(tys) if tys.is_empty() => true,
-            _ => false,
+            ) => TyDiagnosticClass::Primitive,
+            Tuple(tys) if tys.is_empty() => TyDiagnosticClass::Simple,
+            Ref(_, ty, _) => match ty.peel_refs().diagnostic_class() {
+                TyDiagnosticClass::Primitive | TyDiagnosticClass::Simple => {
+                    TyDiagnosticClass::Simple
+                }
+                TyDiagnosticClass::SimpleNominal => TyDiagnosticClass::SimpleNominal,
+                _ => TyDiagnosticClass::Complex,
+            },
+            Array(ty, _) | Slice(ty) => {
+                if ty.peel_refs().diagnostic_class() <= TyDiagnosticClass::Simple {
+                    TyDiagnosticClass::Simple
+                } else {
+                    TyDiagnosticClass::Complex
+                }
+            }
+            Adt(..) => TyDiagnosticClass::SimpleNominal,
+            _ => TyDiagnosticClass::Complex,
         }
     }
 
+    /// Similar to `Ty::is_primitive`, but also considers inferred numeric values to be primitive.
+    pub fn is_primitive_ty(self) -> bool {
+        self.diagnostic_class() == TyDiagnosticClass::Primitive
+    }
+
+    /// Whether the type is succinctly representable as a type instead of just referred to with a
+    /// description in error messages. This is used in the main error message.
+    pub fn is_simple_ty(self) -> bool {
+        self.diagnostic_class() <= TyDiagnosticClass::Simple
+    }
+
     /// Whether the type is succinctly representable as a type instead of just referred to with a
     /// description in error messages. This is used in the primary span label. Beyond what
-    /// `is_simple_ty` includes, it also accepts ADTs with no type arguments and references to
-    /// ADTs with no type arguments.
-    pub fn is_simple_text(self) -> bool {
-        match self.kind() {
-            Adt(_, substs) => substs.non_erasable_generics().next().is_none(),
-            Ref(_, ty, _) => ty.is_simple_text(),
-            _ => self.is_simple_ty(),
+    /// `is_simple_ty` includes, it also accepts ADTs and references to ADTs with no type
+    /// arguments, or with `-Z type-label-length`, the ones printed in at most that many
+    /// characters.
+    pub fn is_simple_text(self, tcx: TyCtxt<'tcx>) -> bool {
+        match self.diagnostic_class() {
+            TyDiagnosticClass::Primitive | TyDiagnosticClass::Simple => true,
+            TyDiagnosticClass::SimpleNominal => {
+                let adt = self.peel_refs();
+                let Some(max_len) = tcx.sess.opts.debugging_opts.type_label_length else {
+                    return match adt.kind() {
+                        Adt(_, substs) => substs.non_erasable_generics().next().is_none(),
+                        _ => false,
+                    };
+                };
+                // Each type takes at least one character, so there's no need to print more types
+                // than that to know the ADT is too long.
+                let printer = FmtPrinter::new_with_limit(tcx, Namespace::TypeNS, Limit(max_len));
+                adt.print(printer)
+                    .map_or(false, |cx| !cx.is_truncated() && cx.into_buffer().len() <= max_len)
+            }
+            TyDiagnosticClass::Complex => false,
         }
     }
 }
 
-pub trait IsSuggestable<'tcx> {
+pub trait IsSuggestable<'tcx>: Sized {
     /// Whether this makes sense to suggest in a diagnostic.
     ///
     /// We filter out certain types and constants since they don't provide
@@ -83,6 +148,15 @@ pub trait IsSuggestable<'tcx> {
     /// nonsense, such as region vars, since those render as `'_` and are
     /// usually okay to reinterpret as elided lifetimes.
     fn is_suggestable(self, tcx: TyCtxt<'tcx>) -> bool;
+
+    /// Like `is_suggestable`, but tries to turn what can't be suggested into something which
+    /// can, rather than giving up: type and const variables are printed as `_`, unnameable
+    /// regions are erased and function items become function pointers. Returns `None` if there's
+    /// something left that can't be written, like a closure, a generator or an integer variable.
+    ///
+    /// Inference variables aren't allowed everywhere, so this should only be used for
+    /// suggestions where `_` is fine, or on types without inference variables.
+    fn make_suggestable(self, tcx: TyCtxt<'tcx>) -> Option<Self>;
 }
 
 impl<'tcx, T> IsSuggestable<'tcx> for T
@@ -92,6 +166,10 @@ impl<'tcx, T> IsSuggestable<'tcx> for T
     fn is_suggestable(self, tcx: TyCtxt<'tcx>) -> bool {
         self.visit_with(&mut IsSuggestableVisitor { tcx }).is_continue()
     }
+
+    fn make_suggestable(self, tcx: TyCtxt<'tcx>) -> Option<Self> {
+        self.try_fold_with(&mut MakeSuggestableFolder { tcx }).ok()
+    }
 }
 
 pub fn suggest_arbitrary_trait_bound<'tcx>(
@@ -104,8 +182,8 @@ pub fn suggest_arbitrary_trait_bound<'tcx>(
         return false;
     }
 
-    let param_name = trait_pred.skip_binder().self_ty().to_string();
-    let constraint = trait_pred.print_modifiers_and_trait_path().to_string();
+    let self_ty = trait_pred.skip_binder().self_ty();
+    let param_name = self_ty.to_string();
     let param = generics.params.iter().find(|p| p.name.ident().as_str() == param_name);
 
     // Skip, there is a param named Self
@@ -113,25 +191,57 @@ pub fn suggest_arbitrary_trait_bound<'tcx>(
         return false;
     }
 
+    // When the self type mentions the bound lifetimes of a higher-ranked predicate, the binder
+    // has to be in front of the whole predicate, as in `for<'a> &'a T: Foo`, rather than in front
+    // of the trait, as in `T: for<'a> Foo<'a>`.
+    let predicate = if self_ty.has_escaping_bound_vars() {
+        trait_pred.to_string()
+    } else {
+        format!("{}: {}", param_name, trait_pred.print_modifiers_and_trait_path())
+    };
+
     // Suggest a where clause bound for a non-type parameter.
+    err.set_arg("has_where_clause", !generics.where_clause_span.is_empty());
     err.span_suggestion_verbose(
         generics.tail_span_for_predicate_suggestion(),
-        &format!(
-            "consider {} `where` clause, but there might be an alternative better way to express \
-             this requirement",
-            if generics.where_clause_span.is_empty() { "introducing a" } else { "extending the" },
-        ),
-        format!("{} {}: {}", generics.add_where_or_trailing_comma(), param_name, constraint),
+        fluent::middle::suggest_arbitrary_trait_bound,
+        format!("{} {}", generics.add_where_or_trailing_comma(), predicate),
         Applicability::MaybeIncorrect,
     );
     true
 }
 
+/// Suggest restricting `Self` in the `where` clause of a single trait method, like
+/// `where Self: Sized` for a method which takes `self` by value, rather than in the trait, which
+/// would keep it from being used as a trait object.
+///
+/// Returns `false` if the `where` clause of the method was written by a macro.
+pub fn suggest_restricting_self_in_method(
+    generics: &hir::Generics<'_>,
+    err: &mut Diagnostic,
+    constraint: &str,
+) -> bool {
+    if generics.where_clause_span.from_expansion()
+        || generics.where_clause_span.desugaring_kind().is_some()
+    {
+        return false;
+    }
+    err.span_suggestion_verbose(
+        generics.tail_span_for_predicate_suggestion(),
+        fluent::middle::restrict_self_in_method,
+        format!("{} Self: {}", generics.add_where_or_trailing_comma(), constraint),
+        Applicability::MachineApplicable,
+    );
+    true
+}
+
 #[derive(Debug)]
 enum SuggestChangingConstraintsMessage<'a> {
     RestrictBoundFurther,
     RestrictType { ty: &'a str },
     RestrictTypeFurther { ty: &'a str },
+    RestrictAssocType { ty: &'a str },
+    RestrictTypeParams,
     RemovingQSized,
 }
 
@@ -184,28 +294,237 @@ pub fn suggest_constraining_type_param(
         tcx,
         generics,
         err,
-        [(param_name, constraint, def_id)].into_iter(),
+        [(ConstrainedTy::Param(param_name), constraint, def_id)].into_iter(),
     )
 }
 
-/// Suggest restricting a type param with a new bound.
-pub fn suggest_constraining_type_params<'a>(
-    tcx: TyCtxt<'_>,
+/// Suggest restricting type params with the bounds which make `trait_pred` hold, as found by the
+/// trait solver, like `T: Clone` for `Vec<T>: Clone`.
+pub fn suggest_bounds_satisfying_predicate<'tcx>(
+    tcx: TyCtxt<'tcx>,
     generics: &hir::Generics<'_>,
     err: &mut Diagnostic,
-    param_names_and_constraints: impl Iterator<Item = (&'a str, &'a str, Option<DefId>)>,
+    param_env: ty::ParamEnv<'tcx>,
+    trait_pred: PolyTraitPredicate<'tcx>,
 ) -> bool {
-    let mut grouped = FxHashMap::default();
-    param_names_and_constraints.for_each(|(param_name, constraint, def_id)| {
-        grouped.entry(param_name).or_insert(Vec::new()).push((constraint, def_id))
-    });
+    if trait_pred.needs_infer() {
+        return false;
+    }
+    let Some(bounds) = tcx.bounds_satisfying_predicate(param_env.and(trait_pred)) else {
+        return false;
+    };
+    let mut constraints = Vec::new();
+    for bound in bounds {
+        let Some(bound) = bound.to_opt_poly_trait_pred() else { return false };
+        if bound.skip_binder().self_ty().has_escaping_bound_vars() || !bound.is_suggestable(tcx) {
+            return false;
+        }
+        let constrained_ty = match bound.skip_binder().self_ty().kind() {
+            Param(param) => ConstrainedTy::Param(param.name.as_str()),
+            Projection(projection) => ConstrainedTy::Projection(*projection),
+            _ => return false,
+        };
+        constraints.push((
+            constrained_ty,
+            bound.print_modifiers_and_trait_path().to_string(),
+            bound.def_id(),
+        ));
+    }
+    !constraints.is_empty()
+        && suggest_constraining_type_params(
+            tcx,
+            generics,
+            err,
+            constraints.iter().map(|(constrained_ty, constraint, def_id)| {
+                (*constrained_ty, constraint.as_str(), Some(*def_id))
+            }),
+        )
+}
+
+/// A type which `suggest_constraining_type_params` restricts with new bounds.
+#[derive(Clone, Copy, Debug)]
+pub enum ConstrainedTy<'a, 'tcx> {
+    /// A type param, by name.
+    Param(&'a str),
+    /// An associated type of a type param, like `<T as Iterator>::Item`.
+    Projection(ty::ProjectionTy<'tcx>),
+}
+
+/// What `type_param_constraint_suggestions` groups the new bounds by.
+#[derive(PartialEq, Eq, Hash)]
+enum BoundedTy {
+    Param(Symbol),
+    /// An associated type which is restricted in a `where` clause, as printed there.
+    Projection(String),
+}
+
+/// A suggestion to change the bounds of a type param, computed by
+/// `type_param_constraint_suggestions`.
+#[derive(Clone, Debug)]
+pub struct ConstraintSuggestion {
+    pub span: Span,
+    /// The code replacing `span`, which is empty to remove a bound.
+    pub code: String,
+    /// The message to use when this is the only suggestion, which can refer to `param_name`.
+    pub message: DiagnosticMessage,
+    /// The type param or associated type which is restricted, if any.
+    pub param_name: Option<String>,
+    pub applicability: Applicability,
+}
+
+/// Suggest restricting type params with new bounds.
+///
+/// Returns `false` if a param isn't declared in `generics`. The params of an impl or trait, and
+/// their `?Sized` bounds, aren't in the generics of its associated functions and types, which is
+/// why `suggest_restricting_param_bound` in trait selection tries again with the generics of each
+/// parent item of the body.
+///
+/// When the generics were written by a macro, there's only a note naming the macro, unless the
+/// code to change was passed to the macro.
+pub fn suggest_constraining_type_params<'a, 'tcx>(
+    tcx: TyCtxt<'tcx>,
+    generics: &hir::Generics<'_>,
+    err: &mut Diagnostic,
+    tys_and_constraints: impl Iterator<Item = (ConstrainedTy<'a, 'tcx>, &'a str, Option<DefId>)>,
+) -> bool {
+    let Some((labels, mut suggestions)) =
+        type_param_constraint_suggestions(tcx, generics, tys_and_constraints)
+    else {
+        return false;
+    };
+    for (span, constraint) in labels {
+        err.set_arg("sized_constraint", constraint);
+        err.span_label(span, fluent::middle::type_param_needs_sized);
+    }
+    // The code generated by a macro can't be edited, but the code the macro was called with can,
+    // like the tokens a procedural macro kept the spans of or the code a desugaring replaced.
+    let mut macro_call = None;
+    for suggestion in &mut suggestions {
+        if !suggestion.span.from_expansion() {
+            continue;
+        }
+        let call_site = suggestion.span.source_callsite();
+        if call_site.contains(suggestion.span) {
+            suggestion.span = suggestion.span.with_ctxt(call_site.ctxt());
+        } else {
+            macro_call = Some(suggestion.span.ctxt().outer_expn_data().kind);
+        }
+    }
+    if let Some(kind) = macro_call {
+        err.set_arg("macro_call", kind.descr());
+        err.note(fluent::middle::type_params_declared_by_macro);
+        return true;
+    }
+    if suggestions.len() == 1 {
+        let ConstraintSuggestion { span, code, message, param_name, applicability } =
+            suggestions.pop().unwrap();
+        if let Some(param_name) = param_name {
+            err.set_arg("param_name", param_name);
+        }
+        err.span_suggestion_verbose(span, message, code, applicability);
+    } else if !suggestions.is_empty() {
+        let applicability = suggestions
+            .iter()
+            .map(|suggestion| suggestion.applicability)
+            .find(|applicability| *applicability != Applicability::MachineApplicable)
+            .unwrap_or(Applicability::MachineApplicable);
+        err.multipart_suggestion_verbose(
+            fluent::middle::restrict_type_params,
+            suggestions.into_iter().map(|suggestion| (suggestion.span, suggestion.code)).collect(),
+            applicability,
+        );
+    }
+    true
+}
+
+/// Computes the suggestions to restrict type params with new bounds, along with the params which
+/// need to be `Sized` and the `Sized` bound they need. This is `suggest_constraining_type_params`
+/// without the diagnostic, for callers which want to filter or rank the suggestions.
+///
+/// An associated type of a type param, like `<T as Iterator>::Item`, is restricted on the param
+/// with `T: Iterator<Item: Bound>` if the `associated_type_bounds` feature is enabled, and in a
+/// `where` clause otherwise.
+///
+/// Returns `None` if a param isn't declared in `generics`, or if it can't be constrained.
+pub fn type_param_constraint_suggestions<'a, 'tcx>(
+    tcx: TyCtxt<'tcx>,
+    generics: &hir::Generics<'_>,
+    tys_and_constraints: impl Iterator<Item = (ConstrainedTy<'a, 'tcx>, &'a str, Option<DefId>)>,
+) -> Option<(Vec<(Span, String)>, Vec<ConstraintSuggestion>)> {
+    let mut grouped = FxIndexMap::default();
+    for (constrained_ty, constraint, def_id) in tys_and_constraints {
+        let projection = match constrained_ty {
+            ConstrainedTy::Param(param_name) => {
+                let param_name = BoundedTy::Param(Symbol::intern(param_name));
+                let constraints = grouped.entry(param_name).or_insert(Vec::new());
+                constraints.push((Cow::Borrowed(constraint), def_id));
+                continue;
+            }
+            ConstrainedTy::Projection(projection) => projection,
+        };
+        let param = match *projection.self_ty().kind() {
+            Param(self_param) => {
+                generics.params.iter().find(|p| p.name.ident().name == self_param.name)
+            }
+            _ => None,
+        };
+        // An `impl Trait` can't be named in a `where` clause either.
+        if let Some(hir::GenericParamKind::Type { synthetic: true, .. }) = param.map(|p| &p.kind) {
+            return None;
+        }
+        // The generic arguments of the trait, or of the associated type, would have to be
+        // repeated in the new bound.
+        if let Some(param) = param
+            && tcx.features().associated_type_bounds
+            && projection.substs.len() == 1
+        {
+            let constraint = format!(
+                "{}<{}: {}>",
+                projection.trait_ref(tcx).print_only_trait_path(),
+                tcx.item_name(projection.item_def_id),
+                constraint
+            );
+            let param_name = BoundedTy::Param(param.name.ident().name);
+            let constraints = grouped.entry(param_name).or_insert(Vec::new());
+            constraints.push((Cow::Owned(constraint), None));
+        } else {
+            let projection = tcx.mk_projection(projection.item_def_id, projection.substs);
+            let constraints =
+                grouped.entry(BoundedTy::Projection(projection.to_string())).or_insert(Vec::new());
+            constraints.push((Cow::Borrowed(constraint), def_id));
+        }
+    }
 
     let mut applicability = Applicability::MachineApplicable;
+    let mut labels = Vec::new();
     let mut suggestions = Vec::new();
 
-    for (param_name, mut constraints) in grouped {
-        let param = generics.params.iter().find(|p| p.name.ident().as_str() == param_name);
-        let Some(param) = param else { return false };
+    for (bounded_ty, constraints) in &mut grouped {
+        let param_name = match bounded_ty {
+            BoundedTy::Param(param_name) => param_name.as_str(),
+            BoundedTy::Projection(projection) => {
+                // A bound on an associated type of a type param, like `<T as Iterator>::Item`,
+                // can only be added in a `where` clause:
+                //
+                //   fn foo<T: Iterator>(t: T) { ... }
+                //                     - insert: ` where <T as Iterator>::Item: Display`
+                let mut constraint = constraints.iter().map(|(c, _)| &**c).collect::<Vec<_>>();
+                constraint.sort();
+                constraint.dedup();
+                suggestions.push((
+                    generics.tail_span_for_predicate_suggestion(),
+                    format!(
+                        "{} {}: {}",
+                        generics.add_where_or_trailing_comma(),
+                        projection,
+                        constraint.join(" + ")
+                    ),
+                    SuggestChangingConstraintsMessage::RestrictAssocType { ty: projection },
+                ));
+                continue;
+            }
+        };
+        let param = generics.params.iter().find(|p| p.name.ident().as_str() == param_name)?;
 
         {
             let mut sized_constraints =
@@ -213,10 +532,7 @@ pub fn suggest_constraining_type_params<'a>(
             if let Some((constraint, def_id)) = sized_constraints.next() {
                 applicability = Applicability::MaybeIncorrect;
 
-                err.span_label(
-                    param.span,
-                    &format!("this type parameter needs to be `{}`", constraint),
-                );
+                labels.push((param.span, constraint.into_owned()));
                 suggest_removing_unsized_bound(tcx, generics, &mut suggestions, param, def_id);
             }
         }
@@ -224,11 +540,38 @@ pub fn suggest_constraining_type_params<'a>(
         if constraints.is_empty() {
             continue;
         }
+        if new_bounds_may_break_users(tcx, generics, param, constraints) {
+            applicability = Applicability::MaybeIncorrect;
+        }
 
-        let mut constraint = constraints.iter().map(|&(c, _)| c).collect::<Vec<_>>();
+        // `T: Foo + Bar` is written `T: FooBar` when there's a `trait FooBar = Foo + Bar;`.
+        if let Some(alias) = trait_alias_for_bounds(tcx, param.hir_id.owner, constraints) {
+            *constraints = vec![(Cow::Owned(alias), None)];
+        }
+
+        let mut constraint = constraints.iter().map(|(c, _)| &**c).collect::<Vec<_>>();
         constraint.sort();
         constraint.dedup();
         let constraint = constraint.join(" + ");
+
+        // An `impl Trait` in argument position has no name which could be used in a `where`
+        // clause, so the bound is added to the `impl Trait` itself:
+        //
+        //   fn foo(t: impl Foo) { ... }
+        //                     - insert: ` + Bar`
+        //
+        //   fn foo(t: &impl Foo) { ... }
+        //              -------- replace with: `(impl Foo + Bar)`
+        if let hir::GenericParamKind::Type { synthetic: true, .. } = param.kind {
+            let (span, suggestion) = impl_trait_with_bound(tcx, param, &constraint)?;
+            suggestions.push((
+                span,
+                suggestion,
+                SuggestChangingConstraintsMessage::RestrictBoundFurther,
+            ));
+            continue;
+        }
+
         let mut suggest_restrict = |span, bound_list_non_empty| {
             suggestions.push((
                 span,
@@ -292,7 +635,7 @@ pub fn suggest_constraining_type_params<'a>(
                 generics.tail_span_for_predicate_suggestion(),
                 constraints
                     .iter()
-                    .map(|&(constraint, _)| format!(", {}: {}", param_name, constraint))
+                    .map(|(constraint, _)| format!(", {}: {}", param_name, constraint))
                     .collect::<String>(),
                 SuggestChangingConstraintsMessage::RestrictTypeFurther { ty: param_name },
             ));
@@ -313,7 +656,12 @@ pub fn suggest_constraining_type_params<'a>(
             // default (`<T=Foo>`), so we suggest adding `where T: Bar`.
             suggestions.push((
                 generics.tail_span_for_predicate_suggestion(),
-                format!(" where {}: {}", param_name, constraint),
+                format!(
+                    "{} {}: {}",
+                    generics.add_where_or_trailing_comma(),
+                    param_name,
+                    constraint
+                ),
                 SuggestChangingConstraintsMessage::RestrictTypeFurther { ty: param_name },
             ));
             continue;
@@ -343,40 +691,207 @@ pub fn suggest_constraining_type_params<'a>(
         ));
     }
 
-    if suggestions.len() == 1 {
-        let (span, suggestion, msg) = suggestions.pop().unwrap();
-
-        let s;
-        let msg = match msg {
-            SuggestChangingConstraintsMessage::RestrictBoundFurther => {
-                "consider further restricting this bound"
-            }
-            SuggestChangingConstraintsMessage::RestrictType { ty } => {
-                s = format!("consider restricting type parameter `{}`", ty);
-                &s
-            }
-            SuggestChangingConstraintsMessage::RestrictTypeFurther { ty } => {
-                s = format!("consider further restricting type parameter `{}`", ty);
-                &s
-            }
-            SuggestChangingConstraintsMessage::RemovingQSized => {
-                "consider removing the `?Sized` bound to make the type parameter `Sized`"
+    // Several predicates can be added at the end of the `where` clause, which have to be a single
+    // edit, with one `where` and commas between them:
+    //
+    //   fn foo<X = (), Y = ()>() { ... }
+    //                           - insert: ` where X: Bar, Y: Baz`
+    let tail_span = generics.tail_span_for_predicate_suggestion();
+    let prefix = generics.add_where_or_trailing_comma();
+    let mut merged: Vec<(Span, String, SuggestChangingConstraintsMessage<'_>)> = Vec::new();
+    for (span, code, msg) in suggestions {
+        let previous = merged.iter_mut().find(|(previous_span, ..)| *previous_span == span);
+        match previous.filter(|_| span == tail_span) {
+            Some((_, merged_code, merged_msg)) => {
+                merged_code.push(',');
+                merged_code.push_str(code.strip_prefix(prefix).unwrap_or(&code));
+                *merged_msg = SuggestChangingConstraintsMessage::RestrictTypeParams;
             }
+            None => merged.push((span, code, msg)),
+        }
+    }
+
+    let suggestions = merged
+        .into_iter()
+        .map(|(span, code, msg)| {
+            let (message, param_name) = match msg {
+                SuggestChangingConstraintsMessage::RestrictBoundFurther => {
+                    (fluent::middle::restrict_bound_further, None)
+                }
+                SuggestChangingConstraintsMessage::RestrictType { ty } => {
+                    (fluent::middle::restrict_type, Some(ty))
+                }
+                SuggestChangingConstraintsMessage::RestrictTypeFurther { ty } => {
+                    (fluent::middle::restrict_type_further, Some(ty))
+                }
+                SuggestChangingConstraintsMessage::RestrictAssocType { ty } => {
+                    (fluent::middle::restrict_assoc_type, Some(ty))
+                }
+                SuggestChangingConstraintsMessage::RestrictTypeParams => {
+                    (fluent::middle::restrict_type_params, None)
+                }
+                SuggestChangingConstraintsMessage::RemovingQSized => {
+                    (fluent::middle::remove_maybe_sized, None)
+                }
+            };
+            let param_name = param_name.map(str::to_owned);
+            ConstraintSuggestion { span, code, message, param_name, applicability }
+        })
+        .collect();
+
+    Some((labels, suggestions))
+}
+
+/// Whether adding the bounds `constraints` to `param` can break code using its item, so that the
+/// suggestion shouldn't be applied without a look:
+///
+/// - the bounds make a trait impl apply to fewer types, which other code may rely on,
+/// - the bounds make a method of a trait impl stricter than the method of the trait, which is
+///   an error unless the trait is changed as well,
+/// - the param is `?Sized`, so it may be a trait object which doesn't implement the bounds,
+/// - the item is exported, so its users can't be checked.
+fn new_bounds_may_break_users(
+    tcx: TyCtxt<'_>,
+    generics: &hir::Generics<'_>,
+    param: &hir::GenericParam<'_>,
+    constraints: &[(Cow<'_, str>, Option<DefId>)],
+) -> bool {
+    let is_trait_impl = |def_id: LocalDefId| {
+        tcx.def_kind(def_id) == DefKind::Impl && tcx.impl_trait_ref(def_id).is_some()
+    };
+    let owner = param.hir_id.owner;
+    if is_trait_impl(owner)
+        || tcx.def_kind(owner) == DefKind::AssocFn && is_trait_impl(tcx.local_parent(owner))
+    {
+        return true;
+    }
+    let param_def_id = tcx.hir().local_def_id(param.hir_id);
+    let maybe_unsized = generics.bounds_for_param(param_def_id).any(|predicate| {
+        predicate.bounds.iter().any(|bound| {
+            matches!(bound, hir::GenericBound::Trait(_, hir::TraitBoundModifier::Maybe))
+        })
+    });
+    let not_object_safe = |def_id: Option<DefId>| {
+        def_id.map_or(true, |def_id| !tcx.trait_is_auto(def_id) && !tcx.is_object_safe(def_id))
+    };
+    if maybe_unsized && constraints.iter().any(|&(_, def_id)| not_object_safe(def_id)) {
+        return true;
+    }
+    is_exported(tcx, owner)
+}
+
+/// Whether the item `def_id` can be used by other crates.
+///
+/// This uses the access levels computed by name resolution, which don't know about impls, instead
+/// of `privacy_access_levels`: those need the hidden types of the opaque types, so computing them
+/// while type checking the function defining one would be a cycle.
+fn is_exported(tcx: TyCtxt<'_>, def_id: LocalDefId) -> bool {
+    let access_levels = &tcx.resolutions(()).access_levels;
+    match tcx.def_kind(def_id) {
+        // An inherent impl is as exported as its self type.
+        DefKind::Impl => tcx
+            .type_of(def_id)
+            .ty_adt_def()
+            .and_then(|adt| adt.did().as_local())
+            .map_or(true, |did| access_levels.is_exported(did)),
+        DefKind::AssocFn | DefKind::AssocConst | DefKind::AssocTy
+            if tcx.def_kind(tcx.local_parent(def_id)) == DefKind::Impl =>
+        {
+            tcx.visibility(def_id).is_public() && is_exported(tcx, tcx.local_parent(def_id))
+        }
+        _ => access_levels.is_exported(def_id),
+    }
+}
+
+/// Finds a trait alias of this crate, usable from `owner`, which stands for exactly the traits
+/// of `constraints`, when there are several and none of them has generic arguments.
+fn trait_alias_for_bounds(
+    tcx: TyCtxt<'_>,
+    owner: LocalDefId,
+    constraints: &[(Cow<'_, str>, Option<DefId>)],
+) -> Option<String> {
+    if !tcx.features().trait_alias || constraints.len() < 2 {
+        return None;
+    }
+    let mut traits = constraints
+        .iter()
+        .map(|(constraint, def_id)| def_id.filter(|_| !constraint.contains('<')))
+        .collect::<Option<Vec<_>>>()?;
+    traits.sort();
+    traits.dedup();
+
+    let module = tcx.parent_module_from_def_id(owner).to_def_id();
+    let alias = tcx.hir().items().map(|id| id.def_id.to_def_id()).find(|&alias| {
+        if tcx.def_kind(alias) != DefKind::TraitAlias
+            || tcx.generics_of(alias).count() != 1
+            || !tcx.visibility(alias).is_accessible_from(module, tcx)
+        {
+            return false;
+        }
+        let alias_traits = tcx.super_predicates_of(alias).predicates.iter().map(|(pred, _)| {
+            let trait_pred = pred.to_opt_poly_trait_pred()?.skip_binder();
+            (trait_pred.self_ty() == tcx.types.self_param && trait_pred.trait_ref.substs.len() == 1)
+                .then_some(trait_pred.def_id())
+        });
+        let Some(mut alias_traits) = alias_traits.collect::<Option<Vec<_>>>() else {
+            return false;
         };
+        alias_traits.sort();
+        alias_traits.dedup();
+        alias_traits == traits
+    })?;
+    Some(tcx.def_path_str(alias))
+}
 
-        err.span_suggestion_verbose(span, msg, suggestion, applicability);
-    } else if suggestions.len() > 1 {
-        err.multipart_suggestion_verbose(
-            "consider restricting type parameters",
-            suggestions.into_iter().map(|(span, suggestion, _)| (span, suggestion)).collect(),
-            applicability,
-        );
+/// Adds `constraint` to the bounds of the `impl Trait` in argument position `param`.
+fn impl_trait_with_bound(
+    tcx: TyCtxt<'_>,
+    param: &hir::GenericParam<'_>,
+    constraint: &str,
+) -> Option<(Span, String)> {
+    let source_map = tcx.sess.source_map();
+    // The span of the synthetic param is the one of the `impl Trait` in the signature.
+    let prev = source_map.span_to_prev_source(param.span).ok()?;
+    if prev.trim_end().ends_with([':', '<', '(', ',', '=']) {
+        return Some((param.span.shrink_to_hi(), format!(" + {}", constraint)));
     }
+    // `&impl Foo + Bar` is ambiguous, so the `impl Trait` has to be wrapped in parentheses when
+    // it's not a whole type on its own.
+    let impl_trait = source_map.span_to_snippet(param.span).ok()?;
+    Some((param.span, format!("({} + {})", impl_trait, constraint)))
+}
 
-    true
+/// Suggest restricting a type param with an outlives bound like `T: 'a`, which is placed like
+/// the trait bounds of `suggest_constraining_type_params`: after the other bounds of the param if
+/// it has some, and on the `impl Trait` itself for an `impl Trait` in argument position.
+///
+/// Returns the span to insert at and the code to insert there.
+pub fn outlives_bound_suggestion(
+    tcx: TyCtxt<'_>,
+    param_def_id: LocalDefId,
+    region: &str,
+) -> Option<(Span, String)> {
+    let hir_id = tcx.hir().local_def_id_to_hir_id(param_def_id);
+    let generics = tcx.hir().get_generics(hir_id.owner)?;
+    let param = generics.params.iter().find(|param| param.hir_id == hir_id)?;
+    if let hir::GenericParamKind::Type { synthetic: true, .. } = param.kind {
+        return impl_trait_with_bound(tcx, param, region);
+    }
+    Some(match generics.bounds_span_for_suggestions(param_def_id) {
+        Some(span) => (span, format!(" + {}", region)),
+        // Reuse the colon of a param without bounds, like `T:`.
+        None => match param.colon_span {
+            Some(colon_span) => (colon_span.shrink_to_hi(), format!(" {}", region)),
+            None => (param.span.shrink_to_hi(), format!(": {}", region)),
+        },
+    })
 }
 
 /// Collect al types that have an implicit `'static` obligation that we could suggest `'_` for.
+///
+/// The whole type is walked, so the trait objects nested in generic arguments, tuples, arrays
+/// and the bounds of `impl Trait`, like in `impl Iterator<Item = Box<dyn Trait>>`, are collected
+/// too, along with the type they're in.
 pub struct TraitObjectVisitor<'tcx>(pub Vec<&'tcx hir::Ty<'tcx>>, pub crate::hir::map::Map<'tcx>);
 
 impl<'v> hir::intravisit::Visitor<'v> for TraitObjectVisitor<'v> {
@@ -417,61 +932,161 @@ fn visit_lifetime(&mut self, lt: &'v hir::Lifetime) {
     }
 }
 
-pub struct IsSuggestableVisitor<'tcx> {
-    tcx: TyCtxt<'tcx>,
+impl<'tcx> StaticLifetimeVisitor<'tcx> {
+    /// The parts of a suggestion introducing a new lifetime parameter in `generics`, the item the
+    /// lifetimes were collected in, and using it in place of each of them. Returns `None` if no
+    /// lifetime was collected.
+    ///
+    /// Callers may push the spans of other lifetimes to replace as well, like the `&` of a
+    /// reference with an elided lifetime or an explicit `'_`.
+    pub fn named_lifetime_suggestion(
+        &self,
+        tcx: TyCtxt<'_>,
+        generics: &hir::Generics<'_>,
+    ) -> Option<Vec<(Span, String)>> {
+        if self.0.is_empty() {
+            return None;
+        }
+        // The names of the params but also of the higher-ranked lifetimes of the bounds, including
+        // the ones of `impl Trait` arguments, which the new parameter mustn't shadow.
+        let mut used = LifetimeNameCollector(FxHashSet::default());
+        hir::intravisit::walk_generics(&mut used, generics);
+        let name = ('a'..='z').map(|c| format!("'{}", c)).find(|name| !used.0.contains(name))?;
+
+        let source_map = tcx.sess.source_map();
+        let mut suggestions = vec![new_lifetime_param_suggestion(generics, &name)];
+        for &span in &self.0 {
+            match source_map.span_to_snippet(span).as_deref() {
+                Ok("'static" | "'_") => suggestions.push((span, name.clone())),
+                Ok("&") => suggestions.push((span.shrink_to_hi(), format!("{} ", name))),
+                _ => {
+                    // The span of an implicit trait object lifetime is the one of the trait object.
+                    // `&dyn Foo + 'a` is ambiguous, so it needs parentheses when behind a reference.
+                    let prev = source_map.span_to_prev_source(span).ok()?;
+                    let prev = prev.trim_end();
+                    if prev.ends_with(['&', '*']) || prev.ends_with(" mut") {
+                        suggestions.push((span.shrink_to_lo(), "(".to_owned()));
+                        suggestions.push((span.shrink_to_hi(), format!(" + {})", name)));
+                    } else {
+                        suggestions.push((span.shrink_to_hi(), format!(" + {}", name)));
+                    }
+                }
+            }
+        }
+        Some(suggestions)
+    }
 }
 
-impl<'tcx> TypeVisitor<'tcx> for IsSuggestableVisitor<'tcx> {
-    type BreakTy = ();
+/// Collect the names of all the lifetimes declared or used.
+struct LifetimeNameCollector(FxHashSet<String>);
 
-    fn visit_ty(&mut self, t: Ty<'tcx>) -> ControlFlow<Self::BreakTy> {
-        match t.kind() {
-            FnDef(..)
-            | Closure(..)
-            | Infer(..)
-            | Generator(..)
-            | GeneratorWitness(..)
-            | Bound(_, _)
-            | Placeholder(_)
-            | Error(_) => {
-                return ControlFlow::Break(());
-            }
+impl<'v> hir::intravisit::Visitor<'v> for LifetimeNameCollector {
+    fn visit_generic_param(&mut self, param: &'v hir::GenericParam<'v>) {
+        if let hir::GenericParamKind::Lifetime { .. } = param.kind {
+            self.0.insert(param.name.ident().to_string());
+        }
+        hir::intravisit::walk_generic_param(self, param);
+    }
 
-            Opaque(did, _) => {
-                let parent = self.tcx.parent(*did);
-                if let hir::def::DefKind::TyAlias | hir::def::DefKind::AssocTy = self.tcx.def_kind(parent)
-                    && let Opaque(parent_did, _) = self.tcx.type_of(parent).kind()
-                    && parent_did == did
-                {
-                    // Okay
-                } else {
-                    return ControlFlow::Break(());
-                }
-            }
+    fn visit_lifetime(&mut self, lt: &'v hir::Lifetime) {
+        self.0.insert(lt.name.ident().to_string());
+    }
+}
 
-            Dynamic(dty, _) => {
-                for pred in *dty {
-                    match pred.skip_binder() {
-                        ExistentialPredicate::Trait(_) | ExistentialPredicate::Projection(_) => {
-                            // Okay
-                        }
-                        _ => return ControlFlow::Break(()),
-                    }
-                }
-            }
+/// The part of a suggestion introducing a lifetime parameter named `name` in `generics`, before
+/// the other params.
+pub fn new_lifetime_param_suggestion(generics: &hir::Generics<'_>, name: &str) -> (Span, String) {
+    // The params of `impl Trait` arguments aren't written in the list.
+    if let Some(first) = generics.params.iter().find(|p| {
+        !p.name.ident().span.is_empty()
+            && !matches!(p.kind, hir::GenericParamKind::Type { synthetic: true, .. })
+    }) {
+        (first.span.shrink_to_lo(), format!("{}, ", name))
+    } else {
+        (generics.span, format!("<{}>", name))
+    }
+}
 
-            Param(param) => {
-                // FIXME: It would be nice to make this not use string manipulation,
-                // but it's pretty hard to do this, since `ty::ParamTy` is missing
-                // sufficient info to determine if it is synthetic, and we don't
-                // always have a convenient way of getting `ty::Generics` at the call
-                // sites we invoke `IsSuggestable::is_suggestable`.
-                if param.name.as_str().starts_with("impl ") {
-                    return ControlFlow::Break(());
-                }
+/// Whether the opaque type `did` can be written in a suggestion, which is the case for a
+/// `type_alias_impl_trait` which is the whole type of its type alias or associated type, from this
+/// crate or another one, as it's printed as the path of that alias.
+///
+/// The `impl Trait` of a return type can't be named, and neither can an opaque type nested in
+/// another type of an alias, like the `impl Debug` of `type Foo = (i32, impl Debug);`, which is
+/// printed as `Foo::{opaque#0}`.
+pub fn is_nameable_opaque(tcx: TyCtxt<'_>, did: DefId) -> bool {
+    let parent = tcx.parent(did);
+    match tcx.def_kind(parent) {
+        DefKind::TyAlias | DefKind::AssocTy => {
+            matches!(*tcx.type_of(parent).kind(), Opaque(parent_did, _) if parent_did == did)
+        }
+        // The `impl Trait` of return types, from this crate or another one, and the opaque types
+        // nested in the bounds of a `type_alias_impl_trait`, which are printed as `impl Trait`.
+        _ => false,
+    }
+}
+
+/// The path of an opaque type given as the value of an associated type in the bounds of another
+/// opaque type, like the `impl Debug` of `type Foo = impl Iterator<Item = impl Debug>;`, which is
+/// `<Foo as Iterator>::Item`.
+fn nested_opaque_as_projection<'tcx>(
+    tcx: TyCtxt<'tcx>,
+    did: DefId,
+    substs: SubstsRef<'tcx>,
+) -> Option<Ty<'tcx>> {
+    let parent = tcx.parent(did);
+    if tcx.def_kind(parent) != DefKind::OpaqueTy {
+        return None;
+    }
+    let parent_substs = substs.truncate_to(tcx, tcx.generics_of(parent));
+    tcx.explicit_item_bounds(parent).iter().find_map(|(pred, _)| {
+        let ty::PredicateKind::Projection(proj) = pred.kind().no_bound_vars()? else {
+            return None;
+        };
+        match *proj.term.ty()?.kind() {
+            Opaque(term_did, _) if term_did == did => {
+                let projection_ty = proj.projection_ty;
+                let projection = tcx.mk_projection(projection_ty.item_def_id, projection_ty.substs);
+                Some(ty::EarlyBinder(projection).subst(tcx, parent_substs))
             }
+            _ => None,
+        }
+    })
+}
 
-            _ => {}
+/// Whether a type can be written in a suggestion as it's printed, without looking at the types it
+/// contains, for the kinds of types which `IsSuggestableVisitor` and `MakeSuggestableFolder` treat
+/// the same way. Function items and inference variables are left to each of them.
+fn is_suggestable_kind<'tcx>(tcx: TyCtxt<'tcx>, t: Ty<'tcx>) -> bool {
+    match *t.kind() {
+        Closure(..)
+        | Generator(..)
+        | GeneratorWitness(..)
+        | Bound(_, _)
+        | Placeholder(_)
+        | Error(_) => false,
+        Opaque(did, _) => is_nameable_opaque(tcx, did),
+        Dynamic(dty, _) => dty.iter().all(|pred| {
+            matches!(
+                pred.skip_binder(),
+                ExistentialPredicate::Trait(_) | ExistentialPredicate::Projection(_)
+            )
+        }),
+        Param(param) => !param.synthetic,
+        _ => true,
+    }
+}
+
+pub struct IsSuggestableVisitor<'tcx> {
+    tcx: TyCtxt<'tcx>,
+}
+
+impl<'tcx> TypeVisitor<'tcx> for IsSuggestableVisitor<'tcx> {
+    type BreakTy = ();
+
+    fn visit_ty(&mut self, t: Ty<'tcx>) -> ControlFlow<Self::BreakTy> {
+        if matches!(t.kind(), FnDef(..) | Infer(..)) || !is_suggestable_kind(self.tcx, t) {
+            return ControlFlow::Break(());
         }
 
         t.super_visit_with(self)
@@ -491,3 +1106,119 @@ fn visit_const(&mut self, c: Const<'tcx>) -> ControlFlow<Self::BreakTy> {
         c.super_visit_with(self)
     }
 }
+
+/// An inference variable found by `InferVarCollector`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum InferVar<'tcx> {
+    Ty(InferTy),
+    Const(ty::InferConst<'tcx>),
+}
+
+/// Collects the inference variables in a type, once each and in the order they are found, so that
+/// errors about what couldn't be inferred can point at each unknown part of the type instead of
+/// the whole type. Unlike `IsSuggestableVisitor`, this doesn't stop at the first one.
+#[derive(Default)]
+pub struct InferVarCollector<'tcx> {
+    pub vars: FxIndexSet<InferVar<'tcx>>,
+}
+
+impl<'tcx> InferVarCollector<'tcx> {
+    pub fn collect<T: TypeFoldable<'tcx>>(value: T) -> FxIndexSet<InferVar<'tcx>> {
+        let mut collector = InferVarCollector::default();
+        value.visit_with(&mut collector);
+        collector.vars
+    }
+}
+
+impl<'tcx> TypeVisitor<'tcx> for InferVarCollector<'tcx> {
+    type BreakTy = ();
+
+    fn visit_ty(&mut self, t: Ty<'tcx>) -> ControlFlow<Self::BreakTy> {
+        if !t.has_infer_types_or_consts() {
+            return ControlFlow::CONTINUE;
+        }
+        if let Infer(infer) = *t.kind() {
+            self.vars.insert(InferVar::Ty(infer));
+        }
+        t.super_visit_with(self)
+    }
+
+    fn visit_const(&mut self, c: Const<'tcx>) -> ControlFlow<Self::BreakTy> {
+        if let ConstKind::Infer(infer) = c.kind() {
+            self.vars.insert(InferVar::Const(infer));
+        }
+        c.super_visit_with(self)
+    }
+}
+
+/// Renders a closure as the `impl Fn(..) -> ..` it can be written as in a return type, or returns
+/// `None` if its signature can't be written.
+///
+/// The lifetimes the closure captures aren't part of it, so it needs a `+ '_` when it captures a
+/// reference, which is up to the caller.
+pub fn closure_as_impl_trait<'tcx>(
+    tcx: TyCtxt<'tcx>,
+    closure: ty::ClosureSubsts<'tcx>,
+) -> Option<String> {
+    let kind = closure.kind_ty().to_opt_closure_kind()?;
+    // The lifetimes of the arguments are late-bound, and are elided in `Fn` sugar.
+    let sig = tcx.erase_late_bound_regions(closure.sig()).make_suggestable(tcx)?;
+    let inputs = sig.inputs().iter().map(|ty| ty.to_string()).collect::<Vec<_>>();
+    let output = sig.output();
+    if output.is_unit() {
+        Some(format!("impl {}({})", kind, inputs.join(", ")))
+    } else {
+        Some(format!("impl {}({}) -> {}", kind, inputs.join(", "), output))
+    }
+}
+
+pub struct MakeSuggestableFolder<'tcx> {
+    tcx: TyCtxt<'tcx>,
+}
+
+impl<'tcx> FallibleTypeFolder<'tcx> for MakeSuggestableFolder<'tcx> {
+    type Error = ();
+
+    fn tcx<'a>(&'a self) -> TyCtxt<'tcx> {
+        self.tcx
+    }
+
+    fn try_fold_ty(&mut self, t: Ty<'tcx>) -> Result<Ty<'tcx>, Self::Error> {
+        let t = match *t.kind() {
+            // Type variables are printed as `_`, but integer and float variables aren't.
+            Infer(InferTy::TyVar(_)) => t,
+            Infer(_) => return Err(()),
+            FnDef(..) => self.tcx.mk_fn_ptr(t.fn_sig(self.tcx)),
+            Opaque(did, substs) if !is_nameable_opaque(self.tcx, did) => {
+                let projection = nested_opaque_as_projection(self.tcx, did, substs).ok_or(())?;
+                return projection.try_fold_with(self);
+            }
+            _ if !is_suggestable_kind(self.tcx, t) => return Err(()),
+            _ => t,
+        };
+
+        t.try_super_fold_with(self)
+    }
+
+    fn try_fold_region(&mut self, r: ty::Region<'tcx>) -> Result<ty::Region<'tcx>, Self::Error> {
+        match *r {
+            ty::ReVar(_) | ty::RePlaceholder(_) | ty::ReEmpty(_) => {
+                Ok(self.tcx.lifetimes.re_erased)
+            }
+            _ => Ok(r),
+        }
+    }
+
+    fn try_fold_const(&mut self, c: Const<'tcx>) -> Result<Const<'tcx>, Self::Error> {
+        match c.kind() {
+            // Inference variables are printed as `_`.
+            ConstKind::Infer(..) => {}
+            ConstKind::Bound(..) | ConstKind::Placeholder(..) | ConstKind::Error(..) => {
+                return Err(());
+            }
+            _ => {}
+        }
+
+        c.try_super_fold_with(self)
+    }
+}
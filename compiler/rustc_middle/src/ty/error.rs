@@ -249,7 +249,7 @@ pub fn sort_string(self, tcx: TyCtxt<'_>) -> Cow<'static, str> {
             ty::Adt(def, _) => format!("{} `{}`", def.descr(), tcx.def_path_str(def.did())).into(),
             ty::Foreign(def_id) => format!("extern type `{}`", tcx.def_path_str(def_id)).into(),
             ty::Array(t, n) => {
-                if t.is_simple_ty() {
+                if t.diagnostic_class() <= ty::TyDiagnosticClass::Simple {
                     return format!("array `{}`", self).into();
                 }
 
@@ -261,14 +261,17 @@ pub fn sort_string(self, tcx: TyCtxt<'_>) -> Cow<'static, str> {
                 }
                 "array".into()
             }
-            ty::Slice(ty) if ty.is_simple_ty() => format!("slice `{}`", self).into(),
+            ty::Slice(ty) if ty.diagnostic_class() <= ty::TyDiagnosticClass::Simple => {
+                format!("slice `{}`", self).into()
+            }
             ty::Slice(_) => "slice".into(),
             ty::RawPtr(_) => "*-ptr".into(),
             ty::Ref(_, ty, mutbl) => {
                 let tymut = ty::TypeAndMut { ty, mutbl };
                 let tymut_string = tymut.to_string();
                 if tymut_string != "_"
-                    && (ty.is_simple_text() || tymut_string.len() < "mutable reference".len())
+                    && (tcx.lift(ty).map_or(false, |ty| ty.is_simple_text(tcx))
+                        || tymut_string.len() < "mutable reference".len())
                 {
                     format!("`&{}`", tymut_string).into()
                 } else {
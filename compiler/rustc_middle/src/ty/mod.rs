@@ -226,8 +226,8 @@ pub enum ImplSubject<'tcx> {
     PartialEq,
     Eq,
     Hash,
-    TyEncodable,
-    TyDecodable,
+    Encodable,
+    Decodable,
     HashStable,
     Debug,
     TypeFoldable
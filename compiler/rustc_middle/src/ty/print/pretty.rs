@@ -13,6 +13,7 @@
 use rustc_hir::definitions::{DefPathData, DefPathDataName, DisambiguatedDefPathData};
 use rustc_session::config::TrimmedDefPaths;
 use rustc_session::cstore::{ExternCrate, ExternCrateSource};
+use rustc_session::Limit;
 use rustc_span::symbol::{kw, Ident, Symbol};
 use rustc_target::abi::Size;
 use rustc_target::spec::abi::Abi;
@@ -1555,6 +1556,9 @@ pub struct FmtPrinterData<'a, 'tcx> {
     region_index: usize,
     binder_depth: usize,
     printed_type_count: usize,
+    type_length_limit: Limit,
+    /// The length of the output when the first type over `type_length_limit` was elided.
+    truncated_at: Option<usize>,
 
     pub region_highlight_mode: RegionHighlightMode<'tcx>,
 
@@ -1577,6 +1581,11 @@ fn deref_mut(&mut self) -> &mut Self::Target {
 
 impl<'a, 'tcx> FmtPrinter<'a, 'tcx> {
     pub fn new(tcx: TyCtxt<'tcx>, ns: Namespace) -> Self {
+        Self::new_with_limit(tcx, ns, tcx.type_length_limit())
+    }
+
+    /// Creates a printer which elides the types printed after the first `type_length_limit` ones.
+    pub fn new_with_limit(tcx: TyCtxt<'tcx>, ns: Namespace, type_length_limit: Limit) -> Self {
         FmtPrinter(Box::new(FmtPrinterData {
             tcx,
             // Estimated reasonable capacity to allocate upfront based on a few
@@ -1589,6 +1598,8 @@ pub fn new(tcx: TyCtxt<'tcx>, ns: Namespace) -> Self {
             region_index: 0,
             binder_depth: 0,
             printed_type_count: 0,
+            type_length_limit,
+            truncated_at: None,
             region_highlight_mode: RegionHighlightMode::new(tcx),
             ty_infer_name_resolver: None,
             const_infer_name_resolver: None,
@@ -1598,6 +1609,41 @@ pub fn new(tcx: TyCtxt<'tcx>, ns: Namespace) -> Self {
     pub fn into_buffer(self) -> String {
         self.0.fmt
     }
+
+    /// Whether a type was elided for exceeding the type length limit.
+    pub fn is_truncated(&self) -> bool {
+        self.truncated_at.is_some()
+    }
+
+    /// Like `into_buffer`, but if a type was elided for exceeding the type length limit, the
+    /// output is cut where that type starts and ends with `…` instead, followed by the brackets
+    /// which were still open, like `Vec<Option<…>>`.
+    pub fn into_truncated_buffer(self) -> String {
+        let mut fmt = self.0.fmt;
+        if let Some(len) = self.0.truncated_at {
+            fmt.truncate(len);
+            let mut open = Vec::new();
+            let mut prev = None;
+            for c in fmt.chars() {
+                match c {
+                    '<' => open.push('>'),
+                    '(' => open.push(')'),
+                    '[' => open.push(']'),
+                    '{' => open.push('}'),
+                    // The arrow of a function's return type.
+                    '>' if prev == Some('-') => {}
+                    '>' | ')' | ']' | '}' => {
+                        open.pop();
+                    }
+                    _ => {}
+                }
+                prev = Some(c);
+            }
+            fmt.push('…');
+            fmt.extend(open.into_iter().rev());
+        }
+        fmt
+    }
 }
 
 // HACK(eddyb) get rid of `def_path_str` and/or pass `Namespace` explicitly always
@@ -1717,11 +1763,13 @@ fn print_region(self, region: ty::Region<'tcx>) -> Result<Self::Region, Self::Er
     }
 
     fn print_type(mut self, ty: Ty<'tcx>) -> Result<Self::Type, Self::Error> {
-        let type_length_limit = self.tcx.type_length_limit();
-        if type_length_limit.value_within_limit(self.printed_type_count) {
+        if self.type_length_limit.value_within_limit(self.printed_type_count) {
             self.printed_type_count += 1;
             self.pretty_print_type(ty)
         } else {
+            if self.truncated_at.is_none() {
+                self.truncated_at = Some(self.fmt.len());
+            }
             write!(self, "...")?;
             Ok(self)
         }
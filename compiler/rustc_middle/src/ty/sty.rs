@@ -1273,20 +1273,23 @@ pub fn abi(&self) -> abi::Abi {
 pub struct ParamTy {
     pub index: u32,
     pub name: Symbol,
+    /// Whether this is the param of an `impl Trait` in argument position, which can't be named.
+    pub synthetic: bool,
 }
 
 impl<'tcx> ParamTy {
     pub fn new(index: u32, name: Symbol) -> ParamTy {
-        ParamTy { index, name }
+        ParamTy { index, name, synthetic: false }
     }
 
     pub fn for_def(def: &ty::GenericParamDef) -> ParamTy {
-        ParamTy::new(def.index, def.name)
+        let synthetic = matches!(def.kind, ty::GenericParamDefKind::Type { synthetic: true, .. });
+        ParamTy { index: def.index, name: def.name, synthetic }
     }
 
     #[inline]
     pub fn to_ty(self, tcx: TyCtxt<'tcx>) -> Ty<'tcx> {
-        tcx.mk_ty_param(self.index, self.name)
+        tcx.mk_ty(ty::Param(self))
     }
 }
 
@@ -1046,6 +1046,7 @@ fn check_doc_attrs(
                         | sym::issue_tracker_base_url
                         | sym::html_root_url
                         | sym::html_no_source
+                        | sym::item_order
                         | sym::test
                             if !self.check_attr_crate_level(attr, meta, hir_id) =>
                         {
@@ -1078,10 +1079,12 @@ fn check_doc_attrs(
                         | sym::html_root_url
                         | sym::inline
                         | sym::issue_tracker_base_url
+                        | sym::item_order
                         | sym::keyword
                         | sym::masked
                         | sym::no_default_passes
                         | sym::no_inline
+                        | sym::no_synthetic_impls
                         | sym::notable_trait
                         | sym::passes
                         | sym::plugins
@@ -1094,7 +1097,9 @@ fn check_doc_attrs(
                         }
 
                         sym::primitive => {
-                            if !self.tcx.features().rustdoc_internals {
+                            if !self.tcx.features().rustdoc_internals
+                                && !self.tcx.sess.opts.debugging_opts.allow_doc_primitive
+                            {
                                 self.tcx.struct_span_lint_hir(
                                     INVALID_DOC_ATTRIBUTES,
                                     hir_id,
@@ -1193,6 +1193,9 @@ pub(crate) fn parse_branch_protection(
     // If you add a new option, please update:
     // - compiler/rustc_interface/src/tests.rs
 
+    allow_doc_primitive: bool = (false, parse_bool, [TRACKED],
+        "allow `#[doc(primitive)]` outside of the standard library, for crates replacing \
+        `core` (default: no)"),
     allow_features: Option<Vec<String>> = (None, parse_opt_comma_list, [TRACKED],
         "only allow the listed language features to be enabled in code (space separated)"),
     always_encode_mir: bool = (false, parse_bool, [TRACKED],
@@ -1233,6 +1236,9 @@ pub(crate) fn parse_branch_protection(
     dep_tasks: bool = (false, parse_bool, [UNTRACKED],
         "print tasks that execute and the color their dep node gets (requires debug build) \
         (default: no)"),
+    diagnostic_arg_type_length_limit: usize = (32, parse_number, [UNTRACKED],
+        "the number of types printed in the types given to translated diagnostic messages, \
+        after which they are cut short with `…` (default: 32)"),
     dlltool: Option<PathBuf> = (None, parse_opt_pathbuf, [UNTRACKED],
         "import library generation tool (windows-gnu only)"),
     dont_buffer_diagnostics: bool = (false, parse_bool, [UNTRACKED],
@@ -1555,6 +1561,9 @@ pub(crate) fn parse_branch_protection(
         "treat error number `val` that occurs as bug"),
     trim_diagnostic_paths: bool = (true, parse_bool, [UNTRACKED],
         "in diagnostics, use heuristics to shorten paths referring to items"),
+    type_label_length: Option<usize> = (None, parse_opt_number, [UNTRACKED],
+        "name the ADTs with type arguments in the primary label of a type mismatch when they are \
+        at most this long, instead of only the ADTs without type arguments"),
     ui_testing: bool = (false, parse_bool, [UNTRACKED],
         "emit compiler diagnostics in a form suitable for UI testing (default: no)"),
     uninit_const_chunk_threshold: usize = (16, parse_number, [TRACKED],
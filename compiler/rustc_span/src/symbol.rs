@@ -818,6 +818,7 @@
         issue_tracker_base_url,
         item,
         item_like_imports,
+        item_order,
         iter,
         iter_repeat,
         keyword,
@@ -980,6 +981,7 @@
         no_stack_check,
         no_start,
         no_std,
+        no_synthetic_impls,
         nomem,
         non_ascii_idents,
         non_exhaustive,
@@ -12,6 +12,7 @@
 
 use rustc_data_structures::fx::{FxHashMap, FxHashSet};
 
+use std::cell::Cell;
 use std::collections::hash_map::Entry;
 use std::collections::VecDeque;
 use std::iter;
@@ -33,6 +34,8 @@ pub enum AutoTraitResult<A> {
     ExplicitImpl,
     PositiveImpl(A),
     NegativeImpl,
+    /// The analysis was stopped because the `AutoTraitFinder` ran out of fuel.
+    OutOfFuel,
 }
 
 #[allow(dead_code)]
@@ -50,11 +53,45 @@ pub struct AutoTraitInfo<'cx> {
 
 pub struct AutoTraitFinder<'tcx> {
     tcx: TyCtxt<'tcx>,
+    /// How many more predicates can be selected, if limited.
+    fuel: Cell<Option<usize>>,
+    /// Whether a selection was skipped because there was no fuel left.
+    out_of_fuel: Cell<bool>,
 }
 
 impl<'tcx> AutoTraitFinder<'tcx> {
     pub fn new(tcx: TyCtxt<'tcx>) -> Self {
-        AutoTraitFinder { tcx }
+        AutoTraitFinder { tcx, fuel: Cell::new(None), out_of_fuel: Cell::new(false) }
+    }
+
+    /// Creates a finder which gives up after selecting `fuel` predicates, summed over all the
+    /// calls to `find_auto_trait_generics`. Once the fuel is spent, every call returns
+    /// `AutoTraitResult::OutOfFuel`.
+    ///
+    /// The number of predicates to select can grow exponentially with the nesting of generic
+    /// types, so this keeps the analysis of pathological types from running for hours.
+    pub fn with_fuel(tcx: TyCtxt<'tcx>, fuel: usize) -> Self {
+        AutoTraitFinder { tcx, fuel: Cell::new(Some(fuel)), out_of_fuel: Cell::new(false) }
+    }
+
+    /// Whether the fuel given to `with_fuel` ran out, in which case the results of this finder
+    /// are incomplete.
+    pub fn is_out_of_fuel(&self) -> bool {
+        self.out_of_fuel.get()
+    }
+
+    fn consume_fuel(&self) -> bool {
+        match self.fuel.get() {
+            None => true,
+            Some(0) => {
+                self.out_of_fuel.set(true);
+                false
+            }
+            Some(fuel) => {
+                self.fuel.set(Some(fuel - 1));
+                true
+            }
+        }
     }
 
     /// Makes a best effort to determine whether and under which conditions an auto trait is
@@ -177,22 +214,26 @@ pub fn find_auto_trait_generics<A>(
                 &mut fresh_preds,
                 false,
             ) else {
+                if self.is_out_of_fuel() {
+                    return AutoTraitResult::OutOfFuel;
+                }
                 return AutoTraitResult::NegativeImpl;
             };
 
-            let (full_env, full_user_env) = self
-                .evaluate_predicates(
-                    &infcx,
-                    trait_did,
-                    ty,
-                    new_env,
-                    user_env,
-                    &mut fresh_preds,
-                    true,
-                )
-                .unwrap_or_else(|| {
-                    panic!("Failed to fully process: {:?} {:?} {:?}", ty, trait_did, orig_env)
-                });
+            let Some((full_env, full_user_env)) = self.evaluate_predicates(
+                &infcx,
+                trait_did,
+                ty,
+                new_env,
+                user_env,
+                &mut fresh_preds,
+                true,
+            ) else {
+                if self.is_out_of_fuel() {
+                    return AutoTraitResult::OutOfFuel;
+                }
+                panic!("Failed to fully process: {:?} {:?} {:?}", ty, trait_did, orig_env)
+            };
 
             debug!(
                 "find_auto_trait_generics({:?}): fulfilling \
@@ -324,6 +365,11 @@ fn evaluate_predicates(
                 continue;
             }
 
+            if !self.consume_fuel() {
+                debug!("evaluate_nested_obligations: out of fuel, bailing out");
+                return None;
+            }
+
             // Call `infcx.resolve_vars_if_possible` to see if we can
             // get rid of any inference variables.
             let obligation =
@@ -667,6 +667,11 @@ fn report_selection_error(
                                 trait_predicate,
                                 obligation.cause.body_id,
                             );
+                            self.suggest_indirection_for_unsized_param(
+                                &obligation,
+                                &mut err,
+                                trait_predicate,
+                            );
                         } else if !suggested {
                             // Can't show anything else useful, try to find similar impls.
                             let impl_candidates = self.find_similar_impl_candidates(trait_ref);
@@ -1016,13 +1021,28 @@ fn report_selection_error(
                         span,
                         "constant expression depends on a generic parameter",
                     );
-                    // FIXME(const_generics): we should suggest to the user how they can resolve this
-                    // issue. However, this is currently not actually possible
+                    // The constant can only be required to be evaluatable with a `where` bound
+                    // using `feature(generic_const_exprs)`, so that's only mentioned on nightly
                     // (see https://github.com/rust-lang/rust/issues/66962#issuecomment-575907083).
                     //
                     // Note that with `feature(generic_const_exprs)` this case should not
                     // be reachable.
                     err.note("this may fail depending on what value the parameter takes");
+                    if let ty::PredicateKind::ConstEvaluatable(uv) =
+                        obligation.predicate.kind().skip_binder()
+                        && self.tcx.sess.is_nightly_build()
+                        && let Ok(snippet) = self
+                            .tcx
+                            .sess
+                            .source_map()
+                            .span_to_snippet(self.tcx.def_span(uv.def.did))
+                    {
+                        err.help(&format!(
+                            "with `#![feature(generic_const_exprs)]`, the constant can be required \
+                             to be evaluatable with a `where` bound: `where [(); {}]:`",
+                            snippet
+                        ));
+                    }
                     err.emit();
                     return;
                 }
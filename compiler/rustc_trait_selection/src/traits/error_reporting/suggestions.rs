@@ -11,7 +11,7 @@
 use rustc_data_structures::fx::FxHashSet;
 use rustc_data_structures::stack::ensure_sufficient_stack;
 use rustc_errors::{
-    error_code, pluralize, struct_span_err, Applicability, Diagnostic, DiagnosticBuilder,
+    error_code, fluent, pluralize, struct_span_err, Applicability, Diagnostic, DiagnosticBuilder,
     ErrorGuaranteed, MultiSpan, Style,
 };
 use rustc_hir as hir;
@@ -22,8 +22,9 @@
 use rustc_hir::{AsyncGeneratorKind, GeneratorKind, Node};
 use rustc_middle::hir::map;
 use rustc_middle::ty::{
-    self, suggest_arbitrary_trait_bound, suggest_constraining_type_param, AdtKind, DefIdTree,
-    GeneratorDiagnosticData, GeneratorInteriorTypeCause, Infer, InferTy, IsSuggestable,
+    self, suggest_arbitrary_trait_bound, suggest_bounds_satisfying_predicate,
+    suggest_constraining_type_params, suggest_restricting_self_in_method, AdtKind, ConstrainedTy,
+    DefIdTree, GeneratorDiagnosticData, GeneratorInteriorTypeCause, Infer, InferTy, IsSuggestable,
     ToPredicate, Ty, TyCtxt, TypeFoldable, TypeFolder, TypeSuperFoldable,
 };
 use rustc_middle::ty::{TypeAndMut, TypeckResults};
@@ -318,6 +319,13 @@ fn suggest_derive(
         err: &mut Diagnostic,
         trait_pred: ty::PolyTraitPredicate<'tcx>,
     );
+
+    fn suggest_indirection_for_unsized_param(
+        &self,
+        obligation: &PredicateObligation<'tcx>,
+        err: &mut Diagnostic,
+        trait_pred: ty::PolyTraitPredicate<'tcx>,
+    );
 }
 
 fn predicate_constraint(generics: &hir::Generics<'_>, pred: String) -> (Span, String) {
@@ -501,10 +509,13 @@ fn suggest_restricting_param_bound(
                 }) if self_ty == self.tcx.types.self_param => {
                     assert!(param_ty);
                     // Restricting `Self` for a single method.
-                    suggest_restriction(
-                        self.tcx, hir_id, &generics, "`Self`", err, None, projection, trait_pred,
-                        None,
-                    );
+                    if trait_pred.is_suggestable(self.tcx) {
+                        suggest_restricting_self_in_method(
+                            generics,
+                            err,
+                            &trait_pred.print_modifiers_and_trait_path().to_string(),
+                        );
+                    }
                     return;
                 }
 
@@ -520,7 +531,7 @@ fn suggest_restricting_param_bound(
                 })
                 | hir::Node::Item(hir::Item {
                     kind: hir::ItemKind::Fn(fn_sig, generics, _), ..
-                }) if projection.is_some() => {
+                }) if method_can_carry_projection_bound(self.tcx, hir_id, projection) => {
                     // Missing restriction on associated type of type parameter (unmet projection).
                     suggest_restriction(
                         self.tcx,
@@ -571,7 +582,7 @@ fn suggest_restricting_param_bound(
                 })
                 | hir::Node::TraitItem(hir::TraitItem { generics, .. })
                 | hir::Node::ImplItem(hir::ImplItem { generics, .. })
-                    if param_ty =>
+                    if param_ty || projection.is_some() =>
                 {
                     // We skip the 0'th subst (self) because we do not want
                     // to consider the predicate as not suggestible if the
@@ -587,19 +598,42 @@ fn suggest_restricting_param_bound(
                     {
                         return;
                     }
-                    // Missing generic type parameter bound.
-                    let param_name = self_ty.to_string();
+                    // Missing generic type parameter bound, or bound on one of its associated
+                    // types.
+                    let param_name = with_no_trimmed_paths!(self_ty.to_string());
                     let constraint = with_no_trimmed_paths!(
                         trait_pred.print_modifiers_and_trait_path().to_string()
                     );
-                    if suggest_constraining_type_param(
+                    // The errors of an item fixed by the same bound only suggest it once.
+                    let ty_generics = self.tcx.generics_of(hir_id.owner);
+                    let key = match *self_ty.kind() {
+                        ty::Param(param) if (param.index as usize) < ty_generics.count() => {
+                            let param_def = ty_generics.param_at(param.index as usize, self.tcx);
+                            (param_def.name == param.name)
+                                .then(|| (param_def.def_id, constraint.clone()))
+                        }
+                        _ => None,
+                    };
+                    if let Some(key) = &key
+                        && self.suggested_param_constraints.borrow().contains(key)
+                    {
+                        err.set_arg("param_name", param_name);
+                        err.set_arg("constraint", constraint);
+                        err.note(fluent::middle::restriction_suggested_above);
+                        return;
+                    }
+                    let constrained_ty = match projection {
+                        Some(projection) => ConstrainedTy::Projection(*projection),
+                        None => ConstrainedTy::Param(&param_name),
+                    };
+                    if with_no_trimmed_paths!(suggest_constraining_type_params(
                         self.tcx,
                         generics,
                         &mut err,
-                        &param_name,
-                        &constraint,
-                        Some(trait_pred.def_id()),
-                    ) {
+                        [(constrained_ty, constraint.as_str(), Some(trait_pred.def_id()))]
+                            .into_iter(),
+                    )) {
+                        self.suggested_param_constraints.borrow_mut().extend(key);
                         return;
                     }
                 }
@@ -617,7 +651,14 @@ fn suggest_restricting_param_bound(
                         | hir::ItemKind::OpaqueTy(hir::OpaqueTy { generics, .. }),
                     ..
                 }) if !param_ty => {
-                    // Missing generic type parameter bound.
+                    // Missing bounds on the type params of the type, like `T: Clone` for
+                    // `Vec<T>: Clone`, or else a bound on the type itself.
+                    let param_env = self.tcx.param_env(hir_id.owner);
+                    if with_no_trimmed_paths!(suggest_bounds_satisfying_predicate(
+                        self.tcx, generics, err, param_env, trait_pred,
+                    )) {
+                        return;
+                    }
                     if suggest_arbitrary_trait_bound(self.tcx, generics, &mut err, trait_pred) {
                         return;
                     }
@@ -2283,13 +2324,22 @@ fn note_obligation_cause_code<T>(
             }
             ObligationCauseCode::SizedArgumentType(sp) => {
                 if let Some(span) = sp {
-                    err.span_suggestion_verbose(
-                        span.shrink_to_lo(),
-                        "function arguments must have a statically known size, borrowed types \
-                         always have a known size",
-                        "&",
-                        Applicability::MachineApplicable,
-                    );
+                    // The arguments of a type parameter's type were borrowed already by
+                    // `suggest_indirection_for_unsized_param`, along with the other alternatives.
+                    let already_suggested = err.suggestions.as_ref().map_or(false, |suggestions| {
+                        suggestions.iter().flat_map(|s| &s.substitutions).any(|subst| {
+                            subst.parts.iter().any(|part| part.span == span.shrink_to_lo())
+                        })
+                    });
+                    if !already_suggested {
+                        err.span_suggestion_verbose(
+                            span.shrink_to_lo(),
+                            "function arguments must have a statically known size, borrowed \
+                             types always have a known size",
+                            "&",
+                            Applicability::MachineApplicable,
+                        );
+                    }
                 } else {
                     err.note("all function arguments must have a statically known size");
                 }
@@ -2905,6 +2955,92 @@ fn suggest_derive(
             );
         }
     }
+
+    /// Suggests an alternative to making a type param `Sized`, depending on where a value of an
+    /// unsized type param is used. Function arguments and fields are handled along with the
+    /// other notes of their obligation cause.
+    fn suggest_indirection_for_unsized_param(
+        &self,
+        obligation: &PredicateObligation<'tcx>,
+        err: &mut Diagnostic,
+        trait_pred: ty::PolyTraitPredicate<'tcx>,
+    ) {
+        if Some(trait_pred.def_id()) != self.tcx.lang_items().sized_trait()
+            || !matches!(trait_pred.skip_binder().self_ty().kind(), ty::Param(_))
+        {
+            return;
+        }
+        let hir = self.tcx.hir();
+        match *obligation.cause.code() {
+            // let x: T = *boxed;
+            //        -   - help: consider borrowing the value: `&`
+            ObligationCauseCode::VariableType(hir_id) => {
+                let Some(Node::Local(local)) = hir.find(hir.get_parent_node(hir_id)) else {
+                    return;
+                };
+                let suggestion: Vec<_> = local
+                    .ty
+                    .map(|ty| ty.span)
+                    .into_iter()
+                    .chain(local.init.map(|init| init.span))
+                    .map(|span| (span.shrink_to_lo(), "&".to_string()))
+                    .collect();
+                if !suggestion.is_empty() {
+                    err.multipart_suggestion_verbose(
+                        "consider borrowing the value, references always have a statically \
+                         known size",
+                        suggestion,
+                        Applicability::MaybeIncorrect,
+                    );
+                }
+            }
+            // fn foo<T: ?Sized>(t: T) { ... }
+            //                    - help: consider borrowing the argument: `&`
+            //                    - help: consider taking a box: `Box<T>`
+            ObligationCauseCode::SizedArgumentType(Some(span))
+                if !trait_pred.skip_binder().self_ty().is_self() =>
+            {
+                // Both change the function for all of its callers, unlike removing the `?Sized`
+                // bound suggested before, so neither of them is applied automatically.
+                err.span_suggestion_verbose(
+                    span.shrink_to_lo(),
+                    "function arguments must have a statically known size, borrowed types \
+                     always have a known size",
+                    "&",
+                    Applicability::MaybeIncorrect,
+                );
+                err.multipart_suggestion_verbose(
+                    "consider taking a `Box`, which always has a statically known size",
+                    vec![
+                        (span.shrink_to_lo(), "Box<".to_string()),
+                        (span.shrink_to_hi(), ">".to_string()),
+                    ],
+                    Applicability::MaybeIncorrect,
+                );
+            }
+            // fn foo<T: ?Sized>() -> T { ... }
+            //                        - help: consider returning a box: `Box<T>`
+            ObligationCauseCode::SizedReturnType => {
+                let Some(hir::FnDecl { output: hir::FnRetTy::Return(ty), .. }) =
+                    hir.fn_decl_by_hir_id(hir.get_parent_node(obligation.cause.body_id))
+                else {
+                    return;
+                };
+                if ty.span != obligation.cause.span {
+                    return;
+                }
+                err.multipart_suggestion_verbose(
+                    "consider returning a `Box`, which always has a statically known size",
+                    vec![
+                        (ty.span.shrink_to_lo(), "Box<".to_string()),
+                        (ty.span.shrink_to_hi(), ">".to_string()),
+                    ],
+                    Applicability::MaybeIncorrect,
+                );
+            }
+            _ => {}
+        }
+    }
 }
 
 /// Collect all the returned expressions within the input expression.
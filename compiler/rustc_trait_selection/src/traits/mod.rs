@@ -26,6 +26,7 @@
 use crate::infer::{InferCtxt, TyCtxtInferExt};
 use crate::traits::error_reporting::InferCtxtExt as _;
 use crate::traits::query::evaluate_obligation::InferCtxtExt as _;
+use rustc_data_structures::fx::FxIndexSet;
 use rustc_errors::ErrorGuaranteed;
 use rustc_hir as hir;
 use rustc_hir::def_id::DefId;
@@ -476,6 +477,49 @@ fn subst_and_check_impossible_predicates<'tcx>(
     result
 }
 
+fn bounds_satisfying_predicate<'tcx>(
+    tcx: TyCtxt<'tcx>,
+    key: ty::ParamEnvAnd<'tcx, ty::PolyTraitPredicate<'tcx>>,
+) -> Option<&'tcx ty::List<ty::Predicate<'tcx>>> {
+    let (param_env, trait_pred) = key.into_parts();
+    let predicate = trait_pred.to_predicate(tcx);
+    tcx.infer_ctxt().enter(|infcx| {
+        let mut fulfill_cx = FulfillmentContext::new();
+        let obligation = Obligation::new(ObligationCause::dummy(), param_env, predicate);
+        fulfill_cx.register_predicate_obligation(&infcx, obligation);
+        let errors = fulfill_cx.select_all_or_error(&infcx);
+        let _ = infcx.inner.borrow_mut().opaque_type_storage.take_opaque_types();
+
+        // The errors are about the innermost obligations which failed, like `T: Clone` for
+        // `Vec<T>: Clone`.
+        let mut bounds = FxIndexSet::default();
+        for error in errors {
+            let CodeSelectionError(_) = error.code else { return None };
+            let bound = infcx.resolve_vars_if_possible(error.obligation.predicate);
+            let ty::PredicateKind::Trait(bound_pred) = bound.kind().skip_binder() else {
+                return None;
+            };
+            if bound.needs_infer()
+                || !matches!(bound_pred.self_ty().kind(), ty::Param(_) | ty::Projection(_))
+            {
+                return None;
+            }
+            bounds.insert(bound);
+        }
+
+        // Make sure that the bounds are enough, as the errors after the first failure of an
+        // obligation may be missing.
+        let caller_bounds =
+            tcx.mk_predicates(param_env.caller_bounds().iter().chain(bounds.iter().copied()));
+        let param_env = ty::ParamEnv::new(caller_bounds, param_env.reveal(), param_env.constness());
+        let obligation = Obligation::new(ObligationCause::dummy(), param_env, predicate);
+        if !infcx.predicate_must_hold_modulo_regions(&obligation) {
+            return None;
+        }
+        Some(tcx.intern_predicates(&bounds.into_iter().collect::<Vec<_>>()))
+    })
+}
+
 #[derive(Clone, Debug)]
 enum VtblSegment<'tcx> {
     MetadataDSA,
@@ -856,6 +900,7 @@ pub fn provide(providers: &mut ty::query::Providers) {
         vtable_entries,
         vtable_trait_upcasting_coercion_new_vptr_slot,
         subst_and_check_impossible_predicates,
+        bounds_satisfying_predicate,
         thir_abstract_const: |tcx, def_id| {
             let def_id = def_id.expect_local();
             if let Some(def) = ty::WithOptConstParam::try_lookup(def_id, tcx) {
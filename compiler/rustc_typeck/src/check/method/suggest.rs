@@ -17,7 +17,9 @@
 use rustc_middle::ty::fast_reject::{simplify_type, TreatParams};
 use rustc_middle::ty::print::with_crate_prefix;
 use rustc_middle::ty::ToPolyTraitRef;
-use rustc_middle::ty::{self, DefIdTree, ToPredicate, Ty, TyCtxt, TypeFoldable};
+use rustc_middle::ty::{
+    self, suggest_bounds_satisfying_predicate, DefIdTree, ToPredicate, Ty, TyCtxt, TypeFoldable,
+};
 use rustc_span::symbol::{kw, sym, Ident};
 use rustc_span::{lev_distance, source_map, ExpnKind, FileName, MacroKind, Span};
 use rustc_trait_selection::traits::error_reporting::on_unimplemented::InferCtxtExt as _;
@@ -829,6 +831,40 @@ trait bound{s}",
                         );
                     }
 
+                    // The bounds on the type params in scope which make a bound on another type
+                    // hold, like `T: Clone` for `Inner<T>: Clone`. Such a type already implements
+                    // the trait, so it isn't suggested to derive it.
+                    let mut satisfiable_predicates = FxHashSet::default();
+                    let body_owner =
+                        self.tcx.hir().body_owner_def_id(hir::BodyId { hir_id: self.body_id });
+                    if !restrict_type_params
+                        && let Some(generics) = self
+                            .tcx
+                            .typeck_root_def_id(body_owner.to_def_id())
+                            .as_local()
+                            .and_then(|def_id| self.tcx.hir().get_generics(def_id))
+                    {
+                        for (pred, parent_pred, _cause) in &unsatisfied_predicates {
+                            if parent_pred.is_none()
+                                && let Some(trait_pred) = pred.to_opt_poly_trait_pred()
+                                && !matches!(
+                                    trait_pred.self_ty().skip_binder().kind(),
+                                    ty::Param(_)
+                                )
+                                && suggest_bounds_satisfying_predicate(
+                                    self.tcx,
+                                    generics,
+                                    &mut err,
+                                    self.param_env,
+                                    trait_pred,
+                                )
+                            {
+                                restrict_type_params = true;
+                                satisfiable_predicates.insert(*pred);
+                            }
+                        }
+                    }
+
                     bound_list.sort_by(|(_, a), (_, b)| a.cmp(b)); // Sort alphabetically.
                     bound_list.dedup_by(|(_, a), (_, b)| a == b); // #35677
                     bound_list.sort_by_key(|(pos, _)| *pos); // Keep the original predicate order.
@@ -875,7 +911,12 @@ trait bound{s}",
                                 "the following trait bounds were not satisfied:\n{bound_list}"
                             ));
                         }
-                        self.suggest_derive(&mut err, &unsatisfied_predicates);
+                        let unimplemented_predicates = unsatisfied_predicates
+                            .iter()
+                            .filter(|(pred, ..)| !satisfiable_predicates.contains(pred))
+                            .cloned()
+                            .collect::<Vec<_>>();
+                        self.suggest_derive(&mut err, &unimplemented_predicates);
 
                         unsatisfied_bounds = true;
                     }
@@ -11,7 +11,9 @@
 use rustc_infer::infer::outlives::env::OutlivesEnvironment;
 use rustc_infer::infer::TyCtxtInferExt;
 use rustc_middle::ty::adjustment::CoerceUnsizedInfo;
-use rustc_middle::ty::{self, suggest_constraining_type_params, Ty, TyCtxt, TypeFoldable};
+use rustc_middle::ty::{
+    self, suggest_constraining_type_params, ConstrainedTy, Ty, TyCtxt, TypeFoldable,
+};
 use rustc_trait_selection::traits::error_reporting::InferCtxtExt;
 use rustc_trait_selection::traits::misc::{can_type_implement_copy, CopyImplementationError};
 use rustc_trait_selection::traits::predicate_for_trait_def;
@@ -144,14 +146,18 @@ fn visit_implementation_of_copy(tcx: TyCtxt<'_>, impl_did: LocalDefId) {
                             ..
                         }) = error_predicate.kind().skip_binder()
                         {
-                            let ty = trait_ref.self_ty();
-                            if let ty::Param(_) = ty.kind() {
-                                bounds.push((
-                                    format!("{ty}"),
-                                    trait_ref.print_only_trait_path().to_string(),
-                                    Some(trait_ref.def_id),
-                                ));
-                            }
+                            let constrained_ty = match trait_ref.self_ty().kind() {
+                                ty::Param(param) => ConstrainedTy::Param(param.name.as_str()),
+                                ty::Projection(projection) => {
+                                    ConstrainedTy::Projection(*projection)
+                                }
+                                _ => continue,
+                            };
+                            bounds.push((
+                                constrained_ty,
+                                trait_ref.print_only_trait_path().to_string(),
+                                Some(trait_ref.def_id),
+                            ));
                         }
                     }
                 });
@@ -168,8 +174,8 @@ fn visit_implementation_of_copy(tcx: TyCtxt<'_>, impl_did: LocalDefId) {
                     tcx,
                     generics,
                     &mut err,
-                    bounds.iter().map(|(param, constraint, def_id)| {
-                        (param.as_str(), constraint.as_str(), *def_id)
+                    bounds.iter().map(|(constrained_ty, constraint, def_id)| {
+                        (*constrained_ty, constraint.as_str(), *def_id)
                     }),
                 );
             }
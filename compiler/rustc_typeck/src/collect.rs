@@ -36,7 +36,7 @@
 use rustc_middle::middle::codegen_fn_attrs::{CodegenFnAttrFlags, CodegenFnAttrs};
 use rustc_middle::mir::mono::Linkage;
 use rustc_middle::ty::query::Providers;
-use rustc_middle::ty::subst::InternalSubsts;
+use rustc_middle::ty::subst::{GenericArgKind, InternalSubsts};
 use rustc_middle::ty::util::Discr;
 use rustc_middle::ty::util::IntTypeExt;
 use rustc_middle::ty::{self, AdtKind, Const, DefIdTree, IsSuggestable, Ty, TyCtxt};
@@ -567,7 +567,7 @@ fn type_param_predicates(
     let param_owner = tcx.hir().ty_param_owner(def_id);
     let generics = tcx.generics_of(param_owner);
     let index = generics.param_def_id_to_index[&def_id.to_def_id()];
-    let ty = tcx.mk_ty_param(index, tcx.hir().ty_param_name(def_id));
+    let ty = ty::ParamTy::for_def(generics.param_at(index as usize, tcx)).to_ty(tcx);
 
     // Don't look for bounds where the type parameter isn't in scope.
     let parent = if item_def_id == param_owner.to_def_id() {
@@ -1927,29 +1927,35 @@ fn infer_return_ty_for_fn_sig<'tcx>(
             visitor.visit_ty(ty);
             let mut diag = bad_placeholder(tcx, visitor.0, "return type");
             let ret_ty = fn_sig.skip_binder().output();
-            if ret_ty.is_suggestable(tcx) {
+            if let ty::Closure(_, substs) = *ret_ty.kind()
+                && let Some(impl_trait) = ty::closure_as_impl_trait(tcx, substs.as_closure())
+            {
+                // The regions of the signature were erased, so a closure capturing a reference,
+                // which needs a `+ '_`, can't be told from one capturing a `&'static`.
+                let captures_lifetime = substs
+                    .as_closure()
+                    .tupled_upvars_ty()
+                    .walk()
+                    .any(|arg| matches!(arg.unpack(), GenericArgKind::Lifetime(_)));
+                diag.span_suggestion(
+                    ty.span,
+                    "replace with the correct return type",
+                    impl_trait,
+                    if captures_lifetime {
+                        Applicability::MaybeIncorrect
+                    } else {
+                        Applicability::MachineApplicable
+                    },
+                );
+            } else if let Some(ret_ty) = ret_ty.make_suggestable(tcx) {
+                // The types of the signature are fully inferred, so there's no `_` in the
+                // suggestion.
                 diag.span_suggestion(
                     ty.span,
                     "replace with the correct return type",
                     ret_ty,
                     Applicability::MachineApplicable,
                 );
-            } else if matches!(ret_ty.kind(), ty::FnDef(..)) {
-                let fn_sig = ret_ty.fn_sig(tcx);
-                if fn_sig.skip_binder().inputs_and_output.iter().all(|t| t.is_suggestable(tcx)) {
-                    diag.span_suggestion(
-                        ty.span,
-                        "replace with the correct return type",
-                        fn_sig,
-                        Applicability::MachineApplicable,
-                    );
-                }
-            } else if ret_ty.is_closure() {
-                // We're dealing with a closure, so we should suggest using `impl Fn` or trait bounds
-                // to prevent the user from getting a papercut while trying to use the unique closure
-                // syntax (e.g. `[closure@src/lib.rs:2:5: 2:9]`).
-                diag.help("consider using an `Fn`, `FnMut`, or `FnOnce` trait bound");
-                diag.note("for more information on `Fn` traits and closure types, see https://doc.rust-lang.org/book/ch13-01-closures.html");
             }
             diag.emit();
 
@@ -2221,9 +2227,9 @@ fn gather_explicit_predicates_of(tcx: TyCtxt<'_>, def_id: DefId) -> ty::GenericP
         match param.kind {
             // We already dealt with early bound lifetimes above.
             GenericParamKind::Lifetime { .. } => (),
-            GenericParamKind::Type { .. } => {
+            GenericParamKind::Type { synthetic, .. } => {
                 let name = param.name.ident().name;
-                let param_ty = ty::ParamTy::new(index, name).to_ty(tcx);
+                let param_ty = ty::ParamTy { index, name, synthetic }.to_ty(tcx);
                 index += 1;
 
                 let mut bounds = Bounds::default();
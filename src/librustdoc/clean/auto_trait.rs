@@ -1,8 +1,11 @@
 use rustc_data_structures::fx::FxHashSet;
 use rustc_hir as hir;
 use rustc_hir::lang_items::LangItem;
+use rustc_infer::infer::TyCtxtInferExt;
+use rustc_infer::traits::{ImplSource, Obligation, ObligationCause};
 use rustc_middle::ty::{self, Region, RegionVid, TypeFoldable, TypeSuperFoldable};
 use rustc_trait_selection::traits::auto_trait::{self, AutoTraitResult};
+use rustc_trait_selection::traits::SelectionContext;
 
 use std::fmt::Debug;
 
@@ -77,6 +80,7 @@ fn generate_for_trait(
         });
 
         let polarity;
+        let (mut blocking_fields, mut private_blocking_fields) = (Vec::new(), 0);
         let new_generics = match result {
             AutoTraitResult::PositiveImpl(new_generics) => {
                 polarity = ty::ImplPolarity::Positive;
@@ -106,10 +110,12 @@ fn generate_for_trait(
                     ty::GenericPredicates::default(),
                 );
                 let params = raw_generics.params;
+                (blocking_fields, private_blocking_fields) =
+                    self.blocking_fields(ty, trait_def_id, param_env, f);
 
                 Generics { params, where_predicates: Vec::new() }
             }
-            AutoTraitResult::ExplicitImpl => return None,
+            AutoTraitResult::ExplicitImpl | AutoTraitResult::OutOfFuel => return None,
         };
 
         Some(Item {
@@ -124,17 +130,54 @@ fn generate_for_trait(
                 for_: ty.clean(self.cx),
                 items: Vec::new(),
                 polarity,
-                kind: ImplKind::Auto,
+                kind: ImplKind::Auto { blocking_fields, private_blocking_fields },
             }),
             cfg: None,
         })
     }
 
+    /// Finds the fields of `ty` which keep it from ever implementing the auto trait, so that
+    /// users can tell why it has a negative impl. Only the fields which are documented are
+    /// returned, the private ones are only counted.
+    fn blocking_fields(
+        &mut self,
+        ty: Ty<'tcx>,
+        trait_def_id: DefId,
+        param_env: ty::ParamEnv<'tcx>,
+        f: &auto_trait::AutoTraitFinder<'tcx>,
+    ) -> (Vec<(Symbol, Type)>, usize) {
+        let tcx = self.cx.tcx;
+        let ty::Adt(adt, substs) = *ty.kind() else { return (Vec::new(), 0) };
+        let (documented, private): (Vec<_>, Vec<_>) = adt
+            .all_fields()
+            .filter(|field| {
+                let field_ty = field.ty(tcx, substs);
+                match f.find_auto_trait_generics(field_ty, param_env, trait_def_id, |_| ()) {
+                    AutoTraitResult::NegativeImpl => true,
+                    AutoTraitResult::ExplicitImpl => {
+                        has_negative_impl(tcx, field_ty, trait_def_id, param_env)
+                    }
+                    AutoTraitResult::PositiveImpl(()) | AutoTraitResult::OutOfFuel => false,
+                }
+            })
+            .partition(|field| {
+                field.vis.is_public() || self.cx.render_options.document_private
+            });
+        let documented = documented
+            .into_iter()
+            .map(|field| (field.name, field.ty(tcx, substs).clean(self.cx)))
+            .collect();
+        (documented, private.len())
+    }
+
     pub(crate) fn get_auto_trait_impls(&mut self, item_def_id: DefId) -> Vec<Item> {
         let tcx = self.cx.tcx;
         let param_env = tcx.param_env(item_def_id);
         let ty = tcx.type_of(item_def_id);
-        let f = auto_trait::AutoTraitFinder::new(tcx);
+        let f = match self.cx.render_options.auto_trait_fuel {
+            Some(fuel) => auto_trait::AutoTraitFinder::with_fuel(tcx, fuel),
+            None => auto_trait::AutoTraitFinder::new(tcx),
+        };
 
         debug!("get_auto_trait_impls({:?})", ty);
         let auto_traits: Vec<_> = self.cx.auto_traits.iter().copied().collect();
@@ -153,6 +196,23 @@ pub(crate) fn get_auto_trait_impls(&mut self, item_def_id: DefId) -> Vec<Item> {
                 auto_traits.push(item);
             }
         }
+        // Showing only some of the auto trait impls would be misleading, so show none of them.
+        if f.is_out_of_fuel() {
+            let fuel = self.cx.render_options.auto_trait_fuel.unwrap_or_default();
+            self.cx
+                .sess()
+                .struct_span_warn(
+                    tcx.def_span(item_def_id),
+                    &format!(
+                        "not documenting the auto trait implementations of `{}`",
+                        tcx.def_path_str(item_def_id)
+                    ),
+                )
+                .note(&format!("finding them took more than {} steps", fuel))
+                .help("the limit can be raised with `-Z unstable-options --auto-trait-fuel`")
+                .emit();
+            return Vec::new();
+        }
         auto_traits
     }
 
@@ -505,7 +565,7 @@ fn param_env_to_generics(
                         .and_then(|trait_| {
                             ty_to_traits
                                 .get(&ty)
-                                .map(|bounds| bounds.contains(&strip_path_generics(trait_)))
+                                .map(|bounds| bounds.contains(&strip_path_generics(trait_, true)))
                         })
                         .unwrap_or(false)
                     {
@@ -602,7 +662,10 @@ fn param_env_to_generics(
                             ));
                             // Avoid creating any new duplicate bounds later in the outer
                             // loop
-                            ty_to_traits.entry(*ty.clone()).or_default().insert(trait_.clone());
+                            ty_to_traits
+                                .entry(*ty.clone())
+                                .or_default()
+                                .insert(strip_path_generics(trait_.clone(), true));
                         }
                         _ => panic!("Unexpected LHS {:?} for {:?}", lhs, item_def_id),
                     }
@@ -729,3 +792,24 @@ fn fold_region(&mut self, r: ty::Region<'tcx>) -> ty::Region<'tcx> {
         .unwrap_or_else(|| r.super_fold_with(self))
     }
 }
+
+/// Whether `ty` has an explicit negative impl of the auto trait, like `impl<T> !Send for Rc<T>`.
+fn has_negative_impl<'tcx>(
+    tcx: TyCtxt<'tcx>,
+    ty: Ty<'tcx>,
+    trait_def_id: DefId,
+    param_env: ty::ParamEnv<'tcx>,
+) -> bool {
+    let trait_ref = ty::TraitRef { def_id: trait_def_id, substs: tcx.mk_substs_trait(ty, &[]) };
+    let obligation = Obligation::new(
+        ObligationCause::dummy(),
+        param_env,
+        ty::Binder::dummy(trait_ref).to_poly_trait_predicate_negative_polarity(),
+    );
+    tcx.infer_ctxt().enter(|infcx| {
+        matches!(
+            SelectionContext::new(&infcx).select(&obligation),
+            Ok(Some(ImplSource::UserDefined(_)))
+        )
+    })
+}
@@ -1,4 +1,5 @@
 use crate::rustc_trait_selection::traits::query::evaluate_obligation::InferCtxtExt;
+use rustc_data_structures::sync::{par_iter, ParallelIterator};
 use rustc_hir as hir;
 use rustc_infer::infer::{InferOk, TyCtxtInferExt};
 use rustc_infer::traits;
@@ -20,117 +21,146 @@ pub(crate) fn get_blanket_impls(&mut self, item_def_id: DefId) -> Vec<Item> {
         trace!("get_blanket_impls({:?})", ty);
         let mut impls = Vec::new();
         self.cx.with_all_traits(|cx, all_traits| {
-            for &trait_def_id in all_traits {
-                if !cx.cache.access_levels.is_public(trait_def_id)
-                    || cx.generated_synthetics.get(&(ty.0, trait_def_id)).is_some()
-                {
-                    continue;
-                }
-                // NOTE: doesn't use `for_each_relevant_impl` to avoid looking at anything besides blanket impls
-                let trait_impls = cx.tcx.trait_impls_of(trait_def_id);
-                for &impl_def_id in trait_impls.blanket_impls() {
-                    trace!(
-                        "get_blanket_impls: Considering impl for trait '{:?}' {:?}",
-                        trait_def_id,
-                        impl_def_id
-                    );
-                    let trait_ref = cx.tcx.bound_impl_trait_ref(impl_def_id).unwrap();
-                    let is_param = matches!(trait_ref.0.self_ty().kind(), ty::Param(_));
-                    let may_apply = is_param && cx.tcx.infer_ctxt().enter(|infcx| {
-                        let substs = infcx.fresh_substs_for_item(DUMMY_SP, item_def_id);
-                        let ty = ty.subst(infcx.tcx, substs);
-                        let param_env = EarlyBinder(param_env).subst(infcx.tcx, substs);
-
-                        let impl_substs = infcx.fresh_substs_for_item(DUMMY_SP, impl_def_id);
-                        let trait_ref = trait_ref.subst(infcx.tcx, impl_substs);
-
-                        // Require the type the impl is implemented on to match
-                        // our type, and ignore the impl if there was a mismatch.
-                        let cause = traits::ObligationCause::dummy();
-                        let eq_result = infcx.at(&cause, param_env).eq(trait_ref.self_ty(), ty);
-                        if let Ok(InferOk { value: (), obligations }) = eq_result {
-                            // FIXME(eddyb) ignoring `obligations` might cause false positives.
-                            drop(obligations);
+            let mut traits: Vec<DefId> = all_traits
+                .iter()
+                .copied()
+                .filter(|&trait_def_id| {
+                    cx.generated_synthetics.get(&(ty.0, trait_def_id)).is_none()
+                        && !cx.tcx.trait_impls_of(trait_def_id).blanket_impls().is_empty()
+                })
+                .collect();
+            // Only the crates of the traits with blanket impls need their reachability analyzed.
+            cx.visit_extern_crates(traits.iter().map(|trait_def_id| trait_def_id.krate));
+            traits.retain(|&trait_def_id| cx.cache.access_levels.is_public(trait_def_id));
 
+            // Checking whether a blanket impl applies only needs the `TyCtxt`, so it can be done
+            // in parallel. Cleaning the applicable impls needs the `DocContext` and stays serial.
+            let tcx = cx.tcx;
+            let applicable: Vec<(DefId, DefId)> = par_iter(&traits[..])
+                .flat_map(|&trait_def_id| {
+                    // NOTE: doesn't use `for_each_relevant_impl` to avoid looking at anything
+                    // besides blanket impls
+                    tcx.trait_impls_of(trait_def_id)
+                        .blanket_impls()
+                        .iter()
+                        .filter(|&&impl_def_id| {
                             trace!(
-                                "invoking predicate_may_hold: param_env={:?}, trait_ref={:?}, ty={:?}",
-                                param_env,
-                                trait_ref,
-                                ty
+                                "get_blanket_impls: Considering impl for trait '{:?}' {:?}",
+                                trait_def_id,
+                                impl_def_id
                             );
-                            let predicates = cx
-                                .tcx
-                                .predicates_of(impl_def_id)
-                                .instantiate(cx.tcx, impl_substs)
-                                .predicates
-                                .into_iter()
-                                .chain(Some(
-                                    ty::Binder::dummy(trait_ref)
-                                        .to_poly_trait_predicate()
-                                        .map_bound(ty::PredicateKind::Trait)
-                                        .to_predicate(infcx.tcx),
-                                ));
-                            for predicate in predicates {
-                                debug!("testing predicate {:?}", predicate);
-                                let obligation = traits::Obligation::new(
-                                    traits::ObligationCause::dummy(),
-                                    param_env,
-                                    predicate,
-                                );
-                                match infcx.evaluate_obligation(&obligation) {
-                                    Ok(eval_result) if eval_result.may_apply() => {}
-                                    Err(traits::OverflowError::Canonical) => {}
-                                    Err(traits::OverflowError::ErrorReporting) => {}
-                                    _ => {
-                                        return false;
-                                    }
-                                }
-                            }
-                            true
-                        } else {
-                            false
-                        }
-                    });
-                    debug!(
-                        "get_blanket_impls: found applicable impl: {} for trait_ref={:?}, ty={:?}",
-                        may_apply, trait_ref, ty
-                    );
-                    if !may_apply {
-                        continue;
-                    }
+                            may_apply(tcx, param_env, ty, item_def_id, impl_def_id)
+                        })
+                        .map(|&impl_def_id| (trait_def_id, impl_def_id))
+                        .collect::<Vec<_>>()
+                })
+                .collect();
 
-                    cx.generated_synthetics.insert((ty.0, trait_def_id));
+            for (trait_def_id, impl_def_id) in applicable {
+                let trait_ref = cx.tcx.bound_impl_trait_ref(impl_def_id).unwrap();
+                cx.generated_synthetics.insert((ty.0, trait_def_id));
 
-                    impls.push(Item {
-                        name: None,
-                        attrs: Default::default(),
-                        visibility: Inherited,
-                        item_id: ItemId::Blanket { impl_id: impl_def_id, for_: item_def_id },
-                        kind: box ImplItem(Impl {
-                            unsafety: hir::Unsafety::Normal,
-                            generics: clean_ty_generics(
-                                cx,
-                                cx.tcx.generics_of(impl_def_id),
-                                cx.tcx.explicit_predicates_of(impl_def_id),
-                            ),
-                            // FIXME(eddyb) compute both `trait_` and `for_` from
-                            // the post-inference `trait_ref`, as it's more accurate.
-                            trait_: Some(trait_ref.0.clean(cx)),
-                            for_: ty.0.clean(cx),
-                            items: cx.tcx
-                                .associated_items(impl_def_id)
-                                .in_definition_order()
-                                .map(|x| x.clean(cx))
-                                .collect::<Vec<_>>(),
-                            polarity: ty::ImplPolarity::Positive,
-                            kind: ImplKind::Blanket(box trait_ref.0.self_ty().clean(cx)),
-                        }),
-                        cfg: None,
-                    });
-                }
+                impls.push(Item {
+                    name: None,
+                    attrs: Default::default(),
+                    visibility: Inherited,
+                    item_id: ItemId::Blanket { impl_id: impl_def_id, for_: item_def_id },
+                    kind: box ImplItem(Impl {
+                        unsafety: hir::Unsafety::Normal,
+                        generics: clean_ty_generics(
+                            cx,
+                            cx.tcx.generics_of(impl_def_id),
+                            cx.tcx.explicit_predicates_of(impl_def_id),
+                        ),
+                        // FIXME(eddyb) compute both `trait_` and `for_` from
+                        // the post-inference `trait_ref`, as it's more accurate.
+                        trait_: Some(trait_ref.0.clean(cx)),
+                        for_: ty.0.clean(cx),
+                        items: cx.tcx
+                            .associated_items(impl_def_id)
+                            .in_definition_order()
+                            .map(|x| x.clean(cx))
+                            .collect::<Vec<_>>(),
+                        polarity: ty::ImplPolarity::Positive,
+                        kind: ImplKind::Blanket(box trait_ref.0.self_ty().clean(cx)),
+                    }),
+                    cfg: None,
+                });
             }
         });
 
         impls
     }
 }
+
+/// Checks whether the blanket impl `impl_def_id` may apply to the type of `item_def_id`.
+fn may_apply<'tcx>(
+    tcx: TyCtxt<'tcx>,
+    param_env: ty::ParamEnv<'tcx>,
+    ty: EarlyBinder<Ty<'tcx>>,
+    item_def_id: DefId,
+    impl_def_id: DefId,
+) -> bool {
+    let trait_ref = tcx.bound_impl_trait_ref(impl_def_id).unwrap();
+    let is_param = matches!(trait_ref.0.self_ty().kind(), ty::Param(_));
+    let may_apply = is_param
+        && tcx.infer_ctxt().enter(|infcx| {
+            let substs = infcx.fresh_substs_for_item(DUMMY_SP, item_def_id);
+            let ty = ty.subst(infcx.tcx, substs);
+            let param_env = EarlyBinder(param_env).subst(infcx.tcx, substs);
+
+            let impl_substs = infcx.fresh_substs_for_item(DUMMY_SP, impl_def_id);
+            let trait_ref = trait_ref.subst(infcx.tcx, impl_substs);
+
+            // Require the type the impl is implemented on to match
+            // our type, and ignore the impl if there was a mismatch.
+            let cause = traits::ObligationCause::dummy();
+            let eq_result = infcx.at(&cause, param_env).eq(trait_ref.self_ty(), ty);
+            if let Ok(InferOk { value: (), obligations }) = eq_result {
+                // FIXME(eddyb) ignoring `obligations` might cause false positives.
+                drop(obligations);
+
+                trace!(
+                    "invoking predicate_may_hold: param_env={:?}, trait_ref={:?}, ty={:?}",
+                    param_env,
+                    trait_ref,
+                    ty
+                );
+                let predicates = tcx
+                    .predicates_of(impl_def_id)
+                    .instantiate(tcx, impl_substs)
+                    .predicates
+                    .into_iter()
+                    .chain(Some(
+                        ty::Binder::dummy(trait_ref)
+                            .to_poly_trait_predicate()
+                            .map_bound(ty::PredicateKind::Trait)
+                            .to_predicate(infcx.tcx),
+                    ));
+                for predicate in predicates {
+                    debug!("testing predicate {:?}", predicate);
+                    let obligation = traits::Obligation::new(
+                        traits::ObligationCause::dummy(),
+                        param_env,
+                        predicate,
+                    );
+                    match infcx.evaluate_obligation(&obligation) {
+                        Ok(eval_result) if eval_result.may_apply() => {}
+                        Err(traits::OverflowError::Canonical) => {}
+                        Err(traits::OverflowError::ErrorReporting) => {}
+                        _ => {
+                            return false;
+                        }
+                    }
+                }
+                true
+            } else {
+                false
+            }
+        });
+    debug!(
+        "get_blanket_impls: found applicable impl: {} for trait_ref={:?}, ty={:?}",
+        may_apply, trait_ref, ty
+    );
+    may_apply
+}
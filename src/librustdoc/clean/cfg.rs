@@ -10,6 +10,7 @@
 use rustc_ast::{LitKind, MetaItem, MetaItemKind, NestedMetaItem};
 use rustc_data_structures::fx::FxHashSet;
 use rustc_feature::Features;
+use rustc_macros::{Decodable, Encodable};
 use rustc_session::parse::ParseSess;
 use rustc_span::symbol::{sym, Symbol};
 
@@ -20,7 +21,7 @@
 #[cfg(test)]
 mod tests;
 
-#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Encodable, Decodable)]
 pub(crate) enum Cfg {
     /// Accepts all configurations.
     True,
@@ -249,6 +250,28 @@ pub(crate) fn simplify_with(&self, assume: &Cfg) -> Option<Cfg> {
 
         Some(self.clone())
     }
+
+    /// Returns the crate features which must all be enabled for this cfg to hold, that is the
+    /// `feature = "..."` options which aren't inside an `any` or a `not`.
+    ///
+    /// See `tests::test_required_features` for examples.
+    pub(crate) fn required_features(&self) -> Vec<Symbol> {
+        fn collect(cfg: &Cfg, features: &mut Vec<Symbol>) {
+            match *cfg {
+                Cfg::Cfg(sym::feature, Some(feature)) if !features.contains(&feature) => {
+                    features.push(feature)
+                }
+                Cfg::All(ref sub_cfgs) => {
+                    sub_cfgs.iter().for_each(|sub_cfg| collect(sub_cfg, features))
+                }
+                _ => {}
+            }
+        }
+
+        let mut features = Vec::new();
+        collect(self, &mut features);
+        features
+    }
 }
 
 impl ops::Not for Cfg {
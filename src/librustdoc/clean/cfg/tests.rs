@@ -465,3 +465,25 @@ fn test_simplify_with() {
         assert_eq!(foobar.simplify_with(&foobarbaz), None);
     });
 }
+
+#[test]
+fn test_required_features() {
+    create_default_session_globals_then(|| {
+        let feature = |name| name_value_cfg("feature", name);
+        let unix = word_cfg("unix");
+
+        assert_eq!(feature("a").required_features(), [Symbol::intern("a")]);
+        assert!(unix.required_features().is_empty());
+
+        // All the features of an `all` are required, once.
+        assert_eq!(
+            Cfg::All(vec![feature("a"), unix.clone(), feature("b"), feature("a")])
+                .required_features(),
+            [Symbol::intern("a"), Symbol::intern("b")]
+        );
+
+        // Neither feature of an `any` is required, nor a negated one.
+        assert!(Cfg::Any(vec![feature("a"), feature("b")]).required_features().is_empty());
+        assert!((!feature("a")).required_features().is_empty());
+    });
+}
@@ -21,6 +21,7 @@
 };
 use crate::core::DocContext;
 use crate::formats::item_type::ItemType;
+use crate::trait_cache::{CachedTrait, RecordedExtern};
 
 type Attrs<'hir> = &'hir [ast::Attribute];
 
@@ -62,6 +63,10 @@ pub(crate) fn try_inline(
             build_impls(cx, Some(parent_module), did, attrs, &mut ret);
             clean::TraitItem(build_external_trait(cx, did))
         }
+        Res::Def(DefKind::TraitAlias, did) => {
+            record_extern_fqn(cx, did, ItemType::TraitAlias);
+            clean::TraitAliasItem(build_external_trait_alias(cx, did))
+        }
         Res::Def(DefKind::Fn, did) => {
             record_extern_fqn(cx, did, ItemType::Function);
             clean::FunctionItem(build_external_function(cx, did))
@@ -106,7 +111,9 @@ pub(crate) fn try_inline(
         }
         Res::Def(DefKind::Const, did) => {
             record_extern_fqn(cx, did, ItemType::Constant);
-            clean::ConstantItem(build_const(cx, did))
+            let constant = build_const(cx, did);
+            let repr = clean::ConstantExprAndValue::new(constant.kind.clone());
+            clean::ConstantItem(constant, repr)
         }
         Res::Def(DefKind::Macro(kind), did) => {
             let mac = build_macro(cx, did, name, import_def_id);
@@ -163,6 +170,7 @@ pub(crate) fn load_attrs<'hir>(cx: &DocContext<'hir>, did: DefId) -> Attrs<'hir>
 /// These names are used later on by HTML rendering to generate things like
 /// source links back to the original item.
 pub(crate) fn record_extern_fqn(cx: &mut DocContext<'_>, did: DefId, kind: ItemType) {
+    cx.record_extern(RecordedExtern::Fqn(did, kind));
     let crate_name = cx.tcx.crate_name(did.krate);
 
     let relative =
@@ -186,6 +194,8 @@ pub(crate) fn record_extern_fqn(cx: &mut DocContext<'_>, did: DefId, kind: ItemT
     if did.is_local() {
         cx.cache.exact_paths.insert(did, fqn);
     } else {
+        // `href` needs to know whether the item is reachable to link to it.
+        cx.visit_extern_crates([did.krate]);
         cx.cache.external_paths.insert(did, (fqn, kind));
     }
 }
@@ -218,6 +228,13 @@ pub(crate) fn build_external_trait(cx: &mut DocContext<'_>, did: DefId) -> clean
     }
 }
 
+fn build_external_trait_alias(cx: &mut DocContext<'_>, did: DefId) -> clean::TraitAlias {
+    let predicates = cx.tcx.predicates_of(did);
+    let generics = clean_ty_generics(cx, cx.tcx.generics_of(did), predicates);
+    let (generics, bounds) = separate_supertrait_bounds(filter_non_trait_generics(did, generics));
+    clean::TraitAlias { generics, bounds }
+}
+
 fn build_external_function<'tcx>(cx: &mut DocContext<'tcx>, did: DefId) -> clean::Function {
     let sig = cx.tcx.fn_sig(did);
 
@@ -308,10 +325,10 @@ fn merge_attrs(
             } else {
                 Attributes::from_ast(&both, None)
             },
-            both.cfg(cx.tcx, &cx.cache.hidden_cfg),
+            both.cfg(cx.tcx, &cx.cache),
         )
     } else {
-        (old_attrs.clean(cx), old_attrs.cfg(cx.tcx, &cx.cache.hidden_cfg))
+        (old_attrs.clean(cx), old_attrs.cfg(cx.tcx, &cx.cache))
     }
 }
 
@@ -337,6 +354,7 @@ pub(crate) fn build_impl(
     if !did.is_local() {
         if let Some(traitref) = associated_trait {
             let did = traitref.def_id;
+            cx.visit_extern_crates([did.krate]);
             if !cx.cache.access_levels.is_public(did) {
                 return;
             }
@@ -366,6 +384,7 @@ pub(crate) fn build_impl(
     // reachable in rustdoc generated documentation
     if !did.is_local() {
         if let Some(did) = for_.def_id(&cx.cache) {
+            cx.visit_extern_crates([did.krate]);
             if !cx.cache.access_levels.is_public(did) {
                 return;
             }
@@ -552,6 +571,7 @@ fn build_module(
                                 }],
                             },
                             did: None,
+                            reexport_chain: Vec::new(),
                         },
                         true,
                     )),
@@ -601,8 +621,11 @@ fn build_macro(
         LoadedMacro::MacroDef(item_def, _) => {
             if let ast::ItemKind::MacroDef(ref def) = item_def.kind {
                 let vis = cx.tcx.visibility(import_def_id.unwrap_or(def_id)).clean(cx);
+                let (source, arms) = utils::display_macro_source(cx, name, def, def_id, vis);
                 clean::MacroItem(clean::Macro {
-                    source: utils::display_macro_source(cx, name, def, def_id, vis),
+                    source,
+                    arms,
+                    matchers: utils::macro_matchers(def),
                 })
             } else {
                 unreachable!()
@@ -611,6 +634,7 @@ fn build_macro(
         LoadedMacro::ProcMacro(ext) => clean::ProcMacroItem(clean::ProcMacro {
             kind: ext.macro_kind(),
             helpers: ext.helper_attrs,
+            derived_trait: None,
         }),
     }
 }
@@ -676,6 +700,7 @@ pub(crate) fn record_extern_trait(cx: &mut DocContext<'_>, did: DefId) {
     if did.is_local() {
         return;
     }
+    cx.record_extern(RecordedExtern::Trait(did));
 
     {
         if cx.external_traits.borrow().contains_key(&did) || cx.active_extern_traits.contains(&did)
@@ -689,12 +714,53 @@ pub(crate) fn record_extern_trait(cx: &mut DocContext<'_>, did: DefId) {
     }
 
     debug!("record_extern_trait: {:?}", did);
-    let trait_ = build_external_trait(cx, did);
-
-    let trait_ = clean::TraitWithExtraInfo {
-        trait_,
-        is_notable: clean::utils::has_doc_flag(cx.tcx, did, sym::notable_trait),
+    let cached = match &mut cx.trait_cache {
+        Some(trait_cache) => trait_cache.get(cx.tcx, &cx.cache, did),
+        None => None,
+    };
+    let trait_ = if let Some(cached) = cached {
+        // Replay what cleaning the trait recorded, the documentation of the trait refers to it.
+        for recorded in cached.recorded {
+            match recorded {
+                RecordedExtern::Fqn(did, kind) => record_extern_fqn(cx, did, kind),
+                RecordedExtern::Trait(did) => record_extern_trait(cx, did),
+                RecordedExtern::TraitAlias(did) => record_extern_trait_alias(cx, did),
+            }
+        }
+        cached.trait_
+    } else {
+        let caching = cx.trait_cache.is_some();
+        if caching {
+            cx.recorded_externs.push(Vec::new());
+        }
+        let trait_ = clean::TraitWithExtraInfo {
+            trait_: build_external_trait(cx, did),
+            is_notable: clean::utils::has_doc_flag(cx.tcx, did, sym::notable_trait),
+        };
+        if caching {
+            let recorded = cx.recorded_externs.pop().unwrap();
+            let cached = CachedTrait { trait_: trait_.clone(), recorded };
+            cx.trait_cache.as_mut().unwrap().insert(cx.tcx, &cx.cache, did, cached);
+        }
+        trait_
     };
+
     cx.external_traits.borrow_mut().insert(did, trait_);
     cx.active_extern_traits.remove(&did);
 }
+
+/// Records the definition of a trait alias from another crate, so that the bounds using it can
+/// show what it expands to.
+pub(crate) fn record_extern_trait_alias(cx: &mut DocContext<'_>, did: DefId) {
+    if did.is_local() {
+        return;
+    }
+    cx.record_extern(RecordedExtern::TraitAlias(did));
+    if cx.cache.trait_aliases.contains_key(&did) {
+        return;
+    }
+
+    debug!("record_extern_trait_alias: {:?}", did);
+    let alias = build_external_trait_alias(cx, did);
+    cx.cache.trait_aliases.insert(did, alias);
+}
@@ -13,9 +13,10 @@
 use rustc_ast as ast;
 use rustc_attr as attr;
 use rustc_data_structures::fx::{FxHashMap, FxHashSet};
+use rustc_data_structures::thin_vec::ThinVec;
 use rustc_hir as hir;
 use rustc_hir::def::{CtorKind, DefKind, Res};
-use rustc_hir::def_id::{DefId, LOCAL_CRATE};
+use rustc_hir::def_id::{DefId, LocalDefId, LOCAL_CRATE};
 use rustc_hir::PredicateOrigin;
 use rustc_infer::infer::region_constraints::{Constraint, RegionConstraintData};
 use rustc_middle::middle::resolve_lifetime as rl;
@@ -29,12 +30,14 @@
 use rustc_typeck::hir_ty_to_ty;
 
 use std::assert_matches::assert_matches;
+use std::cmp::Reverse;
 use std::collections::hash_map::Entry;
 use std::collections::BTreeMap;
 use std::default::Default;
 use std::hash::Hash;
 use std::{mem, vec};
 
+use crate::config::ItemOrder;
 use crate::core::{self, DocContext, ImplTraitParam};
 use crate::formats::item_type::ItemType;
 use crate::visit_ast::Module as DocModule;
@@ -50,18 +53,36 @@ pub(crate) trait Clean<'tcx, T> {
 
 impl<'tcx> Clean<'tcx, Item> for DocModule<'tcx> {
     fn clean(&self, cx: &mut DocContext<'tcx>) -> Item {
-        let mut items: Vec<Item> = vec![];
-        items.extend(
-            self.foreigns
-                .iter()
-                .map(|(item, renamed)| clean_maybe_renamed_foreign_item(cx, item, *renamed)),
-        );
-        items.extend(self.mods.iter().map(|x| x.clean(cx)));
-        items.extend(
-            self.items
-                .iter()
-                .flat_map(|(item, renamed)| clean_maybe_renamed_item(cx, item, *renamed)),
-        );
+        let tcx = cx.tcx;
+        let mut items: Vec<(rustc_span::Span, Item)> = vec![];
+        items.extend(self.foreigns.iter().map(|(item, renamed)| {
+            (item.span, clean_maybe_renamed_foreign_item(cx, item, *renamed))
+        }));
+        items.extend(self.mods.iter().map(|x| (x.where_outer(tcx), x.clean(cx))));
+        items.extend(self.items.iter().flat_map(|(item, renamed)| {
+            clean_maybe_renamed_item(cx, item, *renamed).into_iter().map(move |i| (item.span, i))
+        }));
+        // Otherwise, the items are sorted by name when rendering the module.
+        if cx.cache.item_order == ItemOrder::Source {
+            items.sort_by_key(|(span, _)| span.source_callsite().lo());
+        }
+        let mut items: Vec<Item> = items.into_iter().map(|(_, item)| item).collect();
+
+        // Items inlined from other modules depend on the `cfg`s of the `use` they were inlined
+        // through as well.
+        let module_did = cx.tcx.hir().local_def_id(self.id);
+        for item in &mut items {
+            if let Some(did) = item.item_id.as_def_id()
+                && did.is_local()
+                && let Some(name) = item.name
+                && cx.tcx.opt_parent(did) != Some(module_did.to_def_id())
+                && let Some(import) = find_inlining_use(cx.tcx, module_did, did, name)
+                && let hir::ItemKind::Use(path, _) = import.kind
+            {
+                let chain = reexport_chain(cx.tcx, path);
+                add_reexport_cfg(cx, item, import.def_id.to_def_id(), &chain);
+            }
+        }
 
         // determine if we should display the inner contents or
         // the outer `mod` item for the source code.
@@ -89,6 +110,24 @@ fn clean(&self, cx: &mut DocContext<'tcx>) -> Item {
     }
 }
 
+/// Finds the `use` item of `module` through which `did` was inlined into it as `name`.
+fn find_inlining_use<'hir>(
+    tcx: TyCtxt<'hir>,
+    module: LocalDefId,
+    did: DefId,
+    name: Symbol,
+) -> Option<&'hir hir::Item<'hir>> {
+    tcx.hir().module_items(module).map(|id| tcx.hir().item(id)).find(|item| match item.kind {
+        hir::ItemKind::Use(path, hir::UseKind::Single) => {
+            item.ident.name == name && path.res.opt_def_id() == Some(did)
+        }
+        hir::ItemKind::Use(path, hir::UseKind::Glob) => {
+            path.res.opt_def_id() == tcx.opt_parent(did)
+        }
+        _ => false,
+    })
+}
+
 impl<'tcx> Clean<'tcx, Attributes> for [ast::Attribute] {
     fn clean(&self, _cx: &mut DocContext<'_>) -> Attributes {
         Attributes::from_ast(self, None)
@@ -141,7 +180,11 @@ fn clean_trait_ref_with_bindings<'tcx>(
         span_bug!(cx.tcx.def_span(trait_ref.def_id), "`TraitRef` had unexpected kind {:?}", kind);
     }
     inline::record_extern_fqn(cx, trait_ref.def_id, kind);
-    let path = external_path(cx, trait_ref.def_id, true, bindings.to_vec(), trait_ref.substs);
+    if kind == ItemType::TraitAlias {
+        inline::record_extern_trait_alias(cx, trait_ref.def_id);
+    }
+    let bindings = bindings.iter().cloned().collect();
+    let path = external_path(cx, trait_ref.def_id, true, bindings, trait_ref.substs);
 
     debug!("ty::TraitRef\n  subst: {:?}\n", trait_ref.substs);
 
@@ -430,7 +473,7 @@ fn projection_to_path_segment<'tcx>(
     PathSegment {
         name: item.name,
         args: GenericArgs::AngleBracketed {
-            args: substs_to_args(cx, &ty.substs[generics.parent_count..], false).into(),
+            args: substs_to_args(cx, &ty.substs[generics.parent_count..], false),
             bindings: Default::default(),
         },
     }
@@ -464,7 +507,10 @@ fn clean(&self, cx: &mut DocContext<'tcx>) -> GenericParamDef {
                     did: self.def_id,
                     ty: Box::new(cx.tcx.type_of(self.def_id).clean(cx)),
                     default: match has_default {
-                        true => Some(Box::new(cx.tcx.const_param_default(self.def_id).to_string())),
+                        true => {
+                            let default = cx.tcx.const_param_default(self.def_id);
+                            Some(Box::new(print_const(cx, default)))
+                        }
                         false => None,
                     },
                 },
@@ -526,7 +572,7 @@ fn clean_generic_param<'tcx>(
                 ty: Box::new(ty.clean(cx)),
                 default: default.map(|ct| {
                     let def_id = cx.tcx.hir().local_def_id(ct.hir_id);
-                    Box::new(ty::Const::from_anon_const(cx.tcx, def_id).to_string())
+                    Box::new(print_const(cx, ty::Const::from_anon_const(cx.tcx, def_id)))
                 }),
             },
         ),
@@ -554,11 +600,20 @@ fn is_elided_lifetime(param: &hir::GenericParam<'_>) -> bool {
 
 impl<'tcx> Clean<'tcx, Generics> for hir::Generics<'tcx> {
     fn clean(&self, cx: &mut DocContext<'tcx>) -> Generics {
-        let impl_trait_params = self
+        // An `impl Trait` can be nested in the bounds of another one, like in
+        // `impl Iterator<Item = impl Display>`. The bounds of the inner one must be known when
+        // cleaning the outer one, and since it starts after the one containing it, cleaning them
+        // from the last one in the source to the first one takes care of it.
+        let mut impl_trait_params = self
             .params
             .iter()
-            .filter(|param| is_impl_trait(param))
-            .map(|param| {
+            .enumerate()
+            .filter(|(_, param)| is_impl_trait(param))
+            .collect::<Vec<_>>();
+        impl_trait_params.sort_by_key(|(_, param)| Reverse(param.span.lo()));
+        let mut impl_trait_params = impl_trait_params
+            .into_iter()
+            .map(|(i, param)| {
                 let param = clean_generic_param(cx, Some(self), param);
                 match param.kind {
                     GenericParamDefKind::Lifetime { .. } => unreachable!(),
@@ -567,16 +622,17 @@ fn clean(&self, cx: &mut DocContext<'tcx>) -> Generics {
                     }
                     GenericParamDefKind::Const { .. } => unreachable!(),
                 }
-                param
+                (i, param)
             })
             .collect::<Vec<_>>();
+        impl_trait_params.sort_by_key(|&(i, _)| i);
 
         let mut params = Vec::with_capacity(self.params.len());
         for p in self.params.iter().filter(|p| !is_impl_trait(p) && !is_elided_lifetime(p)) {
             let p = clean_generic_param(cx, Some(self), p);
             params.push(p);
         }
-        params.extend(impl_trait_params);
+        params.extend(impl_trait_params.into_iter().map(|(_, param)| param));
 
         let mut generics = Generics {
             params,
@@ -712,6 +768,16 @@ fn clean_ty_generics<'tcx>(
         })
         .collect::<Vec<_>>();
 
+    // An `impl Trait` can be nested in the associated type bindings of another one, like in
+    // `impl Iterator<Item = impl Display>`. Its bounds must be known before cleaning the bindings
+    // of the outer one, and since it starts after the one containing it, cleaning them from the
+    // last one in the source to the first one takes care of it.
+    let tcx = cx.tcx;
+    let mut impl_trait = impl_trait.into_iter().collect::<Vec<_>>();
+    impl_trait.sort_by_key(|&(param, _)| {
+        let ImplTraitParam::ParamIndex(idx) = param else { unreachable!() };
+        Reverse(tcx.def_span(gens.param_at(idx as usize, tcx).def_id).lo())
+    });
     for (param, mut bounds) in impl_trait {
         // Move trait bounds to the front.
         bounds.sort_by_key(|b| !matches!(b, GenericBound::TraitBound(..)));
@@ -823,7 +889,7 @@ fn clean_fn_or_proc_macro<'tcx>(
                     }
                 }
             }
-            ProcMacroItem(ProcMacro { kind, helpers })
+            ProcMacroItem(ProcMacro { kind, helpers, derived_trait: None })
         }
         None => {
             let mut func = clean_function(cx, sig, generics, body_id);
@@ -1004,10 +1070,11 @@ fn clean(&self, cx: &mut DocContext<'tcx>) -> Item {
         let local_did = self.def_id.to_def_id();
         cx.with_param_env(local_did, |cx| {
             let inner = match self.kind {
-                hir::TraitItemKind::Const(ty, Some(default)) => AssocConstItem(
-                    ty.clean(cx),
-                    ConstantKind::Local { def_id: local_did, body: default },
-                ),
+                hir::TraitItemKind::Const(ty, Some(default)) => {
+                    let default = ConstantKind::Local { def_id: local_did, body: default };
+                    let repr = ConstantExprAndValue::new(default.clone());
+                    AssocConstItem(ty.clean(cx), default, repr)
+                }
                 hir::TraitItemKind::Const(ty, None) => TyAssocConstItem(ty.clean(cx)),
                 hir::TraitItemKind::Fn(ref sig, hir::TraitFn::Provided(body)) => {
                     let m = clean_function(cx, sig, self.generics, body);
@@ -1053,7 +1120,8 @@ fn clean(&self, cx: &mut DocContext<'tcx>) -> Item {
             let inner = match self.kind {
                 hir::ImplItemKind::Const(ty, expr) => {
                     let default = ConstantKind::Local { def_id: local_did, body: expr };
-                    AssocConstItem(ty.clean(cx), default)
+                    let repr = ConstantExprAndValue::new(default.clone());
+                    AssocConstItem(ty.clean(cx), default, repr)
                 }
                 hir::ImplItemKind::Fn(ref sig, body) => {
                     let m = clean_function(cx, sig, self.generics, body);
@@ -1099,7 +1167,9 @@ fn clean(&self, cx: &mut DocContext<'tcx>) -> Item {
                     ty::TraitContainer(_) => self.defaultness.has_value(),
                 };
                 if provided {
-                    AssocConstItem(ty, ConstantKind::Extern { def_id: self.def_id })
+                    let default = ConstantKind::Extern { def_id: self.def_id };
+                    let repr = ConstantExprAndValue::new(default.clone());
+                    AssocConstItem(ty, default, repr)
                 } else {
                     TyAssocConstItem(ty)
                 }
@@ -1570,12 +1640,12 @@ fn clean_ty<'tcx>(this: Ty<'tcx>, cx: &mut DocContext<'tcx>, def_id: Option<DefI
                 AdtKind::Enum => ItemType::Enum,
             };
             inline::record_extern_fqn(cx, did, kind);
-            let path = external_path(cx, did, false, vec![], substs);
+            let path = external_path(cx, did, false, ThinVec::new(), substs);
             Type::Path { path }
         }
         ty::Foreign(did) => {
             inline::record_extern_fqn(cx, did, ItemType::ForeignType);
-            let path = external_path(cx, did, false, vec![], InternalSubsts::empty());
+            let path = external_path(cx, did, false, ThinVec::new(), InternalSubsts::empty());
             Type::Path { path }
         }
         ty::Dynamic(obj, ref reg) => {
@@ -1599,13 +1669,13 @@ fn clean_ty<'tcx>(this: Ty<'tcx>, cx: &mut DocContext<'tcx>, def_id: Option<DefI
 
             for did in dids {
                 let empty = cx.tcx.intern_substs(&[]);
-                let path = external_path(cx, did, false, vec![], empty);
+                let path = external_path(cx, did, false, ThinVec::new(), empty);
                 inline::record_extern_fqn(cx, did, ItemType::Trait);
                 let bound = PolyTrait { trait_: path, generic_params: Vec::new() };
                 bounds.push(bound);
             }
 
-            let mut bindings = vec![];
+            let mut bindings = ThinVec::new();
             for pb in obj.projection_bounds() {
                 bindings.push(TypeBinding {
                     assoc: projection_to_path_segment(
@@ -1673,12 +1743,23 @@ fn clean_ty<'tcx>(this: Ty<'tcx>, cx: &mut DocContext<'tcx>, def_id: Option<DefI
                         }
                     }
 
+                    let is_fn_trait =
+                        cx.tcx.fn_trait_kind_from_lang_item(trait_ref.def_id()).is_some();
+                    let fn_once_output = cx.tcx.lang_items().fn_once_output();
                     let bindings: Vec<_> = bounds
                         .iter()
                         .filter_map(|bound| {
                             if let ty::PredicateKind::Projection(proj) = bound.kind().skip_binder()
                             {
-                                if proj.projection_ty.trait_ref(cx.tcx) == trait_ref.skip_binder() {
+                                // The return type of `Fn` and `FnMut` is bound on their
+                                // `FnOnce` supertrait, with the same substs.
+                                let is_fn_output = is_fn_trait
+                                    && Some(proj.projection_ty.item_def_id) == fn_once_output
+                                    && proj.projection_ty.substs == trait_ref.skip_binder().substs;
+                                if is_fn_output
+                                    || proj.projection_ty.trait_ref(cx.tcx)
+                                        == trait_ref.skip_binder()
+                                {
                                     Some(TypeBinding {
                                         assoc: projection_to_path_segment(proj.projection_ty, cx),
                                         kind: TypeBindingKind::Equality {
@@ -1725,7 +1806,7 @@ fn clean(&self, cx: &mut DocContext<'tcx>) -> Constant {
         // FIXME: instead of storing the stringified expression, store `self` directly instead.
         Constant {
             type_: self.ty().clean(cx),
-            kind: ConstantKind::TyConst { expr: self.to_string() },
+            kind: ConstantKind::TyConst { expr: print_const(cx, *self) },
         }
     }
 }
@@ -1895,10 +1976,11 @@ fn clean_maybe_renamed_item<'tcx>(
             ItemKind::Static(ty, mutability, body_id) => {
                 StaticItem(Static { type_: ty.clean(cx), mutability, expr: Some(body_id) })
             }
-            ItemKind::Const(ty, body_id) => ConstantItem(Constant {
-                type_: ty.clean(cx),
-                kind: ConstantKind::Local { body: body_id, def_id },
-            }),
+            ItemKind::Const(ty, body_id) => {
+                let kind = ConstantKind::Local { body: body_id, def_id };
+                let repr = ConstantExprAndValue::new(kind.clone());
+                ConstantItem(Constant { type_: ty.clean(cx), kind }, repr)
+            }
             ItemKind::OpaqueTy(ref ty) => OpaqueTyItem(OpaqueTy {
                 bounds: ty.bounds.iter().filter_map(|x| x.clean(cx)).collect(),
                 generics: ty.generics.clean(cx),
@@ -1936,9 +2018,8 @@ fn clean_maybe_renamed_item<'tcx>(
             }
             ItemKind::Macro(ref macro_def, _) => {
                 let ty_vis = cx.tcx.visibility(def_id).clean(cx);
-                MacroItem(Macro {
-                    source: display_macro_source(cx, name, macro_def, def_id, ty_vis),
-                })
+                let (source, arms) = display_macro_source(cx, name, macro_def, def_id, ty_vis);
+                MacroItem(Macro { source, arms, matchers: macro_matchers(macro_def) })
             }
             ItemKind::Trait(is_auto, unsafety, generics, bounds, item_ids) => {
                 let items =
@@ -2066,7 +2147,7 @@ fn clean_extern_crate<'tcx>(
         item_id: crate_def_id.into(),
         visibility: ty_vis.clean(cx),
         kind: box ExternCrateItem { src: orig_name },
-        cfg: attrs.cfg(cx.tcx, &cx.cache.hidden_cfg),
+        cfg: attrs.cfg(cx.tcx, &cx.cache),
     }]
 }
 
@@ -2136,15 +2217,42 @@ fn clean_use_statement<'tcx>(
 
     // Also check whether imports were asked to be inlined, in case we're trying to re-export a
     // crate in Rust 2018+
+    let reexport_chain = reexport_chain(cx.tcx, path);
     let path = path.clean(cx);
+    if visibility.is_public()
+        && let Some(graph) = &mut cx.dependency_graph
+        && let Some(did) = path.res.opt_def_id()
+    {
+        graph.record_reexport(current_mod.to_def_id(), did);
+    }
     let inner = if kind == hir::UseKind::Glob {
         if !denied {
             let mut visited = FxHashSet::default();
             if let Some(items) = inline::try_inline_glob(cx, path.res, &mut visited) {
+                let importing_mod = cx.tcx.parent_module(import.hir_id());
+                let glob_mod = path.res.def_id();
+                let items = items
+                    .into_iter()
+                    .filter(|item| match (item.name, item.item_id.as_def_id()) {
+                        (Some(name), Some(did)) => is_imported_as(cx.tcx, importing_mod, name, did),
+                        // Implementations and primitive re-exports.
+                        _ => true,
+                    })
+                    .collect::<Vec<_>>();
+                // The items are re-exported by the globbed module, unless it defines them.
+                for item in &items {
+                    if let Some(did) = item.item_id.as_def_id() && item.name.is_some() {
+                        let mut chain = reexport_chain.clone();
+                        if find_nearest_parent_module(cx.tcx, did) != Some(glob_mod) {
+                            chain.push(glob_mod);
+                        }
+                        record_reexport_chain(cx, did, &chain);
+                    }
+                }
                 return items;
             }
         }
-        Import::new_glob(resolve_use_source(cx, path), true)
+        Import::new_glob(resolve_use_source(cx, path, reexport_chain), true)
     } else {
         if inline_attr.is_none() {
             if let Res::Def(DefKind::Mod, did) = path.res {
@@ -2168,16 +2276,25 @@ fn clean_use_statement<'tcx>(
                 Some(attrs),
                 &mut visited,
             ) {
+                if let Some(did) = path.res.opt_def_id() {
+                    record_reexport_chain(cx, did, &reexport_chain);
+                }
+                for item in &mut items {
+                    if item.item_id.as_def_id() == path.res.opt_def_id() {
+                        add_reexport_cfg(cx, item, import_def_id, &reexport_chain);
+                    }
+                }
+                let source = resolve_use_source(cx, path, reexport_chain);
                 items.push(Item::from_def_id_and_parts(
                     import_def_id,
                     None,
-                    ImportItem(Import::new_simple(name, resolve_use_source(cx, path), false)),
+                    ImportItem(Import::new_simple(name, source, false)),
                     cx,
                 ));
                 return items;
             }
         }
-        Import::new_simple(name, resolve_use_source(cx, path), true)
+        Import::new_simple(name, resolve_use_source(cx, path, reexport_chain), true)
     };
 
     vec![Item::from_def_id_and_parts(import.def_id.to_def_id(), None, ImportItem(inner), cx)]
@@ -10,7 +10,10 @@
 
 /// Render a macro matcher in a format suitable for displaying to the user
 /// as part of an item declaration.
-pub(super) fn render_macro_matcher(tcx: TyCtxt<'_>, matcher: &TokenTree) -> String {
+///
+/// `indent` is the indentation of the line the matcher starts on: arms of a macro are
+/// indented by 4, while the single matcher of a `macro name($args) { ... }` is not indented.
+pub(super) fn render_macro_matcher(tcx: TyCtxt<'_>, matcher: &TokenTree, indent: isize) -> String {
     if let Some(snippet) = snippet_equal_to_token(tcx, matcher) {
         // If the original source code is known, we display the matcher exactly
         // as present in the source code.
@@ -35,7 +38,7 @@ pub(super) fn render_macro_matcher(tcx: TyCtxt<'_>, matcher: &TokenTree) -> Stri
     //             ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~!
     //         ) => {...};
     //     }
-    printer.cbox(8);
+    printer.cbox(indent + 4);
     printer.word("(");
     printer.zerobreak();
     printer.ibox(0);
@@ -52,6 +55,19 @@ pub(super) fn render_macro_matcher(tcx: TyCtxt<'_>, matcher: &TokenTree) -> Stri
     printer.s.eof()
 }
 
+/// Render the transcriber of a macro arm, that is the body which the matched input expands to.
+pub(super) fn render_macro_transcriber(tcx: TyCtxt<'_>, transcriber: &TokenTree) -> String {
+    if let Some(snippet) = snippet_equal_to_token(tcx, transcriber) {
+        return snippet;
+    }
+
+    let mut printer = Printer::new();
+    printer.ibox(4);
+    print_tt(&mut printer, transcriber);
+    printer.end();
+    printer.s.eof()
+}
+
 /// Find the source snippet for this token's Span, reparse it, and return the
 /// snippet if the reparsed TokenTree matches the argument TokenTree.
 fn snippet_equal_to_token(tcx: TyCtxt<'_>, matcher: &TokenTree) -> Option<String> {
@@ -10,6 +10,7 @@
 use arrayvec::ArrayVec;
 
 use rustc_ast::attr;
+use rustc_ast::tokenstream::TokenTree;
 use rustc_ast::util::comments::beautify_doc_string;
 use rustc_ast::{self as ast, AttrStyle};
 use rustc_attr::{ConstStability, Deprecation, Stability, StabilityLevel};
@@ -22,8 +23,10 @@
 use rustc_hir::lang_items::LangItem;
 use rustc_hir::{BodyId, Mutability};
 use rustc_index::vec::IndexVec;
+use rustc_macros::{Decodable, Encodable};
 use rustc_middle::ty::fast_reject::SimplifiedType;
 use rustc_middle::ty::{self, TyCtxt};
+use rustc_serialize::{Decodable, Decoder, Encodable, Encoder};
 use rustc_session::Session;
 use rustc_span::hygiene::MacroKind;
 use rustc_span::source_map::DUMMY_SP;
@@ -36,8 +39,11 @@
 use crate::clean::cfg::Cfg;
 use crate::clean::external_path;
 use crate::clean::inline::{self, print_inlined_const};
-use crate::clean::utils::{is_literal_expr, print_const_expr, print_evaluated_const};
+use crate::clean::utils::{
+    is_literal_expr, print_const_expr, print_evaluated_const, print_unevaluated_const_expr,
+};
 use crate::clean::Clean;
+use crate::config::NumberFormat;
 use crate::core::DocContext;
 use crate::formats::cache::Cache;
 use crate::formats::item_type::ItemType;
@@ -58,7 +64,7 @@
 
 pub(crate) type ItemIdSet = FxHashSet<ItemId>;
 
-#[derive(Debug, Clone, PartialEq, Eq, Hash, Copy)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Copy, Encodable, Decodable)]
 pub(crate) enum ItemId {
     /// A "normal" item that uses a [`DefId`] for identification.
     DefId(DefId),
@@ -137,7 +143,7 @@ pub(crate) fn src(&self, tcx: TyCtxt<'_>) -> FileName {
 }
 
 /// This struct is used to wrap additional information added by rustdoc on a `trait` item.
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, Encodable, Decodable)]
 pub(crate) struct TraitWithExtraInfo {
     pub(crate) trait_: Trait,
     pub(crate) is_notable: bool,
@@ -268,6 +274,22 @@ pub(crate) fn keywords(&self, tcx: TyCtxt<'_>) -> ThinVec<(DefId, Symbol)> {
         }
     }
 
+    /// Returns the modules documenting each keyword.
+    ///
+    /// Keywords are only documented by `std`, or by the crate being documented, which takes
+    /// precedence.
+    pub(crate) fn keyword_locations(tcx: TyCtxt<'_>) -> &FxHashMap<Symbol, DefId> {
+        static KEYWORD_LOCATIONS: OnceCell<FxHashMap<Symbol, DefId>> = OnceCell::new();
+        KEYWORD_LOCATIONS.get_or_init(|| {
+            tcx.crates(())
+                .iter()
+                .chain(iter::once(&LOCAL_CRATE))
+                .flat_map(|&crate_num| ExternalCrate { crate_num }.keywords(tcx))
+                .map(|(def_id, kw)| (kw, def_id))
+                .collect()
+        })
+    }
+
     pub(crate) fn primitives(&self, tcx: TyCtxt<'_>) -> ThinVec<(DefId, PrimitiveType)> {
         let root = self.def_id();
 
@@ -353,7 +375,7 @@ pub(crate) enum ExternalLocation {
 /// Anything with a source location and set of attributes and, optionally, a
 /// name. That is, anything that can be documented. This doesn't correspond
 /// directly to the AST's concept of an item; it's a strict superset.
-#[derive(Clone)]
+#[derive(Clone, Encodable, Decodable)]
 pub(crate) struct Item {
     /// The name of this item.
     /// Optional because not every item has a name, e.g. impls.
@@ -430,7 +452,7 @@ pub(crate) fn span(&self, tcx: TyCtxt<'_>) -> Span {
         };
         match kind {
             ItemKind::ModuleItem(Module { span, .. }) => *span,
-            ItemKind::ImplItem(Impl { kind: ImplKind::Auto, .. }) => Span::dummy(),
+            ItemKind::ImplItem(Impl { kind: ImplKind::Auto { .. }, .. }) => Span::dummy(),
             ItemKind::ImplItem(Impl { kind: ImplKind::Blanket(_), .. }) => {
                 if let ItemId::Blanket { impl_id, .. } = self.item_id {
                     rustc_span(impl_id, tcx)
@@ -479,7 +501,7 @@ pub(crate) fn from_def_id_and_parts(
             kind,
             box ast_attrs.clean(cx),
             cx,
-            ast_attrs.cfg(cx.tcx, &cx.cache.hidden_cfg),
+            ast_attrs.cfg(cx.tcx, &cx.cache),
         )
     }
 
@@ -721,7 +743,7 @@ fn build_fn_header(
     }
 }
 
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, Encodable, Decodable)]
 pub(crate) enum ItemKind {
     ExternCrateItem {
         /// The crate's name, *not* the name it's imported as.
@@ -736,7 +758,8 @@ pub(crate) enum ItemKind {
     TypedefItem(Typedef),
     OpaqueTyItem(OpaqueTy),
     StaticItem(Static),
-    ConstantItem(Constant),
+    /// A constant, and both representations of its value.
+    ConstantItem(Constant, ConstantExprAndValue),
     TraitItem(Trait),
     TraitAliasItem(TraitAlias),
     ImplItem(Impl),
@@ -759,8 +782,9 @@ pub(crate) enum ItemKind {
     PrimitiveItem(PrimitiveType),
     /// A required associated constant in a trait declaration.
     TyAssocConstItem(Type),
-    /// An associated associated constant in a trait impl or a provided one in a trait declaration.
-    AssocConstItem(Type, ConstantKind),
+    /// An associated associated constant in a trait impl or a provided one in a trait declaration,
+    /// and both representations of its value.
+    AssocConstItem(Type, ConstantKind, ConstantExprAndValue),
     /// A required associated type in a trait declaration.
     ///
     /// The bounds may be non-empty if there is a `where` clause.
@@ -791,7 +815,7 @@ pub(crate) fn inner_items(&self) -> impl Iterator<Item = &Item> {
             | TypedefItem(_)
             | OpaqueTyItem(_)
             | StaticItem(_)
-            | ConstantItem(_)
+            | ConstantItem(..)
             | TraitAliasItem(_)
             | TyMethodItem(_)
             | MethodItem(_, _)
@@ -804,7 +828,7 @@ pub(crate) fn inner_items(&self) -> impl Iterator<Item = &Item> {
             | ProcMacroItem(_)
             | PrimitiveItem(_)
             | TyAssocConstItem(_)
-            | AssocConstItem(_, _)
+            | AssocConstItem(..)
             | TyAssocTypeItem(..)
             | AssocTypeItem(..)
             | StrippedItem(_)
@@ -813,7 +837,7 @@ pub(crate) fn inner_items(&self) -> impl Iterator<Item = &Item> {
     }
 }
 
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, Encodable, Decodable)]
 pub(crate) struct Module {
     pub(crate) items: Vec<Item>,
     pub(crate) span: Span,
@@ -832,7 +856,9 @@ pub(crate) trait AttributesExt {
 
     fn other_attrs(&self) -> Vec<ast::Attribute>;
 
-    fn cfg(&self, tcx: TyCtxt<'_>, hidden_cfg: &FxHashSet<Cfg>) -> Option<Arc<Cfg>>;
+    /// The cfg an item is documented with, from its `#[doc(cfg)]` attributes, or from its `#[cfg]`
+    /// ones with `doc_auto_cfg`. With `--auto-cfg`, both kinds of attributes are used.
+    fn cfg(&self, tcx: TyCtxt<'_>, cache: &Cache) -> Option<Arc<Cfg>>;
 }
 
 impl AttributesExt for [ast::Attribute] {
@@ -862,10 +888,10 @@ fn other_attrs(&self) -> Vec<ast::Attribute> {
         self.iter().filter(|attr| attr.doc_str().is_none()).cloned().collect()
     }
 
-    fn cfg(&self, tcx: TyCtxt<'_>, hidden_cfg: &FxHashSet<Cfg>) -> Option<Arc<Cfg>> {
+    fn cfg(&self, tcx: TyCtxt<'_>, cache: &Cache) -> Option<Arc<Cfg>> {
         let sess = tcx.sess;
         let doc_cfg_active = tcx.features().doc_cfg;
-        let doc_auto_cfg_active = tcx.features().doc_auto_cfg;
+        let doc_auto_cfg_active = tcx.features().doc_auto_cfg || cache.auto_cfg;
 
         fn single<T: IntoIterator>(it: T) -> Option<T::Item> {
             let mut iter = it.into_iter();
@@ -883,7 +909,9 @@ fn single<T: IntoIterator>(it: T) -> Option<T::Item> {
                 .flat_map(|attr| attr.meta_item_list().unwrap_or_default())
                 .filter(|attr| attr.has_name(sym::cfg))
                 .peekable();
-            if doc_cfg.peek().is_some() && doc_cfg_active {
+            // `#[doc(cfg)]` overrides the automatic cfg, unless `--auto-cfg` asks to merge them.
+            // Either way, it's added to the cfg below.
+            if doc_cfg.peek().is_some() && doc_cfg_active && !cache.auto_cfg {
                 doc_cfg
                     .filter_map(|attr| Cfg::parse(attr.meta_item()?).ok())
                     .fold(Cfg::True, |cfg, new_cfg| cfg & new_cfg)
@@ -892,7 +920,7 @@ fn single<T: IntoIterator>(it: T) -> Option<T::Item> {
                     .filter(|attr| attr.has_name(sym::cfg))
                     .filter_map(|attr| single(attr.meta_item_list()?))
                     .filter_map(|attr| {
-                        Cfg::parse_without(attr.meta_item()?, hidden_cfg).ok().flatten()
+                        Cfg::parse_without(attr.meta_item()?, &cache.hidden_cfg).ok().flatten()
                     })
                     .fold(Cfg::True, |cfg, new_cfg| cfg & new_cfg)
             } else {
@@ -978,7 +1006,7 @@ fn get_word_attr(mut self, word: Symbol) -> Option<ast::NestedMetaItem> {
 /// Included files are kept separate from inline doc comments so that proper line-number
 /// information can be given when a doctest fails. Sugared doc comments and "raw" doc comments are
 /// kept separate because of issue #42760.
-#[derive(Clone, PartialEq, Eq, Debug)]
+#[derive(Clone, PartialEq, Eq, Debug, Encodable, Decodable)]
 pub(crate) struct DocFragment {
     pub(crate) span: rustc_span::Span,
     /// The module this doc-comment came from.
@@ -995,7 +1023,7 @@ pub(crate) struct DocFragment {
 #[cfg(all(target_arch = "x86_64", target_pointer_width = "64"))]
 rustc_data_structures::static_assert_size!(DocFragment, 32);
 
-#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Encodable, Decodable)]
 pub(crate) enum DocFragmentKind {
     /// A doc fragment created from a `///` or `//!` doc comment.
     SugaredDoc,
@@ -1147,7 +1175,7 @@ pub struct RenderedLink {
 
 /// The attributes on an [`Item`], including attributes like `#[derive(...)]` and `#[inline]`,
 /// as well as doc comments.
-#[derive(Clone, Debug, Default)]
+#[derive(Clone, Debug, Default, Encodable, Decodable)]
 pub(crate) struct Attributes {
     pub(crate) doc_strings: Vec<DocFragment>,
     pub(crate) other_attrs: Vec<ast::Attribute>,
@@ -1286,7 +1314,7 @@ fn eq(&self, rhs: &Self) -> bool {
 
 impl Eq for Attributes {}
 
-#[derive(Clone, PartialEq, Eq, Debug, Hash)]
+#[derive(Clone, PartialEq, Eq, Debug, Hash, Encodable, Decodable)]
 pub(crate) enum GenericBound {
     TraitBound(PolyTrait, hir::TraitBoundModifier),
     Outlives(Lifetime),
@@ -1296,7 +1324,7 @@ impl GenericBound {
     pub(crate) fn maybe_sized(cx: &mut DocContext<'_>) -> GenericBound {
         let did = cx.tcx.require_lang_item(LangItem::Sized, None);
         let empty = cx.tcx.intern_substs(&[]);
-        let path = external_path(cx, did, false, vec![], empty);
+        let path = external_path(cx, did, false, ThinVec::new(), empty);
         inline::record_extern_fqn(cx, did, ItemType::Trait);
         GenericBound::TraitBound(
             PolyTrait { trait_: path, generic_params: Vec::new() },
@@ -1330,7 +1358,7 @@ pub(crate) fn get_trait_path(&self) -> Option<Path> {
     }
 }
 
-#[derive(Clone, PartialEq, Eq, Debug, Hash)]
+#[derive(Clone, PartialEq, Eq, Debug, Hash, Encodable, Decodable)]
 pub(crate) struct Lifetime(pub Symbol);
 
 impl Lifetime {
@@ -1343,7 +1371,7 @@ pub(crate) fn elided() -> Lifetime {
     }
 }
 
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, Encodable, Decodable)]
 pub(crate) enum WherePredicate {
     BoundPredicate { ty: Type, bounds: Vec<GenericBound>, bound_params: Vec<Lifetime> },
     RegionPredicate { lifetime: Lifetime, bounds: Vec<GenericBound> },
@@ -1360,7 +1388,7 @@ pub(crate) fn get_bounds(&self) -> Option<&[GenericBound]> {
     }
 }
 
-#[derive(Clone, PartialEq, Eq, Debug, Hash)]
+#[derive(Clone, PartialEq, Eq, Debug, Hash, Encodable, Decodable)]
 pub(crate) enum GenericParamDefKind {
     Lifetime { outlives: Vec<Lifetime> },
     Type { did: DefId, bounds: Vec<GenericBound>, default: Option<Box<Type>>, synthetic: bool },
@@ -1373,7 +1401,7 @@ pub(crate) fn is_type(&self) -> bool {
     }
 }
 
-#[derive(Clone, PartialEq, Eq, Debug, Hash)]
+#[derive(Clone, PartialEq, Eq, Debug, Hash, Encodable, Decodable)]
 pub(crate) struct GenericParamDef {
     pub(crate) name: Symbol,
     pub(crate) kind: GenericParamDefKind,
@@ -1404,7 +1432,7 @@ pub(crate) fn get_bounds(&self) -> Option<&[GenericBound]> {
 }
 
 // maybe use a Generic enum and use Vec<Generic>?
-#[derive(Clone, Debug, Default)]
+#[derive(Clone, Debug, Default, Encodable, Decodable)]
 pub(crate) struct Generics {
     pub(crate) params: Vec<GenericParamDef>,
     pub(crate) where_predicates: Vec<WherePredicate>,
@@ -1416,13 +1444,13 @@ pub(crate) fn is_empty(&self) -> bool {
     }
 }
 
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, Encodable, Decodable)]
 pub(crate) struct Function {
     pub(crate) decl: FnDecl,
     pub(crate) generics: Generics,
 }
 
-#[derive(Clone, PartialEq, Eq, Debug, Hash)]
+#[derive(Clone, PartialEq, Eq, Debug, Hash, Encodable, Decodable)]
 pub(crate) struct FnDecl {
     pub(crate) inputs: Arguments,
     pub(crate) output: FnRetTy,
@@ -1459,12 +1487,12 @@ pub(crate) fn sugared_async_return_type(&self) -> FnRetTy {
     }
 }
 
-#[derive(Clone, PartialEq, Eq, Debug, Hash)]
+#[derive(Clone, PartialEq, Eq, Debug, Hash, Encodable, Decodable)]
 pub(crate) struct Arguments {
     pub(crate) values: Vec<Argument>,
 }
 
-#[derive(Clone, PartialEq, Eq, Debug, Hash)]
+#[derive(Clone, PartialEq, Eq, Debug, Hash, Encodable, Decodable)]
 pub(crate) struct Argument {
     pub(crate) type_: Type,
     pub(crate) name: Symbol,
@@ -1497,7 +1525,7 @@ pub(crate) fn to_self(&self) -> Option<SelfTy> {
     }
 }
 
-#[derive(Clone, PartialEq, Eq, Debug, Hash)]
+#[derive(Clone, PartialEq, Eq, Debug, Hash, Encodable, Decodable)]
 pub(crate) enum FnRetTy {
     Return(Type),
     DefaultReturn,
@@ -1512,7 +1540,7 @@ pub(crate) fn as_return(&self) -> Option<&Type> {
     }
 }
 
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, Encodable, Decodable)]
 pub(crate) struct Trait {
     pub(crate) unsafety: hir::Unsafety,
     pub(crate) items: Vec<Item>,
@@ -1521,21 +1549,21 @@ pub(crate) struct Trait {
     pub(crate) is_auto: bool,
 }
 
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, Encodable, Decodable)]
 pub(crate) struct TraitAlias {
     pub(crate) generics: Generics,
     pub(crate) bounds: Vec<GenericBound>,
 }
 
 /// A trait reference, which may have higher ranked lifetimes.
-#[derive(Clone, PartialEq, Eq, Debug, Hash)]
+#[derive(Clone, PartialEq, Eq, Debug, Hash, Encodable, Decodable)]
 pub(crate) struct PolyTrait {
     pub(crate) trait_: Path,
     pub(crate) generic_params: Vec<GenericParamDef>,
 }
 
 /// Rustdoc's representation of types, mostly based on the [`hir::Ty`].
-#[derive(Clone, PartialEq, Eq, Debug, Hash)]
+#[derive(Clone, PartialEq, Eq, Debug, Hash, Encodable, Decodable)]
 pub(crate) enum Type {
     /// A named type, which could be a trait.
     ///
@@ -1721,7 +1749,7 @@ pub(crate) fn def_id(&self, cache: &Cache) -> Option<DefId> {
 ///
 /// N.B. This has to be different from [`hir::PrimTy`] because it also includes types that aren't
 /// paths, like [`Self::Unit`].
-#[derive(Clone, PartialEq, Eq, Hash, Copy, Debug)]
+#[derive(Clone, PartialEq, Eq, Hash, Copy, Debug, Encodable, Decodable)]
 pub(crate) enum PrimitiveType {
     Isize,
     I8,
@@ -1910,17 +1938,29 @@ pub(crate) fn primitive_locations(tcx: TyCtxt<'_>) -> &FxHashMap<PrimitiveType,
         static PRIMITIVE_LOCATIONS: OnceCell<FxHashMap<PrimitiveType, DefId>> = OnceCell::new();
         PRIMITIVE_LOCATIONS.get_or_init(|| {
             let mut primitive_locations = FxHashMap::default();
+            // With `-Z allow-doc-primitive`, the primitives documented by a crate outside of the
+            // standard library (like a replacement for `core`) take precedence.
+            let allow_doc_primitive = tcx.sess.opts.debugging_opts.allow_doc_primitive;
+            let mut outside_std = FxHashSet::default();
             // NOTE: technically this misses crates that are only passed with `--extern` and not loaded when checking the crate.
             // This is a degenerate case that I don't plan to support.
             for &crate_num in tcx.crates(()) {
                 let e = ExternalCrate { crate_num };
                 let crate_name = e.name(tcx);
                 debug!(?crate_num, ?crate_name);
+                let is_std = matches!(crate_name, sym::core | sym::alloc | sym::std);
                 for &(def_id, prim) in &e.primitives(tcx) {
                     // HACK: try to link to std instead where possible
                     if crate_name == sym::core && primitive_locations.contains_key(&prim) {
                         continue;
                     }
+                    if allow_doc_primitive {
+                        if !is_std {
+                            outside_std.insert(prim);
+                        } else if outside_std.contains(&prim) {
+                            continue;
+                        }
+                    }
                     primitive_locations.insert(prim, def_id);
                 }
             }
@@ -2016,7 +2056,7 @@ fn from(prim_ty: hir::PrimTy) -> PrimitiveType {
     }
 }
 
-#[derive(Copy, Clone, Debug)]
+#[derive(Copy, Clone, Debug, Encodable, Decodable)]
 pub(crate) enum Visibility {
     /// `pub`
     Public,
@@ -2034,7 +2074,7 @@ pub(crate) fn is_public(&self) -> bool {
     }
 }
 
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, Encodable, Decodable)]
 pub(crate) struct Struct {
     pub(crate) struct_type: CtorKind,
     pub(crate) generics: Generics,
@@ -2047,7 +2087,7 @@ pub(crate) fn has_stripped_entries(&self) -> bool {
     }
 }
 
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, Encodable, Decodable)]
 pub(crate) struct Union {
     pub(crate) generics: Generics,
     pub(crate) fields: Vec<Item>,
@@ -2062,7 +2102,7 @@ pub(crate) fn has_stripped_entries(&self) -> bool {
 /// This is a more limited form of the standard Struct, different in that
 /// it lacks the things most items have (name, id, parameterization). Found
 /// only as a variant in an enum.
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, Encodable, Decodable)]
 pub(crate) struct VariantStruct {
     pub(crate) struct_type: CtorKind,
     pub(crate) fields: Vec<Item>,
@@ -2074,7 +2114,7 @@ pub(crate) fn has_stripped_entries(&self) -> bool {
     }
 }
 
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, Encodable, Decodable)]
 pub(crate) struct Enum {
     pub(crate) variants: IndexVec<VariantIdx, Item>,
     pub(crate) generics: Generics,
@@ -2090,7 +2130,7 @@ pub(crate) fn variants(&self) -> impl Iterator<Item = &Item> {
     }
 }
 
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, Encodable, Decodable)]
 pub(crate) enum Variant {
     CLike,
     Tuple(Vec<Item>),
@@ -2108,7 +2148,7 @@ pub(crate) fn has_stripped_entries(&self) -> Option<bool> {
 
 /// Small wrapper around [`rustc_span::Span`] that adds helper methods
 /// and enforces calling [`rustc_span::Span::source_callsite()`].
-#[derive(Copy, Clone, Debug)]
+#[derive(Copy, Clone, Debug, Encodable, Decodable)]
 pub(crate) struct Span(rustc_span::Span);
 
 impl Span {
@@ -2150,7 +2190,7 @@ pub(crate) fn cnum(&self, sess: &Session) -> CrateNum {
     }
 }
 
-#[derive(Clone, PartialEq, Eq, Debug, Hash)]
+#[derive(Clone, PartialEq, Eq, Debug, Hash, Encodable, Decodable)]
 pub(crate) struct Path {
     pub(crate) res: Res,
     pub(crate) segments: Vec<PathSegment>,
@@ -2211,7 +2251,7 @@ pub(crate) fn bindings(&self) -> Option<&[TypeBinding]> {
     }
 }
 
-#[derive(Clone, PartialEq, Eq, Debug, Hash)]
+#[derive(Clone, PartialEq, Eq, Debug, Hash, Encodable, Decodable)]
 pub(crate) enum GenericArg {
     Lifetime(Lifetime),
     Type(Type),
@@ -2224,7 +2264,7 @@ pub(crate) enum GenericArg {
 #[cfg(all(target_arch = "x86_64", target_pointer_width = "64"))]
 rustc_data_structures::static_assert_size!(GenericArg, 80);
 
-#[derive(Clone, PartialEq, Eq, Debug, Hash)]
+#[derive(Clone, PartialEq, Eq, Debug, Hash, Encodable, Decodable)]
 pub(crate) enum GenericArgs {
     AngleBracketed { args: Box<[GenericArg]>, bindings: ThinVec<TypeBinding> },
     Parenthesized { inputs: Box<[Type]>, output: Option<Box<Type>> },
@@ -2235,7 +2275,7 @@ pub(crate) enum GenericArgs {
 #[cfg(all(target_arch = "x86_64", target_pointer_width = "64"))]
 rustc_data_structures::static_assert_size!(GenericArgs, 32);
 
-#[derive(Clone, PartialEq, Eq, Debug, Hash)]
+#[derive(Clone, PartialEq, Eq, Debug, Hash, Encodable, Decodable)]
 pub(crate) struct PathSegment {
     pub(crate) name: Symbol,
     pub(crate) args: GenericArgs,
@@ -2246,7 +2286,7 @@ pub(crate) struct PathSegment {
 #[cfg(all(target_arch = "x86_64", target_pointer_width = "64"))]
 rustc_data_structures::static_assert_size!(PathSegment, 40);
 
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, Encodable, Decodable)]
 pub(crate) struct Typedef {
     pub(crate) type_: Type,
     pub(crate) generics: Generics,
@@ -2259,13 +2299,13 @@ pub(crate) struct Typedef {
     pub(crate) item_type: Option<Type>,
 }
 
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, Encodable, Decodable)]
 pub(crate) struct OpaqueTy {
     pub(crate) bounds: Vec<GenericBound>,
     pub(crate) generics: Generics,
 }
 
-#[derive(Clone, PartialEq, Eq, Debug, Hash)]
+#[derive(Clone, PartialEq, Eq, Debug, Hash, Encodable, Decodable)]
 pub(crate) struct BareFunctionDecl {
     pub(crate) unsafety: hir::Unsafety,
     pub(crate) generic_params: Vec<GenericParamDef>,
@@ -2273,20 +2313,20 @@ pub(crate) struct BareFunctionDecl {
     pub(crate) abi: Abi,
 }
 
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, Encodable, Decodable)]
 pub(crate) struct Static {
     pub(crate) type_: Type,
     pub(crate) mutability: Mutability,
     pub(crate) expr: Option<BodyId>,
 }
 
-#[derive(Clone, PartialEq, Eq, Hash, Debug)]
+#[derive(Clone, PartialEq, Eq, Hash, Debug, Encodable, Decodable)]
 pub(crate) struct Constant {
     pub(crate) type_: Type,
     pub(crate) kind: ConstantKind,
 }
 
-#[derive(Clone, PartialEq, Eq, Hash, Debug)]
+#[derive(Clone, PartialEq, Eq, Hash, Debug, Encodable, Decodable)]
 pub(crate) enum Term {
     Type(Type),
     Constant(Constant),
@@ -2304,7 +2344,7 @@ fn from(ty: Type) -> Self {
     }
 }
 
-#[derive(Clone, PartialEq, Eq, Hash, Debug)]
+#[derive(Clone, PartialEq, Eq, Hash, Debug, Encodable, Decodable)]
 pub(crate) enum ConstantKind {
     /// This is the wrapper around `ty::Const` for a non-local constant. Because it doesn't have a
     /// `BodyId`, we need to handle it on its own.
@@ -2327,13 +2367,17 @@ pub(crate) fn expr(&self, tcx: TyCtxt<'_>) -> String {
         self.kind.expr(tcx)
     }
 
-    pub(crate) fn value(&self, tcx: TyCtxt<'_>) -> Option<String> {
-        self.kind.value(tcx)
+    pub(crate) fn value(&self, tcx: TyCtxt<'_>, format: NumberFormat) -> Option<String> {
+        self.kind.value(tcx, format)
     }
 
     pub(crate) fn is_literal(&self, tcx: TyCtxt<'_>) -> bool {
         self.kind.is_literal(tcx)
     }
+
+    pub(crate) fn span(&self, tcx: TyCtxt<'_>) -> Option<Span> {
+        self.kind.span(tcx)
+    }
 }
 
 impl ConstantKind {
@@ -2341,17 +2385,16 @@ pub(crate) fn expr(&self, tcx: TyCtxt<'_>) -> String {
         match *self {
             ConstantKind::TyConst { ref expr } => expr.clone(),
             ConstantKind::Extern { def_id } => print_inlined_const(tcx, def_id),
-            ConstantKind::Local { body, .. } | ConstantKind::Anonymous { body } => {
-                print_const_expr(tcx, body)
-            }
+            ConstantKind::Local { body, .. } => print_const_expr(tcx, body),
+            ConstantKind::Anonymous { body } => print_unevaluated_const_expr(tcx, body),
         }
     }
 
-    pub(crate) fn value(&self, tcx: TyCtxt<'_>) -> Option<String> {
+    pub(crate) fn value(&self, tcx: TyCtxt<'_>, format: NumberFormat) -> Option<String> {
         match *self {
             ConstantKind::TyConst { .. } | ConstantKind::Anonymous { .. } => None,
             ConstantKind::Extern { def_id } | ConstantKind::Local { def_id, .. } => {
-                print_evaluated_const(tcx, def_id)
+                print_evaluated_const(tcx, def_id, format)
             }
         }
     }
@@ -2367,9 +2410,76 @@ pub(crate) fn is_literal(&self, tcx: TyCtxt<'_>) -> bool {
             }
         }
     }
+
+    /// The location of the expression of the constant, which is only known for the constants with a
+    /// body in the local crate.
+    pub(crate) fn span(&self, tcx: TyCtxt<'_>) -> Option<Span> {
+        match *self {
+            ConstantKind::TyConst { .. } | ConstantKind::Extern { .. } => None,
+            ConstantKind::Local { body, .. } | ConstantKind::Anonymous { body } => {
+                Some(Span::new(tcx.hir().body(body).value.span))
+            }
+        }
+    }
 }
 
+/// Both representations of a constant: the expression it's defined with and, if it could be
+/// evaluated, its value. This allows rendering e.g. `= 7 * 24 * 60 * 60; // 604_800u64`.
+///
+/// They are only computed once the constant is rendered, since most of the constants inlined from
+/// other crates never are and evaluating them isn't free.
 #[derive(Clone, Debug)]
+pub(crate) struct ConstantExprAndValue {
+    kind: ConstantKind,
+    expr: OnceCell<String>,
+    value: OnceCell<Option<String>>,
+}
+
+impl ConstantExprAndValue {
+    pub(crate) fn new(kind: ConstantKind) -> Self {
+        ConstantExprAndValue { kind, expr: OnceCell::new(), value: OnceCell::new() }
+    }
+
+    /// The expression, as written in the source or pretty-printed.
+    pub(crate) fn expr(&self, tcx: TyCtxt<'_>) -> &str {
+        self.expr.get_or_init(|| self.kind.expr(tcx))
+    }
+
+    /// The value of the constant, from [`print_evaluated_const`].
+    pub(crate) fn value(&self, tcx: TyCtxt<'_>, format: NumberFormat) -> Option<&str> {
+        self.value.get_or_init(|| self.kind.value(tcx, format)).as_deref()
+    }
+
+    /// Whether the expression is worth showing at all. Expressions of constants which couldn't be
+    /// evaluated are likely to be complex initializers, which aren't useful in the docs.
+    pub(crate) fn show_expr(&self, tcx: TyCtxt<'_>, format: NumberFormat) -> bool {
+        self.value(tcx, format).is_some() || self.kind.is_literal(tcx)
+    }
+
+    /// The evaluated value, if it tells more than the expression itself.
+    pub(crate) fn distinct_value(&self, tcx: TyCtxt<'_>, format: NumberFormat) -> Option<&str> {
+        if self.kind.is_literal(tcx) {
+            return None;
+        }
+        let value = self.value(tcx, format)?;
+        (value.to_lowercase() != self.expr(tcx).to_lowercase()).then_some(value)
+    }
+}
+
+// Only the constant itself is persisted, its representations are computed again when rendered.
+impl<E: Encoder> Encodable<E> for ConstantExprAndValue {
+    fn encode(&self, s: &mut E) {
+        self.kind.encode(s);
+    }
+}
+
+impl<D: Decoder> Decodable<D> for ConstantExprAndValue {
+    fn decode(d: &mut D) -> Self {
+        ConstantExprAndValue::new(Decodable::decode(d))
+    }
+}
+
+#[derive(Clone, Debug, Encodable, Decodable)]
 pub(crate) struct Impl {
     pub(crate) unsafety: hir::Unsafety,
     pub(crate) generics: Generics,
@@ -2390,17 +2500,20 @@ pub(crate) fn provided_trait_methods(&self, tcx: TyCtxt<'_>) -> FxHashSet<Symbol
     }
 }
 
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, Encodable, Decodable)]
 pub(crate) enum ImplKind {
     Normal,
-    Auto,
+    /// An impl of an auto trait synthesized by rustdoc. For negative impls, `blocking_fields`
+    /// holds the documented fields whose types never implement the trait, and
+    /// `private_blocking_fields` counts the other ones.
+    Auto { blocking_fields: Vec<(Symbol, Type)>, private_blocking_fields: usize },
     TupleVaradic,
     Blanket(Box<Type>),
 }
 
 impl ImplKind {
     pub(crate) fn is_auto(&self) -> bool {
-        matches!(self, ImplKind::Auto)
+        matches!(self, ImplKind::Auto { .. })
     }
 
     pub(crate) fn is_blanket(&self) -> bool {
@@ -2419,7 +2532,7 @@ pub(crate) fn as_blanket_ty(&self) -> Option<&Type> {
     }
 }
 
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, Encodable, Decodable)]
 pub(crate) struct Import {
     pub(crate) kind: ImportKind,
     pub(crate) source: ImportSource,
@@ -2440,7 +2553,7 @@ pub(crate) fn new_glob(source: ImportSource, should_be_displayed: bool) -> Self
     }
 }
 
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, Encodable, Decodable)]
 pub(crate) enum ImportKind {
     // use source as str;
     Simple(Symbol),
@@ -2448,32 +2561,43 @@ pub(crate) enum ImportKind {
     Glob,
 }
 
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, Encodable, Decodable)]
 pub(crate) struct ImportSource {
     pub(crate) path: Path,
     pub(crate) did: Option<DefId>,
+    /// The modules re-exporting the item which this import goes through, outermost first. The
+    /// module defining the item isn't part of it.
+    pub(crate) reexport_chain: Vec<DefId>,
 }
 
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, Encodable, Decodable)]
 pub(crate) struct Macro {
     pub(crate) source: String,
+    /// The byte range of each arm in `source`, which the HTML output gives an anchor to.
+    pub(crate) arms: Vec<(u32, u32)>,
+    /// The matcher of each arm, used by the JSON backend to describe the macro's interface and by
+    /// the search index to find macros by the fragments they take.
+    pub(crate) matchers: Vec<TokenTree>,
 }
 
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, Encodable, Decodable)]
 pub(crate) struct ProcMacro {
     pub(crate) kind: MacroKind,
     pub(crate) helpers: Vec<Symbol>,
+    /// For a derive macro, the trait of the same name next to it in its module, which it most
+    /// likely implements. Filled in once the whole crate has been cleaned.
+    pub(crate) derived_trait: Option<DefId>,
 }
 
 /// An type binding on an associated type (e.g., `A = Bar` in `Foo<A = Bar>` or
 /// `A: Send + Sync` in `Foo<A: Send + Sync>`).
-#[derive(Clone, PartialEq, Eq, Debug, Hash)]
+#[derive(Clone, PartialEq, Eq, Debug, Hash, Encodable, Decodable)]
 pub(crate) struct TypeBinding {
     pub(crate) assoc: PathSegment,
     pub(crate) kind: TypeBindingKind,
 }
 
-#[derive(Clone, PartialEq, Eq, Debug, Hash)]
+#[derive(Clone, PartialEq, Eq, Debug, Hash, Encodable, Decodable)]
 pub(crate) enum TypeBindingKind {
     Equality { term: Term },
     Constraint { bounds: Vec<GenericBound> },
@@ -1,27 +1,35 @@
 use crate::clean::auto_trait::AutoTraitFinder;
 use crate::clean::blanket_impl::BlanketImplFinder;
-use crate::clean::render_macro_matchers::render_macro_matcher;
+use crate::clean::cfg::Cfg;
+use crate::clean::render_macro_matchers::{render_macro_matcher, render_macro_transcriber};
 use crate::clean::{
-    inline, Clean, Crate, ExternalCrate, Generic, GenericArg, GenericArgs, ImportSource, Item,
-    ItemKind, Lifetime, Path, PathSegment, Primitive, PrimitiveType, Type, TypeBinding, Visibility,
+    inline, AttributesExt, Clean, Crate, ExternalCrate, Generic, GenericArg, GenericArgs,
+    ImportSource, Item, ItemKind, Lifetime, Path, PathSegment, Primitive, PrimitiveType, Term,
+    Type, TypeBinding, TypeBindingKind, Visibility,
 };
+use crate::config::{ItemOrder, NumberFormat};
 use crate::core::DocContext;
 use crate::formats::item_type::ItemType;
-use crate::visit_lib::LibEmbargoVisitor;
 
 use rustc_ast as ast;
 use rustc_ast::tokenstream::TokenTree;
+use rustc_ast::util::parser::{AssocOp, PREC_PREFIX};
+use rustc_data_structures::fx::{FxHashMap, FxHashSet};
 use rustc_data_structures::thin_vec::ThinVec;
 use rustc_hir as hir;
-use rustc_hir::def::{DefKind, Res};
-use rustc_hir::def_id::{DefId, LOCAL_CRATE};
+use rustc_hir::def::{CtorKind, DefKind, Res};
+use rustc_hir::def_id::{DefId, LocalDefId, CRATE_DEF_ID, LOCAL_CRATE};
 use rustc_middle::mir;
-use rustc_middle::mir::interpret::ConstValue;
-use rustc_middle::ty::subst::{GenericArgKind, SubstsRef};
-use rustc_middle::ty::{self, DefIdTree, TyCtxt};
+use rustc_middle::mir::interpret::{get_slice_bytes, ConstValue, Scalar};
+use rustc_middle::ty::subst::{GenericArgKind, Subst, SubstsRef};
+use rustc_middle::ty::{self, DefIdTree, Ty, TyCtxt};
+use rustc_span::hygiene::MacroKind;
 use rustc_span::symbol::{kw, sym, Symbol};
-use std::fmt::Write as _;
+use smallvec::SmallVec;
+use std::env;
+use std::fmt::{self, Write as _};
 use std::mem;
+use std::sync::{Arc, OnceLock};
 
 #[cfg(test)]
 mod tests;
@@ -29,10 +37,9 @@
 pub(crate) fn krate(cx: &mut DocContext<'_>) -> Crate {
     let module = crate::visit_ast::RustdocVisitor::new(cx).visit();
 
-    for &cnum in cx.tcx.crates(()) {
-        // Analyze doc-reachability for extern items
-        LibEmbargoVisitor::new(cx).visit_lib(cnum);
-    }
+    cx.cache.item_order =
+        cx.render_options.item_order.or_else(|| crate_item_order(cx)).unwrap_or_default();
+    cx.cache.auto_cfg = cx.render_options.auto_cfg;
 
     // Clean the crate, translating the entire librustc_ast AST to one that is
     // understood by rustdoc.
@@ -53,6 +60,14 @@ pub(crate) fn krate(cx: &mut DocContext<'_>) -> Crate {
         }
         _ => unreachable!(),
     }
+    if !cx.render_options.mask_crates.is_empty() {
+        for &cnum in cx.tcx.crates(()) {
+            let name = cx.tcx.crate_name(cnum);
+            if cx.render_options.mask_crates.iter().any(|masked| masked == name.as_str()) {
+                cx.cache.masked_crates.insert(cnum);
+            }
+        }
+    }
 
     let local_crate = ExternalCrate { crate_num: LOCAL_CRATE };
     let primitives = local_crate.primitives(cx.tcx);
@@ -73,14 +88,60 @@ pub(crate) fn krate(cx: &mut DocContext<'_>) -> Crate {
         }));
     }
 
+    link_derived_traits(&mut module);
+
     Crate { module, primitives, external_traits: cx.external_traits.clone() }
 }
 
+/// Reads the item order chosen with `#![doc(item_order = "...")]`, if any.
+fn crate_item_order(cx: &DocContext<'_>) -> Option<ItemOrder> {
+    let meta = cx
+        .tcx
+        .get_attrs(CRATE_DEF_ID.to_def_id(), sym::doc)
+        .flat_map(|attr| attr.meta_item_list().unwrap_or_default())
+        .find(|meta| meta.has_name(sym::item_order))?;
+    let order = meta.value_str().and_then(|order| order.as_str().parse().ok());
+    if order.is_none() {
+        cx.sess()
+            .struct_span_warn(meta.span(), "unknown item order")
+            .help("valid item orders are `alphabetical` and `source`")
+            .emit();
+    }
+    order
+}
+
+/// Links each derive macro to the trait with the same name in its module, following the
+/// convention of crates exporting both a trait and its derive (e.g. `serde::Serialize`).
+fn link_derived_traits(module: &mut Item) {
+    let ItemKind::ModuleItem(ref mut m) = *module.kind else { return };
+    let traits: FxHashMap<Symbol, DefId> = m
+        .items
+        .iter()
+        .filter_map(|item| match *item.kind {
+            ItemKind::TraitItem(_) => Some((item.name?, item.item_id.as_def_id()?)),
+            _ => None,
+        })
+        .collect();
+    for item in &mut m.items {
+        match *item.kind {
+            ItemKind::ProcMacroItem(ref mut mac) if mac.kind == MacroKind::Derive => {
+                mac.derived_trait = item.name.and_then(|name| traits.get(&name).copied());
+            }
+            ItemKind::ModuleItem(_) => link_derived_traits(item),
+            _ => {}
+        }
+    }
+}
+
+/// Cleans the substitutions of a path, skipping the self type if `skip_first` is set.
+///
+/// The args are collected with the exact capacity they need, so converting them to the boxed
+/// slice of `GenericArgs::AngleBracketed` doesn't reallocate.
 pub(crate) fn substs_to_args<'tcx>(
     cx: &mut DocContext<'tcx>,
     substs: &[ty::subst::GenericArg<'tcx>],
     mut skip_first: bool,
-) -> Vec<GenericArg> {
+) -> Box<[GenericArg]> {
     let mut ret_val =
         Vec::with_capacity(substs.len().saturating_sub(if skip_first { 1 } else { 0 }));
     ret_val.extend(substs.iter().filter_map(|kind| match kind.unpack() {
@@ -94,42 +155,78 @@ pub(crate) fn substs_to_args<'tcx>(
         GenericArgKind::Type(ty) => Some(GenericArg::Type(ty.clean(cx))),
         GenericArgKind::Const(ct) => Some(GenericArg::Const(Box::new(ct.clean(cx)))),
     }));
-    ret_val
+    ret_val.into_boxed_slice()
+}
+
+/// Removes the trailing substitutions which are equal to the default of their parameter, so that
+/// e.g. `HashMap<K, V, RandomState>` is shown as `HashMap<K, V>`.
+fn strip_default_substs<'tcx>(
+    tcx: TyCtxt<'tcx>,
+    did: DefId,
+    substs: SubstsRef<'tcx>,
+) -> &'tcx [ty::subst::GenericArg<'tcx>] {
+    let generics = tcx.generics_of(did);
+    if generics.count() != substs.len() {
+        return substs;
+    }
+    let defaulted = generics
+        .params
+        .iter()
+        .rev()
+        .take_while(|param| {
+            param.default_value(tcx).map_or(false, |default| {
+                let default = default.subst(tcx, substs);
+                let arg = substs[param.index as usize];
+                match (default.unpack(), arg.unpack()) {
+                    // Defaults like `{ 1 + 2 }` are only equal to `3` once evaluated.
+                    (GenericArgKind::Const(default), GenericArgKind::Const(arg)) => {
+                        let param_env = ty::ParamEnv::reveal_all();
+                        default.eval(tcx, param_env) == arg.eval(tcx, param_env)
+                    }
+                    _ => default == arg,
+                }
+            })
+        })
+        .count();
+    &substs[..substs.len() - defaulted]
 }
 
 fn external_generic_args<'tcx>(
     cx: &mut DocContext<'tcx>,
     did: DefId,
     has_self: bool,
-    bindings: Vec<TypeBinding>,
+    bindings: ThinVec<TypeBinding>,
     substs: SubstsRef<'tcx>,
 ) -> GenericArgs {
-    let args = substs_to_args(cx, substs, has_self);
-
-    if cx.tcx.fn_trait_kind_from_lang_item(did).is_some() {
-        let inputs =
-            // The trait's first substitution is the one after self, if there is one.
-            match substs.iter().nth(if has_self { 1 } else { 0 }).unwrap().expect_ty().kind() {
-                ty::Tuple(tys) => tys.iter().map(|t| t.clean(cx)).collect::<Vec<_>>().into(),
-                _ => return GenericArgs::AngleBracketed { args: args.into(), bindings: bindings.into() },
-            };
-        let output = None;
-        // FIXME(#20299) return type comes from a projection now
-        // match types[1].kind {
-        //     ty::Tuple(ref v) if v.is_empty() => None, // -> ()
-        //     _ => Some(types[1].clean(cx))
-        // };
-        GenericArgs::Parenthesized { inputs, output }
-    } else {
-        GenericArgs::AngleBracketed { args: args.into(), bindings: bindings.into() }
+    if cx.tcx.fn_trait_kind_from_lang_item(did).is_some()
+        // The trait's first substitution is the one after self, if there is one.
+        && let ty::Tuple(tys) =
+            substs.iter().nth(if has_self { 1 } else { 0 }).unwrap().expect_ty().kind()
+    {
+        let inputs = tys.iter().map(|t| t.clean(cx)).collect();
+        // The return type isn't one of the trait's substitutions, it comes from the projection
+        // on `FnOnce::Output` which was passed in as a binding.
+        let output = bindings.into_iter().find_map(|binding| match binding.kind {
+            TypeBindingKind::Equality { term: Term::Type(output) }
+                if binding.assoc.name == sym::Output && output != Type::Tuple(Vec::new()) =>
+            {
+                Some(Box::new(output))
+            }
+            _ => None,
+        });
+        return GenericArgs::Parenthesized { inputs, output };
     }
+
+    // The substitutions are only cleaned once we know they are shown in angle brackets.
+    let args = substs_to_args(cx, strip_default_substs(cx.tcx, did, substs), has_self);
+    GenericArgs::AngleBracketed { args, bindings }
 }
 
 pub(super) fn external_path<'tcx>(
     cx: &mut DocContext<'tcx>,
     did: DefId,
     has_self: bool,
-    bindings: Vec<TypeBinding>,
+    bindings: ThinVec<TypeBinding>,
     substs: SubstsRef<'tcx>,
 ) -> Path {
     let def_kind = cx.tcx.def_kind(did);
@@ -144,31 +241,90 @@ pub(super) fn external_path<'tcx>(
 }
 
 /// Remove the generic arguments from a path.
-pub(crate) fn strip_path_generics(mut path: Path) -> Path {
+///
+/// If `keep_lifetimes` is set, lifetime arguments are kept, so that e.g. `Trait<'static>` and
+/// `Trait<'a>` stay distinct.
+pub(crate) fn strip_path_generics(mut path: Path, keep_lifetimes: bool) -> Path {
     for ps in path.segments.iter_mut() {
-        ps.args = GenericArgs::AngleBracketed { args: Default::default(), bindings: ThinVec::new() }
+        let args = match ps.args {
+            GenericArgs::AngleBracketed { ref args, .. } if keep_lifetimes => {
+                args.iter().filter(|arg| matches!(arg, GenericArg::Lifetime(_))).cloned().collect()
+            }
+            _ => Default::default(),
+        };
+        ps.args = GenericArgs::AngleBracketed { args, bindings: ThinVec::new() }
     }
 
     path
 }
 
+/// Generic arguments nested deeper than this are elided by [`qpath_to_string`], so that names
+/// rendered from patterns stay short.
+const QPATH_GENERIC_ARGS_DEPTH: usize = 3;
+
 pub(crate) fn qpath_to_string(p: &hir::QPath<'_>) -> String {
+    let mut s = String::new();
+    push_qpath(&mut s, p, QPATH_GENERIC_ARGS_DEPTH);
+    s
+}
+
+/// Appends the path to `s`, rather than building a string for each of its generic arguments.
+fn push_qpath(s: &mut String, p: &hir::QPath<'_>, depth: usize) {
     let segments = match *p {
         hir::QPath::Resolved(_, path) => &path.segments,
-        hir::QPath::TypeRelative(_, segment) => return segment.ident.to_string(),
-        hir::QPath::LangItem(lang_item, ..) => return lang_item.name().to_string(),
+        hir::QPath::TypeRelative(_, segment) => {
+            s.push_str(segment.ident.as_str());
+            push_generic_args(s, segment.args, depth);
+            return;
+        }
+        hir::QPath::LangItem(lang_item, ..) => return s.push_str(lang_item.name().as_str()),
     };
 
-    let mut s = String::new();
     for (i, seg) in segments.iter().enumerate() {
         if i > 0 {
             s.push_str("::");
         }
         if seg.ident.name != kw::PathRoot {
             s.push_str(seg.ident.as_str());
+            push_generic_args(s, seg.args, depth);
         }
     }
-    s
+}
+
+/// Appends the angle-bracketed generic arguments of a path segment, eliding them as `<..>` once
+/// `depth` is exhausted.
+fn push_generic_args(s: &mut String, args: Option<&hir::GenericArgs<'_>>, depth: usize) {
+    // Paths in patterns can't have parenthesized arguments or associated item bindings.
+    let Some(args) = args else { return };
+    let args: SmallVec<[_; 4]> = args
+        .args
+        .iter()
+        .filter(|arg| !matches!(arg, hir::GenericArg::Lifetime(lt) if lt.is_elided()))
+        .collect();
+    if args.is_empty() {
+        return;
+    }
+    if depth == 0 {
+        s.push_str("<..>");
+        return;
+    }
+    s.push('<');
+    for (i, arg) in args.into_iter().enumerate() {
+        if i > 0 {
+            s.push_str(", ");
+        }
+        match arg {
+            hir::GenericArg::Lifetime(lt) => write!(s, "{}", lt).unwrap(),
+            hir::GenericArg::Type(hir::Ty { kind: hir::TyKind::Path(qpath), .. }) => {
+                push_qpath(s, qpath, depth - 1)
+            }
+            hir::GenericArg::Type(ty) => s.push_str(&rustc_hir_pretty::ty_to_string(ty)),
+            // Printing the expression of a const argument needs the body, which isn't worth
+            // looking up for a name.
+            hir::GenericArg::Const(_) | hir::GenericArg::Infer(_) => s.push('_'),
+        }
+    }
+    s.push('>');
 }
 
 pub(crate) fn build_deref_target_impls(
@@ -179,40 +335,86 @@ pub(crate) fn build_deref_target_impls(
     let tcx = cx.tcx;
 
     for item in items {
-        let target = match *item.kind {
-            ItemKind::AssocTypeItem(ref t, _) => &t.type_,
+        let mut target = match *item.kind {
+            ItemKind::AssocTypeItem(ref t, _) => t.type_.clone(),
             _ => continue,
         };
 
-        if let Some(prim) = target.primitive_type() {
-            let _prof_timer = cx.tcx.sess.prof.generic_activity("build_primitive_inherent_impls");
-            for did in prim.impls(tcx).filter(|did| !did.is_local()) {
-                inline::build_impl(cx, None, did, None, ret);
+        // Follow the whole chain of `Deref` impls (e.g. `MyBox<String> -> String -> str`), so
+        // that methods reachable through several derefs get documented as well.
+        let mut visited = FxHashSet::default();
+        loop {
+            if let Some(prim) = target.primitive_type() {
+                let _prof_timer =
+                    cx.tcx.sess.prof.generic_activity("build_primitive_inherent_impls");
+                for did in prim.impls(tcx).filter(|did| !did.is_local()) {
+                    inline::build_impl(cx, None, did, None, ret);
+                }
+                break;
             }
-        } else if let Type::Path { path } = target {
+            let Type::Path { ref path } = target else { break };
             let did = path.def_id();
+            if !visited.insert(did) {
+                // `Deref` impls going in circles, e.g. `impl Deref<Target = S> for S`.
+                break;
+            }
             if !did.is_local() {
                 inline::build_impls(cx, None, did, None, ret);
             }
+            match deref_target(tcx, did) {
+                Some(ty) => target = ty.clean(cx),
+                None => break,
+            }
         }
     }
 }
 
+/// Returns the `Deref::Target` of the type with the given `DefId`, if it implements `Deref`.
+fn deref_target(tcx: TyCtxt<'_>, did: DefId) -> Option<Ty<'_>> {
+    let deref_trait = tcx.lang_items().deref_trait()?;
+    let impl_did = tcx.non_blanket_impls_for_ty(deref_trait, tcx.type_of(did)).next()?;
+    let target = tcx
+        .associated_items(impl_did)
+        .in_definition_order()
+        .find(|item| item.kind == ty::AssocKind::Type)?;
+    Some(tcx.type_of(target.def_id))
+}
+
 pub(crate) fn name_from_pat(p: &hir::Pat<'_>) -> Symbol {
     use rustc_hir::*;
     debug!("trying to get a name from pattern: {:?}", p);
 
     Symbol::intern(&match p.kind {
-        PatKind::Wild | PatKind::Struct(..) => return kw::Underscore,
+        PatKind::Wild => return kw::Underscore,
         PatKind::Binding(_, _, ident, _) => return ident.name,
-        PatKind::TupleStruct(ref p, ..) | PatKind::Path(ref p) => qpath_to_string(p),
+        PatKind::Path(ref p) => qpath_to_string(p),
+        PatKind::TupleStruct(ref p, elts, dotdot) => {
+            format!("{}({})", qpath_to_string(p), names_from_tuple_pats(elts, dotdot))
+        }
+        PatKind::Struct(ref p, fields, etc) => {
+            let mut fields = fields
+                .iter()
+                .map(|field| {
+                    if field.is_shorthand {
+                        name_from_pat(field.pat).to_string()
+                    } else {
+                        format!("{}: {}", field.ident, name_from_pat(field.pat))
+                    }
+                })
+                .collect::<Vec<String>>();
+            if etc {
+                fields.push("..".to_string());
+            }
+            if fields.is_empty() {
+                format!("{} {{}}", qpath_to_string(p))
+            } else {
+                format!("{} {{ {} }}", qpath_to_string(p), fields.join(", "))
+            }
+        }
         PatKind::Or(pats) => {
             pats.iter().map(|p| name_from_pat(p).to_string()).collect::<Vec<String>>().join(" | ")
         }
-        PatKind::Tuple(elts, _) => format!(
-            "({})",
-            elts.iter().map(|p| name_from_pat(p).to_string()).collect::<Vec<String>>().join(", ")
-        ),
+        PatKind::Tuple(elts, dotdot) => format!("({})", names_from_tuple_pats(elts, dotdot)),
         PatKind::Box(p) => return name_from_pat(&*p),
         PatKind::Ref(p, _) => return name_from_pat(&*p),
         PatKind::Lit(..) => {
@@ -231,19 +433,26 @@ pub(crate) fn name_from_pat(p: &hir::Pat<'_>) -> Symbol {
     })
 }
 
+/// Renders the elements of a tuple or tuple struct pattern, including the `..` if there is one.
+fn names_from_tuple_pats(elts: &[hir::Pat<'_>], dotdot: Option<usize>) -> String {
+    let mut names = elts.iter().map(|p| name_from_pat(p).to_string()).collect::<Vec<String>>();
+    if let Some(pos) = dotdot {
+        names.insert(pos, "..".to_string());
+    }
+    names.join(", ")
+}
+
 pub(crate) fn print_const(cx: &DocContext<'_>, n: ty::Const<'_>) -> String {
     match n.kind() {
-        ty::ConstKind::Unevaluated(ty::Unevaluated { def, substs: _, promoted }) => {
-            let mut s = if let Some(def) = def.as_local() {
+        // Promoted constants are shown as the expression they are promoted from, their index is
+        // meaningless to readers.
+        ty::ConstKind::Unevaluated(ty::Unevaluated { def, substs: _, promoted: _ }) => {
+            if let Some(def) = def.as_local() {
                 let hir_id = cx.tcx.hir().local_def_id_to_hir_id(def.did);
-                print_const_expr(cx.tcx, cx.tcx.hir().body_owned_by(hir_id))
+                print_unevaluated_const_expr(cx.tcx, cx.tcx.hir().body_owned_by(hir_id))
             } else {
                 inline::print_inlined_const(cx.tcx, def.did)
-            };
-            if let Some(promoted) = promoted {
-                s.push_str(&format!("::{:?}", promoted))
             }
-            s
         }
         _ => {
             let mut s = n.to_string();
@@ -261,21 +470,128 @@ pub(crate) fn print_const(cx: &DocContext<'_>, n: ty::Const<'_>) -> String {
     }
 }
 
-pub(crate) fn print_evaluated_const(tcx: TyCtxt<'_>, def_id: DefId) -> Option<String> {
+/// Renders the expression of an unevaluated or anonymous constant used in a type, like the `N + 1`
+/// of `[u8; N + 1]`.
+///
+/// Simple expressions made of literals, paths and operators are pretty-printed like the ones of
+/// other crates are, so that they don't depend on how the source is formatted, and the constants
+/// they name are printed by their full path, so that they don't depend on how they were imported.
+/// Anything else is printed as written.
+pub(crate) fn print_unevaluated_const_expr(tcx: TyCtxt<'_>, body: hir::BodyId) -> String {
+    fn is_simple(expr: &hir::Expr<'_>) -> bool {
+        match expr.kind {
+            hir::ExprKind::Lit(_) | hir::ExprKind::Path(_) => true,
+            hir::ExprKind::Unary(_, expr) => is_simple(expr),
+            hir::ExprKind::Binary(_, lhs, rhs) => is_simple(lhs) && is_simple(rhs),
+            hir::ExprKind::Block(block, None) => {
+                block.stmts.is_empty() && block.expr.map_or(false, is_simple)
+            }
+            _ => false,
+        }
+    }
+
+    let hir = tcx.hir();
+    let expr = &hir.body(body).value;
+    if is_simple(expr) {
+        print_simple_const_expr(tcx, expr)
+    } else {
+        print_const_expr(tcx, body)
+    }
+}
+
+fn print_simple_const_expr(tcx: TyCtxt<'_>, expr: &hir::Expr<'_>) -> String {
+    // Operands binding less tightly than their operator need to be parenthesized, as do right
+    // operands binding as tightly since all the binary operators are left-associative.
+    fn print_operand(tcx: TyCtxt<'_>, expr: &hir::Expr<'_>, prec: i8, is_rhs: bool) -> String {
+        let s = print_simple_const_expr(tcx, expr);
+        match expr.kind {
+            hir::ExprKind::Binary(op, ..) => {
+                let op_prec = AssocOp::from_ast_binop(op.node.into()).precedence() as i8;
+                if op_prec < prec || (is_rhs && op_prec == prec) {
+                    format!("({})", s)
+                } else {
+                    s
+                }
+            }
+            _ => s,
+        }
+    }
+
+    match expr.kind {
+        hir::ExprKind::Path(hir::QPath::Resolved(None, path)) => match path.res {
+            Res::Def(DefKind::ConstParam, _) => path.segments.last().unwrap().ident.to_string(),
+            Res::Def(DefKind::Const | DefKind::AssocConst, did) => tcx.def_path_str(did),
+            _ => rustc_hir_pretty::id_to_string(&tcx.hir(), expr.hir_id),
+        },
+        hir::ExprKind::Unary(op, operand) => {
+            format!("{}{}", op.as_str(), print_operand(tcx, operand, PREC_PREFIX, false))
+        }
+        hir::ExprKind::Binary(op, lhs, rhs) => {
+            let prec = AssocOp::from_ast_binop(op.node.into()).precedence() as i8;
+            format!(
+                "{} {} {}",
+                print_operand(tcx, lhs, prec, false),
+                op.node.as_str(),
+                print_operand(tcx, rhs, prec, true),
+            )
+        }
+        hir::ExprKind::Block(hir::Block { expr: Some(expr), .. }, None) => {
+            format!("{{ {} }}", print_simple_const_expr(tcx, expr))
+        }
+        _ => rustc_hir_pretty::id_to_string(&tcx.hir(), expr.hir_id),
+    }
+}
+
+pub(crate) fn print_evaluated_const(
+    tcx: TyCtxt<'_>,
+    def_id: DefId,
+    format: NumberFormat,
+) -> Option<String> {
+    // The constants of a generic impl depend on the parameters of the impl, even when their
+    // expression doesn't mention them.
+    if let Some(impl_did) = tcx.impl_of_method(def_id)
+        && tcx.generics_of(impl_did).requires_monomorphization(tcx)
+    {
+        return None;
+    }
     tcx.const_eval_poly(def_id).ok().and_then(|val| {
         let ty = tcx.type_of(def_id);
         match (val, ty.kind()) {
+            (ConstValue::Slice { .. }, &ty::Ref(_, inner, _)) if inner.is_str() => {
+                let const_ = mir::ConstantKind::from_value(val, ty);
+                Some(print_const_with_custom_print_scalar(tcx, const_, format))
+            }
             (_, &ty::Ref(..)) => None,
+            (ConstValue::Scalar(scalar), &ty::Adt(adt, _)) if adt.is_enum() => {
+                print_fieldless_enum_variant(tcx, ty, adt, scalar)
+            }
             (ConstValue::Scalar(_), &ty::Adt(_, _)) => None,
             (ConstValue::Scalar(_), _) => {
                 let const_ = mir::ConstantKind::from_value(val, ty);
-                Some(print_const_with_custom_print_scalar(tcx, const_))
+                Some(print_const_with_custom_print_scalar(tcx, const_, format))
             }
             _ => None,
         }
     })
 }
 
+/// Maps the value of a fieldless enum constant back to the path of the variant it holds.
+fn print_fieldless_enum_variant<'tcx>(
+    tcx: TyCtxt<'tcx>,
+    ty: Ty<'tcx>,
+    adt: ty::AdtDef<'tcx>,
+    scalar: Scalar,
+) -> Option<String> {
+    if !adt.variants().iter().all(|v| v.ctor_kind == CtorKind::Const) {
+        return None;
+    }
+    // The scalar only holds the tag, which may be narrower than the discriminant type.
+    let size = tcx.layout_of(ty::ParamEnv::empty().and(ty)).ok()?.size;
+    let bits = scalar.to_bits(size).ok()?;
+    let (idx, _) = adt.discriminants(tcx).find(|(_, discr)| size.truncate(discr.val) == bits)?;
+    Some(tcx.def_path_str(adt.variant(idx).def_id))
+}
+
 fn format_integer_with_underscore_sep(num: &str) -> String {
     let num_chars: Vec<_> = num.chars().collect();
     let mut num_start_index = if num_chars.get(0) == Some(&'-') { 1 } else { 0 };
@@ -302,23 +618,76 @@ fn format_integer_with_underscore_sep(num: &str) -> String {
         .collect()
 }
 
-fn print_const_with_custom_print_scalar(tcx: TyCtxt<'_>, ct: mir::ConstantKind<'_>) -> String {
-    // Use a slightly different format for integer types which always shows the actual value.
+/// Adds separators to the integer part of a float, leaving the fractional part and the exponent
+/// alone.
+fn format_float_with_underscore_sep(num: &str) -> String {
+    let (int, rest) = num.split_at(num.find(|c| c == '.' || c == 'e').unwrap_or(num.len()));
+    format_integer_with_underscore_sep(int) + rest
+}
+
+fn format_integer(num: &str, format: NumberFormat) -> String {
+    if format.separators { format_integer_with_underscore_sep(num) } else { num.to_string() }
+}
+
+/// Formats a float so that parsing it back yields the same value, using the associated constants
+/// for the values which can't be written as a literal.
+fn format_float(value: impl fmt::Debug + fmt::LowerExp, ty: &str, format: NumberFormat) -> String {
+    let value = if format.exponent { format!("{value:e}") } else { format!("{value:?}") };
+    match &*value {
+        "NaN" => format!("{ty}::NAN"),
+        "inf" => format!("{ty}::INFINITY"),
+        "-inf" => format!("{ty}::NEG_INFINITY"),
+        value if format.separators => format!("{}{ty}", format_float_with_underscore_sep(value)),
+        value => format!("{value}{ty}"),
+    }
+}
+
+fn print_const_with_custom_print_scalar(
+    tcx: TyCtxt<'_>,
+    ct: mir::ConstantKind<'_>,
+    format: NumberFormat,
+) -> String {
+    // Use a slightly different format for integer, `bool`, `char`, float and `&str` types which
+    // always shows the actual value as it could be written in source.
     // For all other types, fallback to the original `pretty_print_const`.
     match (ct, ct.ty().kind()) {
+        (mir::ConstantKind::Val(ConstValue::Scalar(int), _), ty::Bool) => match int.to_bool() {
+            Ok(b) => b.to_string(),
+            Err(_) => ct.to_string(),
+        },
+        (mir::ConstantKind::Val(ConstValue::Scalar(int), _), ty::Char) => match int.to_char() {
+            Ok(c) => format!("{c:?}"),
+            Err(_) => ct.to_string(),
+        },
+        (mir::ConstantKind::Val(ConstValue::Scalar(int), _), ty::Float(ty::FloatTy::F32)) => {
+            match int.to_u32() {
+                Ok(bits) => format_float(f32::from_bits(bits), "f32", format),
+                Err(_) => ct.to_string(),
+            }
+        }
+        (mir::ConstantKind::Val(ConstValue::Scalar(int), _), ty::Float(ty::FloatTy::F64)) => {
+            match int.to_u64() {
+                Ok(bits) => format_float(f64::from_bits(bits), "f64", format),
+                Err(_) => ct.to_string(),
+            }
+        }
+        (mir::ConstantKind::Val(val @ ConstValue::Slice { .. }, _), ty::Ref(_, inner, _))
+            if inner.is_str() =>
+        {
+            match std::str::from_utf8(get_slice_bytes(&tcx, val)) {
+                Ok(s) => format!("{s:?}"),
+                Err(_) => ct.to_string(),
+            }
+        }
         (mir::ConstantKind::Val(ConstValue::Scalar(int), _), ty::Uint(ui)) => {
-            format!("{}{}", format_integer_with_underscore_sep(&int.to_string()), ui.name_str())
+            format!("{}{}", format_integer(&int.to_string(), format), ui.name_str())
         }
         (mir::ConstantKind::Val(ConstValue::Scalar(int), _), ty::Int(i)) => {
             let ty = tcx.lift(ct.ty()).unwrap();
             let size = tcx.layout_of(ty::ParamEnv::empty().and(ty)).unwrap().size;
             let data = int.assert_bits(size);
             let sign_extended_data = size.sign_extend(data) as i128;
-            format!(
-                "{}{}",
-                format_integer_with_underscore_sep(&sign_extended_data.to_string()),
-                i.name_str()
-            )
+            format!("{}{}", format_integer(&sign_extended_data.to_string(), format), i.name_str())
         }
         _ => ct.to_string(),
     }
@@ -353,6 +722,28 @@ pub(crate) fn print_const_expr(tcx: TyCtxt<'_>, body: hir::BodyId) -> String {
     snippet.unwrap_or_else(|| rustc_hir_pretty::id_to_string(&hir, body.hir_id))
 }
 
+/// Length in bytes after which a constant expression is truncated in the item declaration, the
+/// rest of it being hidden behind a toggle.
+pub(crate) const MAX_CONST_EXPR_LEN: usize = 256;
+
+/// Splits a constant expression into the part that is always shown and the rest, if it is longer
+/// than [`MAX_CONST_EXPR_LEN`]. The cut is made after a `,` or whitespace when there is one, so
+/// that array and struct literals are cut between their elements.
+pub(crate) fn split_const_expr(expr: &str) -> (&str, Option<&str>) {
+    if expr.len() <= MAX_CONST_EXPR_LEN {
+        return (expr, None);
+    }
+    let mut end = MAX_CONST_EXPR_LEN;
+    while !expr.is_char_boundary(end) {
+        end -= 1;
+    }
+    let cut = expr[..end]
+        .rfind(|c: char| c == ',' || c.is_whitespace())
+        .map_or(end, |pos| pos + 1)
+        .max(1);
+    (&expr[..cut], Some(&expr[cut..]))
+}
+
 /// Given a type Path, resolve it to a Type using the TyCtxt
 pub(crate) fn resolve_type(cx: &mut DocContext<'_>, path: Path) -> Type {
     debug!("resolve_type({:?})", path);
@@ -372,6 +763,14 @@ pub(crate) fn get_auto_trait_and_blanket_impls(
     cx: &mut DocContext<'_>,
     item_def_id: DefId,
 ) -> impl Iterator<Item = Item> {
+    // `#[doc(no_synthetic_impls)]` can be put on the type itself or on the crate defining it.
+    let tcx = cx.tcx;
+    if has_doc_flag(tcx, item_def_id, sym::no_synthetic_impls)
+        || has_doc_flag(tcx, item_def_id.krate.as_def_id(), sym::no_synthetic_impls)
+    {
+        return Vec::new().into_iter();
+    }
+
     let auto_impls = cx
         .sess()
         .prof
@@ -382,7 +781,22 @@ pub(crate) fn get_auto_trait_and_blanket_impls(
         .prof
         .generic_activity("get_blanket_impls")
         .run(|| BlanketImplFinder { cx }.get_blanket_impls(item_def_id));
-    auto_impls.into_iter().chain(blanket_impls)
+
+    // A blanket impl and its specializations, or the same impl reached through several crates,
+    // would each be listed on the page although they read the same. Only keep the first impl with
+    // a given trait and substitutions. The def-path hash of the trait tells apart the traits of
+    // two versions of a crate, so their impls are both kept.
+    let mut seen = FxHashSet::default();
+    let blanket_impls: Vec<_> = blanket_impls
+        .into_iter()
+        .filter(|item| {
+            let ItemKind::ImplItem(ref impl_) = *item.kind else { return true };
+            let Some(ref trait_) = impl_.trait_ else { return true };
+            let args = trait_.segments.last().map(|segment| segment.args.clone());
+            seen.insert((tcx.def_path_hash(trait_.def_id()), args, impl_.for_.clone()))
+        })
+        .collect();
+    auto_impls.into_iter().chain(blanket_impls).collect::<Vec<_>>().into_iter()
 }
 
 /// If `res` has a documentation page associated, store it in the cache.
@@ -419,23 +833,132 @@ pub(crate) fn register_res(cx: &mut DocContext<'_>, res: Res) -> DefId {
             id,
         ) => return id,
     };
+    if let Some(graph) = &mut cx.dependency_graph {
+        graph.record_use(did);
+    }
     if did.is_local() {
         return did;
     }
     inline::record_extern_fqn(cx, did, kind);
-    if let ItemType::Trait = kind {
-        inline::record_extern_trait(cx, did);
+    match kind {
+        ItemType::Trait => inline::record_extern_trait(cx, did),
+        ItemType::TraitAlias => inline::record_extern_trait_alias(cx, did),
+        // Variants are documented on the page of their enum, which `href` needs to know about.
+        ItemType::Variant => inline::record_extern_fqn(cx, cx.tcx.parent(did), ItemType::Enum),
+        _ => {}
     }
     did
 }
 
-pub(crate) fn resolve_use_source(cx: &mut DocContext<'_>, path: Path) -> ImportSource {
+pub(crate) fn resolve_use_source(
+    cx: &mut DocContext<'_>,
+    path: Path,
+    reexport_chain: Vec<DefId>,
+) -> ImportSource {
     ImportSource {
         did: if path.res.opt_def_id().is_none() { None } else { Some(register_res(cx, path.res)) },
         path,
+        reexport_chain,
     }
 }
 
+/// Returns the modules a `use` path goes through before reaching the module defining the
+/// imported item, outermost first.
+///
+/// Re-exports made by local modules are followed to the `use` they come from. Other crates don't
+/// record this, so the chain stops at the first module of another crate.
+pub(crate) fn reexport_chain(tcx: TyCtxt<'_>, path: &hir::Path<'_>) -> Vec<DefId> {
+    let mut chain = Vec::new();
+    let Some(defining_mod) =
+        path.res.opt_def_id().and_then(|did| find_nearest_parent_module(tcx, did))
+    else {
+        return chain;
+    };
+    let mut segments = path.segments;
+    while let [.., module, last] = segments
+        && let Some(Res::Def(DefKind::Mod, module_did)) = module.res
+        && module_did != defining_mod
+        && !chain.contains(&module_did)
+    {
+        chain.push(module_did);
+        let Some(local) = module_did.as_local() else { break };
+        let next = tcx.hir().module_items(local).find_map(|id| {
+            let item = tcx.hir().item(id);
+            match item.kind {
+                hir::ItemKind::Use(path, hir::UseKind::Single) if item.ident == last.ident => {
+                    Some(path)
+                }
+                _ => None,
+            }
+        });
+        let Some(next) = next else { break };
+        segments = next.segments;
+    }
+    chain
+}
+
+/// Records the `chain` of modules re-exporting the inlined item `did`, see [`reexport_chain`].
+pub(crate) fn record_reexport_chain(cx: &mut DocContext<'_>, did: DefId, chain: &[DefId]) {
+    if chain.is_empty() {
+        return;
+    }
+    // Imports re-exporting the item further down the chain are inlined too (and stripped later on
+    // if they're private), keep the chain of the outermost one.
+    let recorded = cx.cache.reexport_chains.entry(did).or_default();
+    if recorded.len() < chain.len() {
+        *recorded = chain.to_vec();
+    }
+}
+
+/// Returns whether `module` imports `did` under `name`. This isn't the case for an item brought
+/// by a glob import if the module has an item, or an explicit import, with the same name.
+pub(crate) fn is_imported_as(
+    tcx: TyCtxt<'_>,
+    module: LocalDefId,
+    name: Symbol,
+    did: DefId,
+) -> bool {
+    tcx.module_reexports(module)
+        .unwrap_or_default()
+        .iter()
+        .any(|child| child.ident.name == name && child.res.opt_def_id() == Some(did))
+}
+
+/// Adds to the `cfg` of an item inlined through the `use` item `import` the ones the item depends on
+/// besides its own: the ones of the import, of the modules defining the item and of the `chain` of
+/// modules re-exporting it, including their parents.
+///
+/// The `cfg` of the module the item is inlined into is added by the `propagate-doc-cfg` pass.
+pub(crate) fn add_reexport_cfg(
+    cx: &DocContext<'_>,
+    item: &mut Item,
+    import: DefId,
+    chain: &[DefId],
+) {
+    let Some(did) = item.item_id.as_def_id() else { return };
+    let tcx = cx.tcx;
+    let cfg_of = |did| tcx.get_attrs_unchecked(did).cfg(tcx, &cx.cache);
+
+    let mut cfg = item
+        .cfg
+        .take()
+        .map_or(Cfg::True, |cfg| Arc::try_unwrap(cfg).unwrap_or_else(|cfg| Cfg::clone(&cfg)));
+    if let Some(import_cfg) = cfg_of(import) {
+        cfg &= Cfg::clone(&import_cfg);
+    }
+    let mut visited = FxHashSet::default();
+    for module in chain.iter().copied().chain(find_nearest_parent_module(tcx, did)) {
+        let mut module = Some(module);
+        while let Some(m) = module && visited.insert(m) {
+            if let Some(module_cfg) = cfg_of(m) {
+                cfg &= Cfg::clone(&module_cfg);
+            }
+            module = tcx.opt_parent(m);
+        }
+    }
+    item.cfg = if cfg == Cfg::True { None } else { Some(Arc::new(cfg)) };
+}
+
 pub(crate) fn enter_impl_trait<'tcx, F, R>(cx: &mut DocContext<'tcx>, f: F) -> R
 where
     F: FnOnce(&mut DocContext<'tcx>) -> R,
@@ -481,55 +1004,119 @@ pub(crate) fn has_doc_flag(tcx: TyCtxt<'_>, did: DefId, flag: Symbol) -> bool {
     })
 }
 
+/// The hex-encoded [`DefPathHash`](rustc_span::def_id::DefPathHash) of `did`, which stays the
+/// same across runs of rustdoc as long as the path of the item and the `StableCrateId` of its
+/// crate don't change.
+pub(crate) fn stable_def_path_hash(tcx: TyCtxt<'_>, did: DefId) -> String {
+    tcx.def_path_hash(did).0.to_hex()
+}
+
+/// A link to `doc.rust-lang.org` that includes the channel name.
+///
+/// Set by `bootstrap::Builder::doc_rust_lang_org_channel` in order to keep tests passing on beta/stable.
+const DOC_RUST_LANG_ORG_CHANNEL: &str = env!("DOC_RUST_LANG_ORG_CHANNEL");
+
+static DOC_RUST_LANG_ORG_OVERRIDE: OnceLock<String> = OnceLock::new();
+
+/// Makes [`doc_rust_lang_org_channel`] return `url`, as requested by `--rust-doc-base-url`.
+///
+/// Must be called before the first call to [`doc_rust_lang_org_channel`] to have any effect.
+pub(crate) fn set_doc_rust_lang_org_channel(url: &str) {
+    let _ = DOC_RUST_LANG_ORG_OVERRIDE.set(url.trim_end_matches('/').to_owned());
+}
+
 /// A link to `doc.rust-lang.org` that includes the channel name. Use this instead of manual links
 /// so that the channel is consistent.
 ///
-/// Set by `bootstrap::Builder::doc_rust_lang_org_channel` in order to keep tests passing on beta/stable.
-pub(crate) const DOC_RUST_LANG_ORG_CHANNEL: &str = env!("DOC_RUST_LANG_ORG_CHANNEL");
+/// It can be pointed at a mirror of the Rust documentation with `--rust-doc-base-url` or the
+/// `RUSTDOC_RUST_DOC_BASE_URL` environment variable.
+pub(crate) fn doc_rust_lang_org_channel() -> &'static str {
+    DOC_RUST_LANG_ORG_OVERRIDE.get_or_init(|| match env::var("RUSTDOC_RUST_DOC_BASE_URL") {
+        Ok(url) if !url.is_empty() => url.trim_end_matches('/').to_owned(),
+        _ => DOC_RUST_LANG_ORG_CHANNEL.to_owned(),
+    })
+}
 
 /// Render a sequence of macro arms in a format suitable for displaying to the user
-/// as part of an item declaration.
+/// as part of an item declaration, pushing the byte range of each arm in `out` to `ranges`.
+///
+/// Each arm is a matcher along with its transcriber, which is only rendered if it is provided;
+/// otherwise the body is collapsed to `{ ... }`.
 pub(super) fn render_macro_arms<'a>(
     tcx: TyCtxt<'_>,
-    matchers: impl Iterator<Item = &'a TokenTree>,
+    arms: impl Iterator<Item = (&'a TokenTree, Option<&'a TokenTree>)>,
     arm_delim: &str,
-) -> String {
-    let mut out = String::new();
-    for matcher in matchers {
-        writeln!(out, "    {} => {{ ... }}{}", render_macro_matcher(tcx, matcher), arm_delim)
-            .unwrap();
+    out: &mut String,
+    ranges: &mut Vec<(u32, u32)>,
+) {
+    for (matcher, transcriber) in arms {
+        out.push_str("    ");
+        let lo = out.len() as u32;
+        write!(
+            out,
+            "{} => {}",
+            render_macro_matcher(tcx, matcher, 4),
+            render_macro_body(tcx, transcriber),
+        )
+        .unwrap();
+        ranges.push((lo, out.len() as u32));
+        writeln!(out, "{}", arm_delim).unwrap();
     }
-    out
 }
 
+/// Renders the body of a macro arm, collapsing it to `{ ... }` unless the transcriber is given.
+fn render_macro_body(tcx: TyCtxt<'_>, transcriber: Option<&TokenTree>) -> String {
+    match transcriber {
+        Some(transcriber) => render_macro_transcriber(tcx, transcriber),
+        None => "{ ... }".to_owned(),
+    }
+}
+
+/// Returns the matcher of each arm of the macro.
+pub(super) fn macro_matchers(def: &ast::MacroDef) -> Vec<TokenTree> {
+    def.body.inner_tokens().into_trees().step_by(4).collect()
+}
+
+/// Renders the declaration of a macro, along with the byte range of each of its arms in it.
 pub(super) fn display_macro_source(
     cx: &mut DocContext<'_>,
     name: Symbol,
     def: &ast::MacroDef,
     def_id: DefId,
     vis: Visibility,
-) -> String {
+) -> (String, Vec<(u32, u32)>) {
+    let document_bodies = cx.render_options.document_macro_bodies;
     let tts: Vec<_> = def.body.inner_tokens().into_trees().collect();
-    // Extract the spans of all matchers. They represent the "interface" of the macro.
-    let matchers = tts.chunks(4).map(|arm| &arm[0]);
+    // Each arm is made of a matcher, `=>`, a transcriber and a separator. The matchers represent
+    // the "interface" of the macro, the transcribers are only shown with `--document-macro-bodies`.
+    let arms: Vec<_> = tts
+        .chunks(4)
+        .map(|arm| (&arm[0], if document_bodies { arm.get(2) } else { None }))
+        .collect();
 
+    let mut source = String::new();
+    let mut ranges = Vec::new();
     if def.macro_rules {
-        format!("macro_rules! {} {{\n{}}}", name, render_macro_arms(cx.tcx, matchers, ";"))
+        writeln!(source, "macro_rules! {} {{", name).unwrap();
+        render_macro_arms(cx.tcx, arms.into_iter(), ";", &mut source, &mut ranges);
+        source.push('}');
+    } else if let [(matcher, transcriber)] = arms[..] {
+        // `macro name($args) { ... }` and `macro name { ($args) => { ... } }` are the same
+        // macro, render the shorter form.
+        write!(source, "{}macro {}", vis.to_src_with_space(cx.tcx, def_id), name).unwrap();
+        let lo = source.len() as u32;
+        write!(
+            source,
+            "{} {}",
+            render_macro_matcher(cx.tcx, matcher, 0),
+            render_macro_body(cx.tcx, transcriber),
+        )
+        .unwrap();
+        ranges.push((lo, source.len() as u32));
     } else {
-        if matchers.len() <= 1 {
-            format!(
-                "{}macro {}{} {{\n    ...\n}}",
-                vis.to_src_with_space(cx.tcx, def_id),
-                name,
-                matchers.map(|matcher| render_macro_matcher(cx.tcx, matcher)).collect::<String>(),
-            )
-        } else {
-            format!(
-                "{}macro {} {{\n{}}}",
-                vis.to_src_with_space(cx.tcx, def_id),
-                name,
-                render_macro_arms(cx.tcx, matchers, ","),
-            )
-        }
+        writeln!(source, "{}macro {} {{", vis.to_src_with_space(cx.tcx, def_id), name).unwrap();
+        render_macro_arms(cx.tcx, arms.into_iter(), ",", &mut source, &mut ranges);
+        source.push('}');
     }
+    (source, ranges)
 }
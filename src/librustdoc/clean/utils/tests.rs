@@ -39,3 +39,23 @@ fn int_format_octal() {
     assert_eq!(format_integer_with_underscore_sep("-0b101101011"), "-0b1_0110_1011");
     assert_eq!(format_integer_with_underscore_sep("-0b01101011"), "-0b0110_1011");
 }
+
+#[test]
+fn float_format() {
+    assert_eq!(format_float_with_underscore_sep("1234567.125"), "1_234_567.125");
+    assert_eq!(format_float_with_underscore_sep("-1234.5"), "-1_234.5");
+    assert_eq!(format_float_with_underscore_sep("0.00012345"), "0.00012345");
+    assert_eq!(format_float_with_underscore_sep("1e20"), "1e20");
+    assert_eq!(format_float_with_underscore_sep("12345e-7"), "12_345e-7");
+}
+
+#[test]
+fn float_format_options() {
+    let plain = NumberFormat { separators: false, exponent: false };
+    let exponent = NumberFormat { separators: false, exponent: true };
+    assert_eq!(format_float(1234.5f64, "f64", NumberFormat::default()), "1_234.5f64");
+    assert_eq!(format_float(1234.5f64, "f64", plain), "1234.5f64");
+    assert_eq!(format_float(1234.5f64, "f64", exponent), "1.2345e3f64");
+    assert_eq!(format_float(f32::NAN, "f32", exponent), "f32::NAN");
+    assert_eq!(format_float(f32::NEG_INFINITY, "f32", plain), "f32::NEG_INFINITY");
+}
@@ -2,7 +2,8 @@
 use std::convert::TryFrom;
 use std::ffi::OsStr;
 use std::fmt;
-use std::path::PathBuf;
+use std::iter;
+use std::path::{Path, PathBuf};
 use std::str::FromStr;
 
 use rustc_data_structures::fx::FxHashMap;
@@ -66,6 +67,8 @@ pub(crate) struct Options {
     // Basic options / Options passed directly to rustc
     /// The crate root or Markdown file to load.
     pub(crate) input: PathBuf,
+    /// The crate roots given after `input`, documented after it into the same output directory.
+    pub(crate) other_inputs: Vec<PathBuf>,
     /// The name of the crate being documented.
     pub(crate) crate_name: Option<String>,
     /// Whether or not this is a proc-macro crate
@@ -135,6 +138,9 @@ pub(crate) struct Options {
     /// Whether to run the `calculate-doc-coverage` pass, which counts the number of public items
     /// with and without documentation.
     pub(crate) show_coverage: bool,
+    /// Whether to run the `report-hidden` pass, which lists the public items left out of the
+    /// documentation.
+    pub(crate) report_hidden: bool,
 
     // Options that alter generated documentation pages
     /// Crate version to note on the sidebar of generated docs.
@@ -170,6 +176,7 @@ fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
 
         f.debug_struct("Options")
             .field("input", &self.input)
+            .field("other_inputs", &self.other_inputs)
             .field("crate_name", &self.crate_name)
             .field("proc_macro_crate", &self.proc_macro_crate)
             .field("error_format", &self.error_format)
@@ -190,6 +197,7 @@ fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
             .field("test_run_directory", &self.test_run_directory)
             .field("persist_doctests", &self.persist_doctests)
             .field("show_coverage", &self.show_coverage)
+            .field("report_hidden", &self.report_hidden)
             .field("crate_version", &self.crate_version)
             .field("render_options", &self.render_options)
             .field("runtool", &self.runtool)
@@ -217,12 +225,9 @@ pub(crate) struct RenderOptions {
     ///
     /// Be aware: This option can come both from the CLI and from crate attributes!
     pub(crate) playground_url: Option<String>,
-    /// Whether to sort modules alphabetically on a module page instead of using declaration order.
-    /// `true` by default.
-    //
-    // FIXME(misdreavus): the flag name is `--sort-modules-by-appearance` but the meaning is
-    // inverted once read.
-    pub(crate) sort_modules_alphabetically: bool,
+    /// The order of the items on module pages given with `--item-order` or
+    /// `--sort-modules-by-appearance`. If absent, `#![doc(item_order = "...")]` is used.
+    pub(crate) item_order: Option<ItemOrder>,
     /// List of themes to extend the docs with. Original argument name is included to assist in
     /// displaying errors if it fails a theme check.
     pub(crate) themes: Vec<StylePath>,
@@ -270,6 +275,38 @@ pub(crate) struct RenderOptions {
     pub(crate) generate_redirect_map: bool,
     /// Show the memory layout of types in the docs.
     pub(crate) show_type_layout: bool,
+    /// Show the transcriber of each macro arm instead of collapsing it to `{ ... }`.
+    pub(crate) document_macro_bodies: bool,
+    /// How to write the numbers in the values of constants.
+    pub(crate) number_format: NumberFormat,
+    /// Show what the trait aliases used in bounds expand to.
+    pub(crate) expand_trait_aliases: bool,
+    /// Write out statistics about the items of the crate.
+    pub(crate) crate_stats: bool,
+    /// Write out the graph of the items referenced and re-exported by the documented items, in
+    /// the given format.
+    pub(crate) dependency_graph: Option<GraphFormat>,
+    /// Write the lines this crate adds to the search index and the other shared files to
+    /// `index-parts/CRATE.json`, so they can be merged with `--merge-index-parts`.
+    pub(crate) write_index_part: bool,
+    /// The output directories of separate builds whose documentation is copied to the output
+    /// directory, merging the files they share, instead of documenting a crate.
+    pub(crate) merge_index_parts: Vec<PathBuf>,
+    /// The directory in which the traits of dependencies cleaned by a run are kept for the next
+    /// ones.
+    pub(crate) extern_trait_cache: Option<PathBuf>,
+    /// Derive the cfg shown for items from their `#[cfg]` attributes, and add their
+    /// `#[doc(cfg)]` attributes to it.
+    pub(crate) auto_cfg: bool,
+    /// Generate local pages for the traits of dependencies which have implementations in the
+    /// documentation, instead of linking to the documentation of those dependencies.
+    pub(crate) inline_dependency_traits: bool,
+    /// How many predicates can be selected to find the auto trait impls of an item, if limited.
+    pub(crate) auto_trait_fuel: Option<usize>,
+    /// Names of crates to treat as if they were marked `#[doc(masked)]`.
+    pub(crate) mask_crates: Vec<String>,
+    /// An external program the JSON output is passed to instead of being written to a file.
+    pub(crate) backend: Option<String>,
     pub(crate) unstable_features: rustc_feature::UnstableFeatures,
     pub(crate) emit: Vec<EmitType>,
     /// If `true`, HTML source pages will generate links for items to their definition.
@@ -301,6 +338,78 @@ fn from_str(s: &str) -> Result<Self, Self::Err> {
     }
 }
 
+/// The order of the items listed on module pages.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub(crate) enum ItemOrder {
+    /// Sorted by name within each kind of item, with the unstable items last.
+    #[default]
+    Alphabetical,
+    /// In the order they are declared in the source.
+    Source,
+    /// In the order they are collected in, without sorting them. This is what
+    /// `--sort-modules-by-appearance` has always done.
+    Unsorted,
+}
+
+impl FromStr for ItemOrder {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "alphabetical" => Ok(ItemOrder::Alphabetical),
+            "source" => Ok(ItemOrder::Source),
+            _ => Err(()),
+        }
+    }
+}
+
+/// The format of the graph written out with `--dependency-graph`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub(crate) enum GraphFormat {
+    /// A Graphviz graph.
+    Dot,
+    Json,
+}
+
+impl GraphFormat {
+    pub(crate) fn extension(self) -> &'static str {
+        match self {
+            GraphFormat::Dot => "dot",
+            GraphFormat::Json => "json",
+        }
+    }
+}
+
+impl FromStr for GraphFormat {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "dot" => Ok(GraphFormat::Dot),
+            "json" => Ok(GraphFormat::Json),
+            _ => Err(()),
+        }
+    }
+}
+
+/// The default for `--auto-trait-fuel`, high enough to never be reached by reasonable types.
+const DEFAULT_AUTO_TRAIT_FUEL: usize = 100_000;
+
+/// How numbers are written in the values of constants, set with `--number-format`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub(crate) struct NumberFormat {
+    /// Group the digits with underscores, like `1_000_000`.
+    pub(crate) separators: bool,
+    /// Write floats in exponent notation, like `1.5e3`.
+    pub(crate) exponent: bool,
+}
+
+impl Default for NumberFormat {
+    fn default() -> Self {
+        NumberFormat { separators: true, exponent: false }
+    }
+}
+
 impl RenderOptions {
     pub(crate) fn should_emit_crate(&self) -> bool {
         self.emit.is_empty() || self.emit.contains(&EmitType::InvocationSpecific)
@@ -352,6 +461,11 @@ pub(crate) fn from_matches(matches: &getopts::Matches) -> Result<Options, i32> {
                     print!("{:>20}", p.pass.name);
                     println_condition(p.condition);
                 }
+                println!("\nPasses run with `--report-hidden`:");
+                for p in passes::REPORT_HIDDEN_PASSES {
+                    print!("{:>20}", p.pass.name);
+                    println_condition(p.condition);
+                }
             }
 
             fn println_condition(condition: Condition) {
@@ -380,6 +494,34 @@ fn println_condition(condition: Condition) {
             }
         }
 
+        let auto_trait_fuel = match matches.opt_str("auto-trait-fuel") {
+            None => Some(DEFAULT_AUTO_TRAIT_FUEL),
+            Some(fuel) => match fuel.parse() {
+                Ok(0) => None,
+                Ok(fuel) => Some(fuel),
+                Err(_) => {
+                    diag.err(&format!("invalid value for `--auto-trait-fuel`: {}", fuel));
+                    return Err(1);
+                }
+            },
+        };
+
+        let mut number_format = NumberFormat::default();
+        for list in matches.opt_strs("number-format") {
+            for option in list.split(',') {
+                match option {
+                    "separators" => number_format.separators = true,
+                    "no-separators" => number_format.separators = false,
+                    "decimal" => number_format.exponent = false,
+                    "exponent" => number_format.exponent = true,
+                    _ => {
+                        diag.err(&format!("unrecognized number format: {}", option));
+                        return Err(1);
+                    }
+                }
+            }
+        }
+
         // check for `--output-format=json`
         if !matches!(matches.opt_str("output-format").as_deref(), None | Some("html"))
             && !matches.opt_present("show-coverage")
@@ -416,15 +558,21 @@ fn println_condition(condition: Condition) {
             return Err(0);
         }
 
-        if matches.free.is_empty() {
+        // `--merge-index-parts` documents no crate, so it takes no input file.
+        let merge_index_parts: Vec<_> =
+            matches.opt_strs("merge-index-parts").iter().map(PathBuf::from).collect();
+        if matches.free.is_empty() && merge_index_parts.is_empty() {
             diag.struct_err("missing file operand").emit();
             return Err(1);
         }
-        if matches.free.len() > 1 {
-            diag.struct_err("too many file operands").emit();
+        let input = matches.free.first().map(PathBuf::from).unwrap_or_default();
+        let other_inputs: Vec<_> = matches.free.iter().skip(1).map(PathBuf::from).collect();
+        if !other_inputs.is_empty() && !nightly_options::is_unstable_enabled(matches) {
+            diag.struct_err("too many file operands")
+                .note("the -Z unstable-options flag must be passed to document several crate roots")
+                .emit();
             return Err(1);
         }
-        let input = PathBuf::from(&matches.free[0]);
 
         let libs = matches
             .opt_strs("L")
@@ -598,6 +746,7 @@ fn println_condition(condition: Condition) {
         let target = parse_target_triple(matches, error_format);
 
         let show_coverage = matches.opt_present("show-coverage");
+        let report_hidden = matches.opt_present("report-hidden");
 
         let crate_types = match parse_crate_types_from_list(matches.opt_strs("crate-type")) {
             Ok(types) => types,
@@ -630,7 +779,19 @@ fn println_condition(condition: Condition) {
         let proc_macro_crate = crate_types.contains(&CrateType::ProcMacro);
         let playground_url = matches.opt_str("playground-url");
         let maybe_sysroot = matches.opt_str("sysroot").map(PathBuf::from);
-        let sort_modules_alphabetically = !matches.opt_present("sort-modules-by-appearance");
+        let item_order = match matches.opt_str("item-order") {
+            Some(order) => match order.parse() {
+                Ok(order) => Some(order),
+                Err(()) => {
+                    diag.struct_err(&format!("unknown item order `{}`", order))
+                        .help("valid item orders are `alphabetical` and `source`")
+                        .emit();
+                    return Err(1);
+                }
+            },
+            None if matches.opt_present("sort-modules-by-appearance") => Some(ItemOrder::Unsorted),
+            None => None,
+        };
         let resource_suffix = matches.opt_str("resource-suffix").unwrap_or_default();
         let enable_minification = !matches.opt_present("disable-minification");
         let markdown_no_toc = matches.opt_present("markdown-no-toc");
@@ -654,6 +815,27 @@ fn println_condition(condition: Condition) {
         let run_check = matches.opt_present("check");
         let generate_redirect_map = matches.opt_present("generate-redirect-map");
         let show_type_layout = matches.opt_present("show-type-layout");
+        let document_macro_bodies = matches.opt_present("document-macro-bodies");
+        let expand_trait_aliases = matches.opt_present("expand-trait-aliases");
+        let crate_stats = matches.opt_present("crate-stats");
+        let dependency_graph = match matches.opt_str("dependency-graph") {
+            Some(format) => match format.parse() {
+                Ok(format) => Some(format),
+                Err(()) => {
+                    diag.struct_err(&format!("unknown dependency graph format `{}`", format))
+                        .help("valid formats are `dot` and `json`")
+                        .emit();
+                    return Err(1);
+                }
+            },
+            None => None,
+        };
+        let write_index_part = matches.opt_present("write-index-part");
+        let extern_trait_cache = matches.opt_str("extern-trait-cache").map(PathBuf::from);
+        let auto_cfg = matches.opt_present("auto-cfg");
+        let inline_dependency_traits = matches.opt_present("inline-dependency-traits");
+        let mask_crates = matches.opt_strs("mask-crate");
+        let backend = matches.opt_str("backend");
         let nocapture = matches.opt_present("nocapture");
         let generate_link_to_definition = matches.opt_present("generate-link-to-definition");
         let extern_html_root_takes_precedence =
@@ -667,7 +849,29 @@ fn println_condition(condition: Condition) {
             return Err(1);
         }
 
+        if backend.is_some() && (show_coverage || output_format != OutputFormat::Json) {
+            diag.struct_err("--backend option can only be used with JSON output format").emit();
+            return Err(1);
+        }
+
         let scrape_examples_options = ScrapeExamplesOptions::new(matches, &diag)?;
+
+        if !other_inputs.is_empty()
+            && (should_test
+                || show_coverage
+                || report_hidden
+                || crate_name.is_some()
+                || scrape_examples_options.is_some()
+                || iter::once(&input).chain(&other_inputs).any(|input| is_markdown(input)))
+        {
+            diag.struct_err("too many file operands")
+                .note(
+                    "several crate roots can only be documented, without `--crate-name`, and not \
+                     tested, scraped or checked for coverage",
+                )
+                .emit();
+            return Err(1);
+        }
         let with_examples = matches.opt_strs("with-examples");
         let call_locations = crate::scrape_examples::load_call_locations(with_examples, &diag)?;
 
@@ -675,6 +879,7 @@ fn println_condition(condition: Condition) {
 
         Ok(Options {
             input,
+            other_inputs,
             proc_macro_crate,
             error_format,
             libs,
@@ -696,6 +901,7 @@ fn println_condition(condition: Condition) {
             should_test,
             test_args,
             show_coverage,
+            report_hidden,
             crate_version,
             test_run_directory,
             persist_doctests,
@@ -711,7 +917,7 @@ fn println_condition(condition: Condition) {
                 external_html,
                 id_map,
                 playground_url,
-                sort_modules_alphabetically,
+                item_order,
                 themes,
                 extension_css,
                 extern_html_root_urls,
@@ -729,6 +935,19 @@ fn println_condition(condition: Condition) {
                 document_hidden,
                 generate_redirect_map,
                 show_type_layout,
+                document_macro_bodies,
+                number_format,
+                expand_trait_aliases,
+                crate_stats,
+                dependency_graph,
+                write_index_part,
+                merge_index_parts,
+                extern_trait_cache,
+                auto_cfg,
+                inline_dependency_traits,
+                auto_trait_fuel,
+                mask_crates,
+                backend,
                 unstable_features: rustc_feature::UnstableFeatures::from_environment(
                     crate_name.as_deref(),
                 ),
@@ -746,10 +965,14 @@ fn println_condition(condition: Condition) {
 
     /// Returns `true` if the file given as `self.input` is a Markdown file.
     pub(crate) fn markdown_input(&self) -> bool {
-        self.input.extension().map_or(false, |e| e == "md" || e == "markdown")
+        is_markdown(&self.input)
     }
 }
 
+fn is_markdown(input: &Path) -> bool {
+    input.extension().map_or(false, |e| e == "md" || e == "markdown")
+}
+
 /// Prints deprecation warnings for deprecated options
 fn check_deprecated_options(matches: &getopts::Matches, diag: &rustc_errors::Handler) {
     let deprecated_flags = [];
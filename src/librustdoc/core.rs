@@ -4,11 +4,12 @@
 use rustc_errors::emitter::{Emitter, EmitterWriter};
 use rustc_errors::json::JsonEmitter;
 use rustc_feature::UnstableFeatures;
-use rustc_hir::def::{Namespace, Res};
-use rustc_hir::def_id::{DefId, DefIdMap, LocalDefId};
+use rustc_hir::def::{DefKind, Namespace, Res};
+use rustc_hir::def_id::{CrateNum, DefId, DefIdMap, LocalDefId, LOCAL_CRATE};
 use rustc_hir::intravisit::{self, Visitor};
 use rustc_hir::{HirId, Path, TraitCandidate};
 use rustc_interface::interface;
+use rustc_metadata::creader::CStore;
 use rustc_middle::hir::nested_filter;
 use rustc_middle::middle::privacy::AccessLevels;
 use rustc_middle::ty::{ParamEnv, Ty, TyCtxt};
@@ -28,9 +29,12 @@
 use crate::clean::inline::build_external_trait;
 use crate::clean::{self, ItemId, TraitWithExtraInfo};
 use crate::config::{Options as RustdocOptions, OutputFormat, RenderOptions};
+use crate::dependency_graph::DependencyGraph;
 use crate::formats::cache::Cache;
 use crate::passes::collect_intra_doc_links::PreprocessedMarkdownLink;
 use crate::passes::{self, Condition::*};
+use crate::trait_cache::{RecordedExtern, TraitCache};
+use crate::visit_lib::visit_libs;
 
 pub(crate) use rustc_session::config::{DebuggingOptions, Input, Options};
 
@@ -63,6 +67,11 @@ pub(crate) struct DocContext<'tcx> {
     /// Used while populating `external_traits` to ensure we don't process the same trait twice at
     /// the same time.
     pub(crate) active_extern_traits: FxHashSet<DefId>,
+    /// The traits cleaned by previous runs, with `--extern-trait-cache`.
+    pub(crate) trait_cache: Option<TraitCache>,
+    /// What is recorded while cleaning each of the traits being added to `trait_cache`, innermost
+    /// last.
+    pub(crate) recorded_externs: Vec<Vec<RecordedExtern>>,
     // The current set of parameter substitutions,
     // for expanding type aliases at the HIR level:
     /// Table `DefId` of type, lifetime, or const parameter -> substituted type, lifetime, or const
@@ -79,8 +88,15 @@ pub(crate) struct DocContext<'tcx> {
     pub(crate) cache: Cache,
     /// Used by [`clean::inline`] to tell if an item has already been inlined.
     pub(crate) inlined: FxHashSet<ItemId>,
+    /// Extern crates whose doc-reachability is already in `cache.access_levels`, see
+    /// [`DocContext::visit_extern_crates`].
+    pub(crate) visited_crates: FxHashSet<CrateNum>,
+    /// The crates depending on each extern crate, which can re-export its items.
+    pub(crate) crate_dependents: FxHashMap<CrateNum, Vec<CrateNum>>,
     /// Used by `calculate_doc_coverage`.
     pub(crate) output_format: OutputFormat,
+    /// The graph written out with `--dependency-graph`, filled in while cleaning.
+    pub(crate) dependency_graph: Option<DependencyGraph>,
 }
 
 impl<'tcx> DocContext<'tcx> {
@@ -88,17 +104,34 @@ pub(crate) fn sess(&self) -> &'tcx Session {
         self.tcx.sess
     }
 
+    /// Every local item is cleaned within this, so it's also where the items used by an item are
+    /// attributed to it in the dependency graph. Imports are recorded as re-exports instead.
     pub(crate) fn with_param_env<T, F: FnOnce(&mut Self) -> T>(
         &mut self,
         def_id: DefId,
         f: F,
     ) -> T {
         let old_param_env = mem::replace(&mut self.param_env, self.tcx.param_env(def_id));
+        let is_import = self.tcx.def_kind(def_id) == DefKind::Use;
+        let old_item = self.dependency_graph.as_mut().map(|graph| {
+            mem::replace(&mut graph.current_item, if is_import { None } else { Some(def_id) })
+        });
         let ret = f(self);
         self.param_env = old_param_env;
+        if let Some(graph) = &mut self.dependency_graph {
+            graph.current_item = old_item.flatten();
+        }
         ret
     }
 
+    /// Records a call to one of the `record_extern_*` functions for the trait being added to
+    /// `trait_cache`, if any.
+    pub(crate) fn record_extern(&mut self, recorded: RecordedExtern) {
+        if let Some(recorded_externs) = self.recorded_externs.last_mut() {
+            recorded_externs.push(recorded);
+        }
+    }
+
     pub(crate) fn enter_resolver<F, R>(&self, f: F) -> R
     where
         F: FnOnce(&mut resolve::Resolver<'_>) -> R,
@@ -134,6 +167,27 @@ pub(crate) fn as_local_hir_id(tcx: TyCtxt<'_>, item_id: ItemId) -> Option<HirId>
         }
     }
 
+    /// Analyzes the doc-reachability of the items of the extern `crates`, unless that was already
+    /// done.
+    ///
+    /// Most dependencies never show up in the documentation, so extern crates are only visited
+    /// once one of their items is referenced. This has to happen before checking whether an
+    /// extern item is public in `cache.access_levels`. Since an item can also be reachable through
+    /// a re-export in a crate depending on its own, those crates are visited along with it.
+    pub(crate) fn visit_extern_crates(&mut self, crates: impl IntoIterator<Item = CrateNum>) {
+        let mut stack: Vec<CrateNum> = crates.into_iter().collect();
+        let mut new_crates = Vec::new();
+        while let Some(krate) = stack.pop() {
+            if krate != LOCAL_CRATE && self.visited_crates.insert(krate) {
+                new_crates.push(krate);
+                if let Some(dependents) = self.crate_dependents.get(&krate) {
+                    stack.extend(dependents.iter().copied());
+                }
+            }
+        }
+        visit_libs(self.tcx, &mut self.cache.access_levels, &new_crates);
+    }
+
     pub(crate) fn with_all_traits(&mut self, f: impl FnOnce(&mut Self, &[DefId])) {
         let all_traits = self.resolver_caches.all_traits.take();
         f(self, all_traits.as_ref().expect("`all_traits` are already borrowed"));
@@ -324,6 +378,7 @@ pub(crate) fn run_global_ctxt(
     resolver: Rc<RefCell<interface::BoxedResolver>>,
     resolver_caches: ResolverCaches,
     show_coverage: bool,
+    report_hidden: bool,
     render_options: RenderOptions,
     output_format: OutputFormat,
 ) -> (clean::Crate, RenderOptions, Cache) {
@@ -362,6 +417,13 @@ pub(crate) fn run_global_ctxt(
     let access_levels = AccessLevels {
         map: tcx.privacy_access_levels(()).map.iter().map(|(k, v)| (k.to_def_id(), *v)).collect(),
     };
+    let cstore = CStore::from_tcx(tcx);
+    let mut crate_dependents: FxHashMap<CrateNum, Vec<CrateNum>> = FxHashMap::default();
+    for &cnum in tcx.crates(()) {
+        for dep in cstore.crate_dependencies_untracked(cnum) {
+            crate_dependents.entry(dep).or_default().push(cnum);
+        }
+    }
 
     let mut ctxt = DocContext {
         tcx,
@@ -370,13 +432,18 @@ pub(crate) fn run_global_ctxt(
         param_env: ParamEnv::empty(),
         external_traits: Default::default(),
         active_extern_traits: Default::default(),
+        trait_cache: render_options.extern_trait_cache.clone().map(TraitCache::new),
+        recorded_externs: Vec::new(),
         substs: Default::default(),
         impl_trait_bounds: Default::default(),
         generated_synthetics: Default::default(),
         auto_traits,
         cache: Cache::new(access_levels, render_options.document_private),
         inlined: FxHashSet::default(),
+        visited_crates: FxHashSet::default(),
+        crate_dependents,
         output_format,
+        dependency_graph: render_options.dependency_graph.map(|_| DependencyGraph::default()),
         render_options,
     };
 
@@ -399,7 +466,7 @@ pub(crate) fn run_global_ctxt(
         let help = format!(
             "The following guide may be of use:\n\
             {}/rustdoc/how-to-write-documentation.html",
-            crate::DOC_RUST_LANG_ORG_CHANNEL
+            crate::doc_rust_lang_org_channel()
         );
         tcx.struct_lint_node(
             crate::lint::MISSING_CRATE_LEVEL_DOCS,
@@ -460,7 +527,7 @@ fn report_deprecated_attr(name: &str, diag: &rustc_errors::Handler, sp: Span) {
 
     info!("Executing passes");
 
-    for p in passes::defaults(show_coverage) {
+    for p in passes::defaults(show_coverage, report_hidden) {
         let run = match p.condition {
             Always => true,
             WhenDocumentPrivate => ctxt.render_options.document_private,
@@ -479,6 +546,19 @@ fn report_deprecated_attr(name: &str, diag: &rustc_errors::Handler, sp: Span) {
         rustc_errors::FatalError.raise();
     }
 
+    if let Some(ref graph) = ctxt.dependency_graph {
+        let format = ctxt.render_options.dependency_graph.unwrap();
+        if let Err(err) = graph.write(tcx, &krate, format, &ctxt.render_options.output) {
+            tcx.sess.err(&err);
+        }
+    }
+
+    if let Some(ref trait_cache) = ctxt.trait_cache {
+        if let Err(err) = trait_cache.write(tcx, &ctxt.cache) {
+            tcx.sess.warn(&err);
+        }
+    }
+
     krate = tcx.sess.time("create_format_cache", || Cache::populate(&mut ctxt, krate));
 
     (krate, ctxt.render_options, ctxt.cache)
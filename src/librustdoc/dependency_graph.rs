@@ -0,0 +1,118 @@
+//! The graph of the items used and re-exported by the documented items, written out with
+//! `--dependency-graph`.
+//!
+//! The items an item uses are recorded by [`register_res`] while the item is cleaned, as it sees
+//! every path in the item's signature.
+//!
+//! [`register_res`]: crate::clean::utils::register_res
+
+use std::fmt::Write;
+use std::fs;
+use std::path::Path;
+
+use rustc_data_structures::fx::{FxHashSet, FxIndexSet};
+use rustc_hir::def_id::DefId;
+use rustc_middle::ty::print::{with_crate_prefix, with_no_trimmed_paths};
+use rustc_middle::ty::TyCtxt;
+use serde::Serialize;
+
+use crate::clean;
+use crate::config::GraphFormat;
+use crate::visit::DocVisitor;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize)]
+#[serde(rename_all = "snake_case")]
+enum EdgeKind {
+    /// The item mentions the other item in its signature.
+    Uses,
+    /// The module re-exports the other item.
+    Reexports,
+}
+
+#[derive(Default)]
+pub(crate) struct DependencyGraph {
+    /// The local item being cleaned, for which the used items are recorded.
+    pub(crate) current_item: Option<DefId>,
+    /// The edges, as `(from, to, kind)`, in the order they were found.
+    edges: FxIndexSet<(DefId, DefId, EdgeKind)>,
+}
+
+#[derive(Serialize)]
+struct Edge {
+    from: String,
+    to: String,
+    kind: EdgeKind,
+}
+
+#[derive(Serialize)]
+struct Graph {
+    edges: Vec<Edge>,
+}
+
+impl DependencyGraph {
+    pub(crate) fn record_use(&mut self, did: DefId) {
+        if let Some(item) = self.current_item && item != did {
+            self.edges.insert((item, did, EdgeKind::Uses));
+        }
+    }
+
+    pub(crate) fn record_reexport(&mut self, module: DefId, did: DefId) {
+        self.edges.insert((module, did, EdgeKind::Reexports));
+    }
+
+    /// Writes the edges of the items which are documented, now that the passes have stripped the
+    /// other ones, to `CRATE.dependencies.dot` or `CRATE.dependencies.json` in `dir`.
+    pub(crate) fn write(
+        &self,
+        tcx: TyCtxt<'_>,
+        krate: &clean::Crate,
+        format: GraphFormat,
+        dir: &Path,
+    ) -> Result<(), String> {
+        let mut collector = DocumentedItems(FxHashSet::default());
+        collector.visit_crate(krate);
+        let documented = collector.0;
+
+        let path_str = |did| with_no_trimmed_paths!(with_crate_prefix!(tcx.def_path_str(did)));
+        let edges: Vec<_> = self
+            .edges
+            .iter()
+            .filter(|(from, ..)| documented.contains(from))
+            .map(|&(from, to, kind)| Edge { from: path_str(from), to: path_str(to), kind })
+            .collect();
+
+        let crate_name = krate.name(tcx);
+        let contents = match format {
+            GraphFormat::Dot => {
+                let mut dot = format!("digraph {:?} {{\n", crate_name.as_str());
+                for edge in &edges {
+                    let attrs = match edge.kind {
+                        EdgeKind::Uses => "",
+                        EdgeKind::Reexports => " [style=dashed]",
+                    };
+                    writeln!(dot, "    {:?} -> {:?}{};", edge.from, edge.to, attrs).unwrap();
+                }
+                dot.push_str("}\n");
+                dot
+            }
+            GraphFormat::Json => serde_json::to_string(&Graph { edges }).unwrap(),
+        };
+        let path = dir.join(format!("{}.dependencies.{}", crate_name, format.extension()));
+        fs::create_dir_all(dir)
+            .and_then(|()| fs::write(&path, contents))
+            .map_err(|err| format!("failed to write `{}`: {}", path.display(), err))
+    }
+}
+
+/// Collects the items left in the documentation.
+struct DocumentedItems(FxHashSet<DefId>);
+
+impl DocVisitor for DocumentedItems {
+    fn visit_item(&mut self, item: &clean::Item) {
+        if item.is_stripped() {
+            return;
+        }
+        self.0.extend(item.item_id.as_def_id());
+        self.visit_item_recur(item);
+    }
+}
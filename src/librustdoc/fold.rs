@@ -54,7 +54,7 @@ fn fold_inner_recur(&mut self, kind: ItemKind) -> ItemKind {
             | TypedefItem(_)
             | OpaqueTyItem(_)
             | StaticItem(_)
-            | ConstantItem(_)
+            | ConstantItem(..)
             | TraitAliasItem(_)
             | TyMethodItem(_)
             | MethodItem(_, _)
@@ -7,6 +7,7 @@
 use rustc_span::{sym, Symbol};
 
 use crate::clean::{self, types::ExternalLocation, ExternalCrate, ItemId, PrimitiveType};
+use crate::config::ItemOrder;
 use crate::core::DocContext;
 use crate::fold::DocFolder;
 use crate::formats::item_type::ItemType;
@@ -64,6 +65,9 @@ pub(crate) struct Cache {
     /// should show up in documentation about trait implementations.
     pub(crate) traits: FxHashMap<DefId, clean::TraitWithExtraInfo>,
 
+    /// The definition of all known trait aliases, used to show what they expand to in bounds.
+    pub(crate) trait_aliases: FxHashMap<DefId, clean::TraitAlias>,
+
     /// When rendering traits, it's often useful to be able to list all
     /// implementors of the trait, and this mapping is exactly, that: a mapping
     /// of trait ids to the list of known implementors of the trait
@@ -75,6 +79,9 @@ pub(crate) struct Cache {
     /// Cache of where documentation for primitives can be found.
     pub(crate) primitive_locations: FxHashMap<clean::PrimitiveType, DefId>,
 
+    /// Cache of where documentation for keywords can be found.
+    pub(crate) keyword_locations: FxHashMap<Symbol, DefId>,
+
     // Note that external items for which `doc(hidden)` applies to are shown as
     // non-reachable while local items aren't. This is because we're reusing
     // the access levels from the privacy check pass.
@@ -87,6 +94,10 @@ pub(crate) struct Cache {
     /// This is stored in `Cache` so it doesn't need to be passed through all rustdoc functions.
     pub(crate) document_private: bool,
 
+    /// The order of the items on module pages, from `--item-order` or
+    /// `#![doc(item_order = "...")]`. Modules are assembled in this order when cleaning them.
+    pub(crate) item_order: ItemOrder,
+
     /// Crates marked with [`#[doc(masked)]`][doc_masked].
     ///
     /// [doc_masked]: https://doc.rust-lang.org/nightly/unstable-book/language-features/doc-masked.html
@@ -121,6 +132,16 @@ pub(crate) struct Cache {
     pub(crate) intra_doc_links: FxHashMap<ItemId, Vec<clean::ItemLink>>,
     /// Cfg that have been hidden via #![doc(cfg_hide(...))]
     pub(crate) hidden_cfg: FxHashSet<clean::cfg::Cfg>,
+    /// Whether `--auto-cfg` was passed, see [`clean::AttributesExt::cfg`].
+    pub(crate) auto_cfg: bool,
+
+    /// For items inlined through `pub use`, the modules the import went through before reaching
+    /// the module defining the item. See [`clean::ImportSource::reexport_chain`].
+    pub(crate) reexport_chains: FxHashMap<DefId, Vec<DefId>>,
+
+    /// The crate features required by the items guarded by `#[cfg(feature = "...")]`, directly
+    /// or through one of their parents. See [`clean::cfg::Cfg::required_features`].
+    pub(crate) required_features: FxHashMap<ItemId, Vec<Symbol>>,
 }
 
 /// This struct is used to wrap the `cache` and `tcx` in order to run `DocFolder`.
@@ -136,6 +157,20 @@ pub(crate) fn new(access_levels: AccessLevels<DefId>, document_private: bool) ->
         Cache { access_levels, document_private, ..Cache::default() }
     }
 
+    /// If `item` is an implementation from a masked crate, or involving a trait or type from one,
+    /// returns the first such crate. These implementations are left out of the documentation.
+    pub(crate) fn masking_crate(&self, item: &clean::Item) -> Option<CrateNum> {
+        let clean::ImplItem(ref i) = *item.kind else { return None };
+        [
+            Some(item.item_id.krate()),
+            i.trait_.as_ref().map(|t| t.def_id().krate),
+            i.for_.def_id(self).map(|d| d.krate),
+        ]
+        .into_iter()
+        .flatten()
+        .find(|krate| self.masked_crates.contains(krate))
+    }
+
     /// Populates the `Cache` with more data. The returned `Crate` will be missing some data that was
     /// in `krate` due to the data being moved into the `Cache`.
     pub(crate) fn populate(cx: &mut DocContext<'_>, mut krate: clean::Crate) -> clean::Crate {
@@ -171,6 +206,14 @@ pub(crate) fn populate(cx: &mut DocContext<'_>, mut krate: clean::Crate) -> clea
                 .insert(def_id, (vec![crate_name, prim.as_sym()], ItemType::Primitive));
         }
 
+        cx.cache.keyword_locations = ExternalCrate::keyword_locations(tcx).clone();
+        for (&kw, &def_id) in &cx.cache.keyword_locations {
+            if !def_id.is_local() {
+                let crate_name = tcx.crate_name(def_id.krate);
+                cx.cache.external_paths.insert(def_id, (vec![crate_name, kw], ItemType::Keyword));
+            }
+        }
+
         let (krate, mut impl_ids) = {
             let mut cache_builder =
                 CacheBuilder { tcx, cache: &mut cx.cache, impl_ids: FxHashMap::default() };
@@ -209,17 +252,8 @@ fn fold_item(&mut self, item: clean::Item) -> Option<clean::Item> {
 
         // If the impl is from a masked crate or references something from a
         // masked crate then remove it completely.
-        if let clean::ImplItem(ref i) = *item.kind {
-            if self.cache.masked_crates.contains(&item.item_id.krate())
-                || i.trait_
-                    .as_ref()
-                    .map_or(false, |t| self.cache.masked_crates.contains(&t.def_id().krate))
-                || i.for_
-                    .def_id(self.cache)
-                    .map_or(false, |d| self.cache.masked_crates.contains(&d.krate))
-            {
-                return None;
-            }
+        if self.cache.masking_crate(&item).is_some() {
+            return None;
         }
 
         // Propagate a trait method's documentation to all implementors of the
@@ -233,6 +267,13 @@ fn fold_item(&mut self, item: clean::Item) -> Option<clean::Item> {
             });
         }
 
+        if let clean::TraitAliasItem(ref t) = *item.kind {
+            self.cache
+                .trait_aliases
+                .entry(item.item_id.expect_def_id())
+                .or_insert_with(|| t.clone());
+        }
+
         // Collect all the implementors of traits.
         if let clean::ImplItem(ref i) = *item.kind {
             if let Some(trait_) = &i.trait_ {
@@ -335,6 +376,7 @@ fn fold_item(&mut self, item: clean::Item) -> Option<clean::Item> {
                                 self.cache,
                             ),
                             aliases: item.attrs.get_doc_aliases(),
+                            via_deref: false,
                         });
                     }
                 }
@@ -395,7 +437,7 @@ fn fold_item(&mut self, item: clean::Item) -> Option<clean::Item> {
                     }
                 }
             }
-            clean::PrimitiveItem(..) => {
+            clean::PrimitiveItem(..) | clean::KeywordItem(..) => {
                 self.cache
                     .paths
                     .insert(item.item_id.expect_def_id(), (self.cache.stack.clone(), item.type_()));
@@ -412,8 +454,7 @@ fn fold_item(&mut self, item: clean::Item) -> Option<clean::Item> {
             | clean::AssocConstItem(..)
             | clean::TyAssocTypeItem(..)
             | clean::AssocTypeItem(..)
-            | clean::StrippedItem(..)
-            | clean::KeywordItem(..) => {
+            | clean::StrippedItem(..) => {
                 // FIXME: Do these need handling?
                 // The person writing this comment doesn't know.
                 // So would rather leave them to an expert,
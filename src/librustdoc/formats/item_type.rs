@@ -5,6 +5,7 @@
 use serde::{Serialize, Serializer};
 
 use rustc_hir::def::DefKind;
+use rustc_macros::{Decodable, Encodable};
 use rustc_span::hygiene::MacroKind;
 
 use crate::clean;
@@ -20,7 +21,7 @@
 /// module headings. If you are adding to this enum and want to ensure that the sidebar also prints
 /// a heading, edit the listing in `html/render.rs`, function `sidebar_module`. This uses an
 /// ordering based on a helper function inside `item_module`, in the same file.
-#[derive(Copy, PartialEq, Eq, Hash, Clone, Debug, PartialOrd, Ord)]
+#[derive(Copy, PartialEq, Eq, Hash, Clone, Debug, PartialOrd, Ord, Encodable, Decodable)]
 pub(crate) enum ItemType {
     Module = 0,
     ExternCrate = 1,
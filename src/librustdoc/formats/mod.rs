@@ -14,6 +14,8 @@
 pub(crate) enum AssocItemRender<'a> {
     All,
     DerefFor { trait_: &'a clean::Path, type_: &'a clean::Type, deref_mut_: bool },
+    /// The inherent impls of the type aliased by a type alias, rendered on the alias' page.
+    AliasedType { type_: &'a clean::Type },
 }
 
 /// For different handling of associated items from the Deref target of a type rather than the type
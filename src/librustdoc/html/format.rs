@@ -16,7 +16,7 @@
 use rustc_data_structures::fx::FxHashSet;
 use rustc_hir as hir;
 use rustc_hir::def::DefKind;
-use rustc_hir::def_id::DefId;
+use rustc_hir::def_id::{CrateNum, DefId};
 use rustc_metadata::creader::{CStore, LoadedMacro};
 use rustc_middle::ty;
 use rustc_middle::ty::DefIdTree;
@@ -166,13 +166,23 @@ fn comma_sep<T: fmt::Display>(
 pub(crate) fn print_generic_bounds<'a, 'tcx: 'a>(
     bounds: &'a [clean::GenericBound],
     cx: &'a Context<'tcx>,
+) -> impl fmt::Display + 'a + Captures<'tcx> {
+    print_generic_bounds_inner(bounds, cx, false)
+}
+
+/// Prints the bounds separated by `+`. With `wrap`, each bound after the first one goes on its
+/// own line, indented once more than the first one.
+fn print_generic_bounds_inner<'a, 'tcx: 'a>(
+    bounds: &'a [clean::GenericBound],
+    cx: &'a Context<'tcx>,
+    wrap: bool,
 ) -> impl fmt::Display + 'a + Captures<'tcx> {
     display_fn(move |f| {
         let mut bounds_dup = FxHashSet::default();
 
         for (i, bound) in bounds.iter().filter(|b| bounds_dup.insert(b.clone())).enumerate() {
             if i > 0 {
-                f.write_str(" + ")?;
+                f.write_str(if wrap { "<br>&nbsp;&nbsp;&nbsp;&nbsp;+ " } else { " + " })?;
             }
             fmt::Display::fmt(&bound.print(cx), f)?;
         }
@@ -180,6 +190,46 @@ pub(crate) fn print_generic_bounds<'a, 'tcx: 'a>(
     })
 }
 
+/// Width, in characters, above which the bounds of a where predicate are put on one line each.
+const MAX_WHERE_PREDICATE_WIDTH: usize = 80;
+
+/// Width, in characters, above which an `impl Trait` argument is collapsed.
+const MAX_IMPL_TRAIT_ARG_WIDTH: usize = 30;
+
+/// Returns the collapsed form of an `impl Trait` argument type, which is its first bound, e.g.
+/// `impl Iterator<…> + …`, if it's long enough to be worth collapsing.
+fn abbreviate_impl_trait(ty: &clean::Type, cx: &Context<'_>) -> Option<String> {
+    let clean::ImplTrait(ref bounds) = *ty else { return None };
+    let full = format!("impl {:#}", print_generic_bounds(bounds, cx));
+    if full.len() <= MAX_IMPL_TRAIT_ARG_WIDTH {
+        return None;
+    }
+    let mut short = String::from("impl ");
+    match bounds.first()? {
+        clean::GenericBound::Outlives(lt) => short.push_str(lt.0.as_str()),
+        clean::GenericBound::TraitBound(poly_trait, modifier) => {
+            if let hir::TraitBoundModifier::Maybe = modifier {
+                short.push('?');
+            }
+            let segment = poly_trait.trait_.segments.last()?;
+            short.push_str(segment.name.as_str());
+            match segment.args {
+                clean::GenericArgs::AngleBracketed { ref args, ref bindings }
+                    if !args.is_empty() || !bindings.is_empty() =>
+                {
+                    short.push_str("<…>")
+                }
+                clean::GenericArgs::AngleBracketed { .. } => {}
+                clean::GenericArgs::Parenthesized { .. } => short.push_str("(…)"),
+            }
+        }
+    }
+    if bounds.len() > 1 {
+        short.push_str(" + …");
+    }
+    if short == full { None } else { Some(short) }
+}
+
 impl clean::GenericParamDef {
     pub(crate) fn print<'a, 'tcx: 'a>(
         &'a self,
@@ -290,6 +340,12 @@ pub(crate) fn print_where_clause<'a, 'tcx: 'a>(
                     clean::WherePredicate::BoundPredicate { ty, bounds, bound_params } => {
                         let ty_cx = ty.print(cx);
                         let generic_bounds = print_generic_bounds(bounds, cx);
+                        // Keep the predicates of heavily-bounded items readable.
+                        let wrap = !f.alternate()
+                            && bounds.len() > 1
+                            && format!("{ty_cx:#}: {generic_bounds:#}").len()
+                                > MAX_WHERE_PREDICATE_WIDTH;
+                        let generic_bounds = print_generic_bounds_inner(bounds, cx, wrap);
 
                         if bound_params.is_empty() {
                             if f.alternate() {
@@ -438,10 +494,19 @@ pub(crate) fn print<'a, 'tcx: 'a>(
                     hir::TraitBoundModifier::MaybeConst => "",
                 };
                 if f.alternate() {
-                    write!(f, "{}{:#}", modifier_str, ty.print(cx))
+                    write!(f, "{}{:#}", modifier_str, ty.print(cx))?;
                 } else {
-                    write!(f, "{}{}", modifier_str, ty.print(cx))
+                    write!(f, "{}{}", modifier_str, ty.print(cx))?;
+                }
+                if cx.shared.expand_trait_aliases
+                    && let Some(alias) = cx.cache().trait_aliases.get(&ty.trait_.def_id())
+                {
+                    // Written as a comment so the bounds are still valid Rust.
+                    f.write_str(" /* = ")?;
+                    fmt::Display::fmt(&print_generic_bounds(&alias.bounds, cx), f)?;
+                    f.write_str(" */")?;
                 }
+                Ok(())
             }
         })
     }
@@ -638,6 +703,7 @@ pub(crate) fn href_with_root_path(
     root_path: Option<&str>,
 ) -> Result<(String, ItemType, Vec<Symbol>), HrefError> {
     let tcx = cx.tcx();
+    let original_did = did;
     let def_kind = tcx.def_kind(did);
     let did = match def_kind {
         DefKind::AssocTy | DefKind::AssocFn | DefKind::AssocConst | DefKind::Variant => {
@@ -656,6 +722,7 @@ fn to_module_fqp(shortty: ItemType, fqp: &[Symbol]) -> &[Symbol] {
         && !cache.access_levels.is_public(did)
         && !cache.document_private
         && !cache.primitive_locations.values().any(|&id| id == did)
+        && !cache.keyword_locations.values().any(|&id| id == did)
     {
         return Err(HrefError::Private);
     }
@@ -684,6 +751,19 @@ fn to_module_fqp(shortty: ItemType, fqp: &[Symbol]) -> &[Symbol] {
                         ExternalLocation::Local => {
                             href_relative_parts(module_fqp, relative_to).collect()
                         }
+                        // The pages of the primitives and keywords documented by the standard
+                        // library are linked to from any crate, use the documentation on
+                        // `doc.rust-lang.org` when no other location was given.
+                        ExternalLocation::Unknown
+                            if matches!(shortty, ItemType::Primitive | ItemType::Keyword)
+                                && is_std_crate(tcx, did.krate) =>
+                        {
+                            is_remote = true;
+                            let mut builder =
+                                UrlPartsBuilder::singleton(crate::doc_rust_lang_org_channel());
+                            builder.extend(module_fqp.iter().copied());
+                            builder
+                        }
                         ExternalLocation::Unknown => return Err(HrefError::DocumentationNotBuilt),
                     },
                 )
@@ -711,7 +791,18 @@ fn to_module_fqp(shortty: ItemType, fqp: &[Symbol]) -> &[Symbol] {
             url_parts.push_fmt(format_args!("{}.{}.html", prefix, last));
         }
     }
-    Ok((url_parts.finish(), shortty, fqp.to_vec()))
+    let mut url = url_parts.finish();
+    if def_kind == DefKind::Variant {
+        // Variants don't have their own page, link to their anchor on the enum page instead.
+        url.push_str("#variant.");
+        url.push_str(tcx.item_name(original_did).as_str());
+    }
+    Ok((url, shortty, fqp.to_vec()))
+}
+
+/// Whether `krate` is one of the crates of the standard library documented on `doc.rust-lang.org`.
+fn is_std_crate(tcx: TyCtxt<'_>, krate: CrateNum) -> bool {
+    matches!(tcx.crate_name(krate), sym::core | sym::alloc | sym::std)
 }
 
 pub(crate) fn href(
@@ -803,23 +894,48 @@ fn primitive_link_fragment(
     name: &str,
     fragment: &str,
     cx: &Context<'_>,
+) -> fmt::Result {
+    let def_id = cx.cache().primitive_locations.get(&prim).copied();
+    crate_root_page_link(f, def_id, "primitive", prim.as_sym(), name, fragment, cx)
+}
+
+/// Renders the keyword `kw` as a link to the page documenting it, if there is one.
+pub(crate) fn keyword_link<'a, 'tcx: 'a>(
+    kw: Symbol,
+    cx: &'a Context<'tcx>,
+) -> impl fmt::Display + 'a + Captures<'tcx> {
+    display_fn(move |f| {
+        let def_id = cx.cache().keyword_locations.get(&kw).copied();
+        crate_root_page_link(f, def_id, "keyword", kw, kw.as_str(), "", cx)
+    })
+}
+
+/// Writes `name` as a link to the `kind.page.html` page, which lives at the root of the
+/// documentation of the crate of `def_id` like the pages of primitives and keywords do.
+fn crate_root_page_link(
+    f: &mut fmt::Formatter<'_>,
+    def_id: Option<DefId>,
+    kind: &str,
+    page: Symbol,
+    name: &str,
+    fragment: &str,
+    cx: &Context<'_>,
 ) -> fmt::Result {
     let m = &cx.cache();
     let mut needs_termination = false;
     if !f.alternate() {
-        match m.primitive_locations.get(&prim) {
-            Some(&def_id) if def_id.is_local() => {
+        match def_id {
+            Some(def_id) if def_id.is_local() => {
                 let len = cx.current.len();
                 let len = if len == 0 { 0 } else { len - 1 };
                 write!(
                     f,
-                    "<a class=\"primitive\" href=\"{}primitive.{}.html{fragment}\">",
+                    "<a class=\"{kind}\" href=\"{}{kind}.{page}.html{fragment}\">",
                     "../".repeat(len),
-                    prim.as_sym()
                 )?;
                 needs_termination = true;
             }
-            Some(&def_id) => {
+            Some(def_id) => {
                 let loc = match m.extern_locations[&def_id.krate] {
                     ExternalLocation::Remote(ref s) => {
                         let cname_sym = ExternalCrate { crate_num: def_id.krate }.name(cx.tcx());
@@ -843,8 +959,8 @@ fn primitive_link_fragment(
                     ExternalLocation::Unknown => None,
                 };
                 if let Some(mut loc) = loc {
-                    loc.push_fmt(format_args!("primitive.{}.html", prim.as_sym()));
-                    write!(f, "<a class=\"primitive\" href=\"{}{fragment}\">", loc.finish())?;
+                    loc.push_fmt(format_args!("{kind}.{page}.html"));
+                    write!(f, "<a class=\"{kind}\" href=\"{}{fragment}\">", loc.finish())?;
                     needs_termination = true;
                 }
             }
@@ -1261,14 +1377,20 @@ pub(crate) fn print<'b, 'a: 'b, 'tcx: 'a>(
     /// * `indent`: The number of spaces to indent each successive line with, if line-wrapping is
     ///   necessary.
     /// * `asyncness`: Whether the function is async or not.
+    /// * `collapse_impl_trait_args`: Whether long `impl Trait` argument types are collapsed to
+    ///   their first bound. Their full types are then printed by
+    ///   `print_collapsed_impl_trait_args`.
     pub(crate) fn full_print<'a, 'tcx: 'a>(
         &'a self,
         header_len: usize,
         indent: usize,
         asyncness: hir::IsAsync,
+        collapse_impl_trait_args: bool,
         cx: &'a Context<'tcx>,
     ) -> impl fmt::Display + 'a + Captures<'tcx> {
-        display_fn(move |f| self.inner_full_print(header_len, indent, asyncness, f, cx))
+        display_fn(move |f| {
+            self.inner_full_print(header_len, indent, asyncness, collapse_impl_trait_args, f, cx)
+        })
     }
 
     fn inner_full_print(
@@ -1276,6 +1398,7 @@ fn inner_full_print(
         header_len: usize,
         indent: usize,
         asyncness: hir::IsAsync,
+        collapse_impl_trait_args: bool,
         f: &mut fmt::Formatter<'_>,
         cx: &Context<'_>,
     ) -> fmt::Result {
@@ -1324,12 +1447,20 @@ fn inner_full_print(
                     write!(args_plain, "{}: ", input.name);
                 }
 
-                if f.alternate() {
-                    write!(args, "{:#}", input.type_.print(cx));
+                let short = (collapse_impl_trait_args && !f.alternate())
+                    .then(|| abbreviate_impl_trait(&input.type_, cx))
+                    .flatten();
+                if let Some(short) = short {
+                    write!(args, "<span class=\"impl-trait-arg\">{}</span>", Escape(&short));
+                    args_plain.push_str(&short);
                 } else {
-                    write!(args, "{}", input.type_.print(cx));
+                    if f.alternate() {
+                        write!(args, "{:#}", input.type_.print(cx));
+                    } else {
+                        write!(args, "{}", input.type_.print(cx));
+                    }
+                    write!(args_plain, "{:#}", input.type_.print(cx));
                 }
-                write!(args_plain, "{:#}", input.type_.print(cx));
             }
             if i + 1 < self.inputs.values.len() {
                 args.push_str(",");
@@ -1375,6 +1506,36 @@ fn inner_full_print(
             write!(f, "{}", output)
         }
     }
+
+    /// Prints the arguments whose `impl Trait` type is collapsed by `full_print`, one per line
+    /// with their full type, or returns `None` if there are none.
+    pub(crate) fn print_collapsed_impl_trait_args<'a, 'tcx: 'a>(
+        &'a self,
+        cx: &'a Context<'tcx>,
+    ) -> Option<impl fmt::Display + 'a + Captures<'tcx>> {
+        let args = self
+            .inputs
+            .values
+            .iter()
+            .filter(|input| input.to_self().is_none())
+            .filter(|input| abbreviate_impl_trait(&input.type_, cx).is_some())
+            .collect::<Vec<_>>();
+        if args.is_empty() {
+            return None;
+        }
+        Some(display_fn(move |f| {
+            for (i, input) in args.into_iter().enumerate() {
+                if i > 0 {
+                    f.write_str("\n")?;
+                }
+                if !input.name.is_empty() {
+                    write!(f, "{}: ", input.name)?;
+                }
+                fmt::Display::fmt(&input.type_.print(cx), f)?;
+            }
+            Ok(())
+        }))
+    }
 }
 
 impl clean::Visibility {
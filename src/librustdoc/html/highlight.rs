@@ -5,12 +5,14 @@
 //!
 //! Use the `render_with_highlighting` to highlight some rust code.
 
+use crate::clean::utils::doc_rust_lang_org_channel;
 use crate::clean::PrimitiveType;
 use crate::html::escape::Escape;
 use crate::html::render::Context;
 
 use std::collections::VecDeque;
 use std::fmt::{Display, Write};
+use std::mem;
 
 use rustc_data_structures::fx::FxHashMap;
 use rustc_lexer::{LiteralKind, TokenKind};
@@ -35,7 +37,78 @@ pub(crate) struct HrefContext<'a, 'b, 'c> {
 /// Each range will be wrapped in a span with that class.
 pub(crate) struct DecorationInfo(pub(crate) FxHashMap<&'static str, Vec<(u32, u32)>>);
 
+/// A fragment specifier of a macro metavariable, such as `expr` in `$e:expr`.
+#[derive(Debug, Eq, PartialEq)]
+struct MacroFragment {
+    name: &'static str,
+    /// The page of the reference describing what the fragment matches.
+    path: &'static str,
+    /// Shown when hovering the fragment specifier.
+    description: &'static str,
+}
+
+const MACRO_FRAGMENTS: &[MacroFragment] = &[
+    MacroFragment {
+        name: "block",
+        path: "expressions/block-expr.html",
+        description: "a block expression",
+    },
+    MacroFragment { name: "expr", path: "expressions.html", description: "an expression" },
+    MacroFragment {
+        name: "ident",
+        path: "identifiers.html",
+        description: "an identifier or a keyword",
+    },
+    MacroFragment { name: "item", path: "items.html", description: "an item" },
+    MacroFragment {
+        name: "lifetime",
+        path: "tokens.html#lifetimes-and-loop-labels",
+        description: "a lifetime",
+    },
+    MacroFragment {
+        name: "literal",
+        path: "expressions/literal-expr.html",
+        description: "a literal expression, optionally preceded by `-`",
+    },
+    MacroFragment {
+        name: "meta",
+        path: "attributes.html#meta-item-attribute-syntax",
+        description: "the contents of an attribute",
+    },
+    MacroFragment { name: "pat", path: "patterns.html", description: "a pattern" },
+    MacroFragment {
+        name: "pat_param",
+        path: "patterns.html",
+        description: "a pattern without top-level or-patterns",
+    },
+    MacroFragment {
+        name: "path",
+        path: "paths.html#paths-in-types",
+        description: "a path in type position",
+    },
+    MacroFragment {
+        name: "stmt",
+        path: "statements.html",
+        description: "a statement without its trailing semicolon",
+    },
+    MacroFragment {
+        name: "tt",
+        path: "macros.html#macro-invocation",
+        description: "a single token tree",
+    },
+    MacroFragment { name: "ty", path: "types.html#type-expressions", description: "a type" },
+    MacroFragment {
+        name: "vis",
+        path: "visibility-and-privacy.html",
+        description: "a possibly empty visibility qualifier",
+    },
+];
+
 /// Highlights `src`, returning the HTML output.
+///
+/// If `macro_arms` is given, `src` is the declaration of a macro: the fragment specifiers of its
+/// metavariables link to the reference, and each arm, given by its byte range in `src`, is wrapped
+/// in a span with an `arm.N` anchor.
 pub(crate) fn render_with_highlighting(
     src: &str,
     out: &mut Buffer,
@@ -46,6 +119,7 @@ pub(crate) fn render_with_highlighting(
     extra_content: Option<Buffer>,
     href_context: Option<HrefContext<'_, '_, '_>>,
     decoration_info: Option<DecorationInfo>,
+    macro_arms: Option<&[(u32, u32)]>,
 ) {
     debug!("highlighting: ================\n{}\n==============", src);
     if let Some((edition_info, class)) = tooltip {
@@ -62,7 +136,7 @@ pub(crate) fn render_with_highlighting(
     }
 
     write_header(out, class, extra_content);
-    write_code(out, src, edition, href_context, decoration_info);
+    write_code(out, src, edition, href_context, decoration_info, macro_arms);
     write_footer(out, playground_button);
 }
 
@@ -96,6 +170,7 @@ fn write_code(
     edition: Edition,
     href_context: Option<HrefContext<'_, '_, '_>>,
     decoration_info: Option<DecorationInfo>,
+    macro_arms: Option<&[(u32, u32)]>,
 ) {
     // This replace allows to fix how the code source with DOS backline characters is displayed.
     let src = src.replace("\r\n", "\n");
@@ -105,6 +180,7 @@ fn write_code(
         edition,
         href_context.as_ref().map(|c| c.file_span).unwrap_or(DUMMY_SP),
         decoration_info,
+        macro_arms,
     )
     .highlight(&mut |highlight| {
         match highlight {
@@ -136,6 +212,9 @@ enum Class {
     Op,
     Macro(Span),
     MacroNonTerminal,
+    MacroFragment(&'static MacroFragment),
+    /// The arm of a macro declaration with the given index.
+    MacroArm(usize),
     String,
     Number,
     Bool,
@@ -160,6 +239,8 @@ fn as_html(self) -> &'static str {
             Class::Op => "op",
             Class::Macro(_) => "macro",
             Class::MacroNonTerminal => "macro-nonterminal",
+            Class::MacroFragment(_) => "macro-fragment",
+            Class::MacroArm(_) => "macro-arm",
             Class::String => "string",
             Class::Number => "number",
             Class::Bool => "bool-val",
@@ -184,6 +265,8 @@ fn get_span(self) -> Option<Span> {
             | Self::RefKeyWord
             | Self::Op
             | Self::MacroNonTerminal
+            | Self::MacroFragment(_)
+            | Self::MacroArm(_)
             | Self::String
             | Self::Number
             | Self::Bool
@@ -304,6 +387,25 @@ fn new(info: DecorationInfo) -> Self {
     }
 }
 
+/// The arms of a macro declaration, which are wrapped in spans as they are reached.
+#[derive(Default)]
+struct MacroArms {
+    /// The byte range of each arm, in order.
+    ranges: Vec<(u32, u32)>,
+    /// The index of the arm the classifier is in or will reach next.
+    next: usize,
+    in_arm: bool,
+}
+
+/// Where the classifier is in a `$name:fragment` metavariable.
+#[derive(Clone, Copy, Default, PartialEq)]
+enum FragmentState {
+    #[default]
+    None,
+    AfterNonTerminal,
+    AfterColon,
+}
+
 /// Processes program tokens, classifying strings of text by highlighting
 /// category (`Class`).
 struct Classifier<'a> {
@@ -311,6 +413,9 @@ struct Classifier<'a> {
     in_attribute: bool,
     in_macro: bool,
     in_macro_nonterminal: bool,
+    fragment_state: FragmentState,
+    link_fragments: bool,
+    macro_arms: MacroArms,
     edition: Edition,
     byte_pos: u32,
     file_span: Span,
@@ -326,6 +431,7 @@ fn new(
         edition: Edition,
         file_span: Span,
         decoration_info: Option<DecorationInfo>,
+        macro_arms: Option<&[(u32, u32)]>,
     ) -> Classifier<'_> {
         let tokens = PeekIter::new(TokenIter { src });
         let decorations = decoration_info.map(Decorations::new);
@@ -334,6 +440,12 @@ fn new(
             in_attribute: false,
             in_macro: false,
             in_macro_nonterminal: false,
+            fragment_state: FragmentState::None,
+            link_fragments: macro_arms.is_some(),
+            macro_arms: MacroArms {
+                ranges: macro_arms.map(|arms| arms.to_vec()).unwrap_or_default(),
+                ..MacroArms::default()
+            },
             edition,
             byte_pos: 0,
             file_span,
@@ -432,6 +544,7 @@ fn highlight(mut self, sink: &mut dyn FnMut(Highlight<'a>)) {
                     sink(Highlight::ExitSpan);
                 }
             }
+            self.update_macro_arms(sink);
 
             if self
                 .tokens
@@ -455,6 +568,29 @@ fn highlight(mut self, sink: &mut dyn FnMut(Highlight<'a>)) {
                 break;
             }
         }
+        // An arm can end with the source.
+        self.update_macro_arms(sink);
+    }
+
+    /// Enters or exits the spans of the macro arms starting or ending at the current position.
+    fn update_macro_arms(&mut self, sink: &mut dyn FnMut(Highlight<'a>)) {
+        let arms = &mut self.macro_arms;
+        while let Some(&(lo, hi)) = arms.ranges.get(arms.next) {
+            if arms.in_arm {
+                if self.byte_pos < hi {
+                    break;
+                }
+                sink(Highlight::ExitSpan);
+                arms.in_arm = false;
+                arms.next += 1;
+            } else {
+                if self.byte_pos < lo {
+                    break;
+                }
+                sink(Highlight::EnterSpan { class: Class::MacroArm(arms.next) });
+                arms.in_arm = true;
+            }
+        }
     }
 
     /// Single step of highlighting. This will classify `token`, but maybe also a couple of
@@ -472,6 +608,20 @@ fn advance(
     ) {
         let lookahead = self.peek();
         let no_highlight = |sink: &mut dyn FnMut(_)| sink(Highlight::Token { text, class: None });
+        // There may be whitespace around the colon of `$name:fragment`.
+        let fragment_state = if token == TokenKind::Whitespace {
+            self.fragment_state
+        } else {
+            mem::take(&mut self.fragment_state)
+        };
+        if self.link_fragments
+            && fragment_state == FragmentState::AfterColon
+            && token == TokenKind::Ident
+            && let Some(fragment) = MACRO_FRAGMENTS.iter().find(|f| f.name == text)
+        {
+            sink(Highlight::Token { text, class: Some(Class::MacroFragment(fragment)) });
+            return;
+        }
         let class = match token {
             TokenKind::Whitespace => return no_highlight(sink),
             TokenKind::LineComment { doc_style } | TokenKind::BlockComment { doc_style, .. } => {
@@ -558,6 +708,11 @@ fn advance(
             | TokenKind::Lt
             | TokenKind::Gt => Class::Op,
 
+            TokenKind::Colon if fragment_state == FragmentState::AfterNonTerminal => {
+                self.fragment_state = FragmentState::AfterColon;
+                return no_highlight(sink);
+            }
+
             // Miscellaneous, no highlighting.
             TokenKind::Dot
             | TokenKind::Semi
@@ -643,6 +798,7 @@ fn advance(
                     "union" if self.check_if_is_union_keyword() => Class::KeyWord,
                     _ if self.in_macro_nonterminal => {
                         self.in_macro_nonterminal = false;
+                        self.fragment_state = FragmentState::AfterNonTerminal;
                         Class::MacroNonTerminal
                     }
                     "self" | "Self" => Class::Self_(self.new_span(before, text)),
@@ -740,6 +896,22 @@ fn string_without_closing_tag<T: Display>(
         write!(out, "{}", text);
         return None;
     };
+    if let Class::MacroFragment(fragment) = klass {
+        write!(
+            out,
+            "<a class=\"{}\" href=\"{}/reference/{}\" title=\"{}\">{}",
+            klass.as_html(),
+            doc_rust_lang_org_channel(),
+            fragment.path,
+            Escape(fragment.description),
+            text,
+        );
+        return Some("</a>");
+    }
+    if let Class::MacroArm(index) = klass {
+        write!(out, "<span id=\"arm.{}\" class=\"{}\">{}", index, klass.as_html(), text);
+        return Some("</span>");
+    }
     let Some(def_span) = klass.get_span()
     else {
         write!(out, "<span class=\"{}\">{}", klass.as_html(), text);
@@ -23,7 +23,7 @@ fn test_html_highlighting() {
         let src = include_str!("fixtures/sample.rs");
         let html = {
             let mut out = Buffer::new();
-            write_code(&mut out, src, Edition::Edition2018, None, None);
+            write_code(&mut out, src, Edition::Edition2018, None, None, None);
             format!("{}<pre><code>{}</code></pre>\n", STYLE, out.into_inner())
         };
         expect_file!["fixtures/sample.html"].assert_eq(&html);
@@ -37,7 +37,7 @@ fn test_dos_backline() {
     println!(\"foo\");\r\n\
 }\r\n";
         let mut html = Buffer::new();
-        write_code(&mut html, src, Edition::Edition2018, None, None);
+        write_code(&mut html, src, Edition::Edition2018, None, None, None);
         expect_file!["fixtures/dos_line.html"].assert_eq(&html.into_inner());
     });
 }
@@ -51,7 +51,7 @@ fn test_keyword_highlight() {
 let y = Self::whatever;";
 
         let mut html = Buffer::new();
-        write_code(&mut html, src, Edition::Edition2018, None, None);
+        write_code(&mut html, src, Edition::Edition2018, None, None, None);
         expect_file!["fixtures/highlight.html"].assert_eq(&html.into_inner());
     });
 }
@@ -61,7 +61,7 @@ fn test_union_highlighting() {
     create_default_session_globals_then(|| {
         let src = include_str!("fixtures/union.rs");
         let mut html = Buffer::new();
-        write_code(&mut html, src, Edition::Edition2018, None, None);
+        write_code(&mut html, src, Edition::Edition2018, None, None, None);
         expect_file!["fixtures/union.html"].assert_eq(&html.into_inner());
     });
 }
@@ -75,7 +75,37 @@ fn test_decorations() {
         decorations.insert("example", vec![(0, 10)]);
 
         let mut html = Buffer::new();
-        write_code(&mut html, src, Edition::Edition2018, None, Some(DecorationInfo(decorations)));
+        let decorations = Some(DecorationInfo(decorations));
+        write_code(&mut html, src, Edition::Edition2018, None, decorations, None);
         expect_file!["fixtures/decorations.html"].assert_eq(&html.into_inner());
     });
 }
+
+#[test]
+fn test_macro_fragments() {
+    create_default_session_globals_then(|| {
+        let src = "($e:expr, $t : ty, $($rest:tt)*) => { let x: $t = $e; }";
+        let mut html = Buffer::new();
+        write_code(&mut html, src, Edition::Edition2018, None, None, Some(&[]));
+        let html = html.into_inner();
+        assert!(html.contains("/reference/expressions.html\" title=\"an expression\">expr</a>"));
+        assert!(html.contains("/reference/types.html#type-expressions\" title=\"a type\">ty</a>"));
+        assert!(html.contains("title=\"a single token tree\">tt</a>"));
+        // Only the fragment specifiers of metavariables are links.
+        assert_eq!(html.matches("<a ").count(), 3);
+    });
+}
+
+#[test]
+fn test_macro_arms() {
+    create_default_session_globals_then(|| {
+        let src = "macro_rules! m {\n    () => { ... };\n    ($e:expr) => { ... };\n}";
+        let arms = [(21, 34), (40, 60)];
+        let mut html = Buffer::new();
+        write_code(&mut html, src, Edition::Edition2018, None, None, Some(&arms));
+        let html = html.into_inner();
+        assert!(html.contains("\n    <span id=\"arm.0\" class=\"macro-arm\">()"));
+        assert!(html.contains("\n    <span id=\"arm.1\" class=\"macro-arm\">("));
+        assert_eq!(html.matches("}</span>;").count(), 2);
+    });
+}
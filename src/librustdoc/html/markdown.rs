@@ -358,6 +358,7 @@ fn dont_escape(c: u8) -> bool {
             None,
             None,
             None,
+            None,
         );
         Some(Event::Html(s.into_inner().into()))
     }
@@ -15,6 +15,7 @@
 
 use super::print_item::{full_path, item_path, print_item};
 use super::search_index::build_index;
+use super::stats::CrateStats;
 use super::write_shared::write_shared;
 use super::{
     collect_spans_and_sources, print_sidebar, scrape_examples_help, AllTypes, LinkFromSrc, NameDoc,
@@ -22,7 +23,7 @@
 };
 
 use crate::clean::{self, types::ExternalLocation, ExternalCrate};
-use crate::config::RenderOptions;
+use crate::config::{ItemOrder, NumberFormat, RenderOptions};
 use crate::docfs::{DocFS, PathError};
 use crate::error::Error;
 use crate::formats::cache::Cache;
@@ -87,6 +88,12 @@ pub(crate) struct SharedContext<'tcx> {
     pub(crate) local_sources: FxHashMap<PathBuf, String>,
     /// Show the memory layout of types in the docs.
     pub(super) show_type_layout: bool,
+    /// How to write the numbers in the values of constants.
+    pub(super) number_format: NumberFormat,
+    /// Show what the trait aliases used in bounds expand to.
+    pub(crate) expand_trait_aliases: bool,
+    /// The statistics to write out at the end, when `--crate-stats` is passed.
+    pub(super) crate_stats: Option<CrateStats>,
     /// The base-URL of the issue tracker for when an item has been tagged with
     /// an issue number.
     pub(super) issue_tracker_base_url: Option<String>,
@@ -394,7 +401,6 @@ fn init(
             external_html,
             id_map,
             playground_url,
-            sort_modules_alphabetically,
             themes: style_files,
             default_settings,
             extension_css,
@@ -403,11 +409,15 @@ fn init(
             unstable_features,
             generate_redirect_map,
             show_type_layout,
+            number_format,
+            expand_trait_aliases,
+            crate_stats,
             generate_link_to_definition,
             call_locations,
             no_emit_shared,
             ..
         } = options;
+        let sort_modules_alphabetically = cache.item_order == ItemOrder::Alphabetical;
 
         let src_root = match krate.src(tcx) {
             FileName::Real(ref p) => match p.local_path_if_available().parent() {
@@ -487,6 +497,9 @@ fn init(
             errors: receiver,
             redirections: if generate_redirect_map { Some(Default::default()) } else { None },
             show_type_layout,
+            number_format,
+            expand_trait_aliases,
+            crate_stats: if crate_stats { Some(CrateStats::new(&krate)) } else { None },
             span_correspondance_map: matches,
             cache,
             call_locations,
@@ -644,6 +657,26 @@ fn after_krate(&mut self) -> Result<(), Error> {
             }
         }
 
+        if let Some(ref stats) = shared.crate_stats {
+            let crate_dir = self.dst.join(crate_name.as_str());
+            shared.ensure_dir(&crate_dir)?;
+            let json = serde_json::to_string(stats).expect("failed to convert JSON data");
+            shared.fs.write(crate_dir.join("stats.json"), json)?;
+
+            page.title = "Crate statistics";
+            page.description = "Statistics about the items in this crate";
+            page.root_path = "../";
+            let sidebar = format!("<h2 class=\"location\">Crate {}</h2>", crate_name);
+            let v = layout::render(
+                &shared.layout,
+                &page,
+                sidebar,
+                |buf: &mut Buffer| stats.print(buf),
+                &shared.style_files,
+            );
+            shared.fs.write(crate_dir.join("stats.html"), v)?;
+        }
+
         // No need for it anymore.
         drop(shared);
 
@@ -31,10 +31,12 @@
 mod context;
 mod print_item;
 mod span_map;
+mod stats;
 mod write_shared;
 
 pub(crate) use self::context::*;
 pub(crate) use self::span_map::{collect_spans_and_sources, LinkFromSrc};
+pub(crate) use self::write_shared::merge_index_parts;
 
 use std::collections::VecDeque;
 use std::default::Default;
@@ -62,7 +64,9 @@
 use serde::ser::SerializeSeq;
 use serde::{Serialize, Serializer};
 
+use crate::clean::utils::find_nearest_parent_module;
 use crate::clean::{self, ItemId, RenderedLink, SelfTy};
+use crate::doc_rust_lang_org_channel;
 use crate::error::Error;
 use crate::formats::cache::Cache;
 use crate::formats::item_type::ItemType;
@@ -79,7 +83,6 @@
 use crate::html::static_files::SCRAPE_EXAMPLES_HELP_MD;
 use crate::scrape_examples::{CallData, CallLocation};
 use crate::try_none;
-use crate::DOC_RUST_LANG_ORG_CHANNEL;
 
 /// A pair of name and its optional document.
 pub(crate) type NameDoc = (String, Option<String>);
@@ -105,6 +108,9 @@ pub(crate) struct IndexItem {
     pub(crate) parent_idx: Option<usize>,
     pub(crate) search_type: Option<IndexItemFunctionType>,
     pub(crate) aliases: Box<[Symbol]>,
+    /// Whether this is a method of a `Deref` target, callable on `parent` through its `Deref`
+    /// implementation.
+    pub(crate) via_deref: bool,
 }
 
 /// A type used for the search index.
@@ -340,7 +346,7 @@ fn scrape_examples_help(shared: &SharedContext<'_>) -> String {
     content.push_str(&format!(
       "## More information\n\n\
       If you want more information about this feature, please read the [corresponding chapter in the Rustdoc book]({}/rustdoc/scraped-examples.html).",
-      DOC_RUST_LANG_ORG_CHANNEL));
+      doc_rust_lang_org_channel()));
 
     let mut ids = IdMap::default();
     format!(
@@ -518,6 +524,34 @@ fn portability(item: &clean::Item, parent: Option<&clean::Item>) -> Option<Strin
     Some(format!("<div class=\"stab portability\">{}</div>", cfg?.render_long_html()))
 }
 
+/// Lists the crate features required by `item` which its parent doesn't require, and which aren't
+/// already mentioned by its portability note.
+fn required_features(
+    item: &clean::Item,
+    cx: &Context<'_>,
+    parent: Option<&clean::Item>,
+) -> Option<String> {
+    let cache = cx.cache();
+    let features = cache.required_features.get(&item.item_id)?;
+    let shown = item.cfg.as_ref().map(|cfg| cfg.required_features()).unwrap_or_default();
+    let parent_features = parent.and_then(|parent| cache.required_features.get(&parent.item_id));
+    let features = features
+        .iter()
+        .filter(|feature| {
+            !shown.contains(feature) && !parent_features.map_or(false, |p| p.contains(feature))
+        })
+        .map(|feature| format!("<code>{}</code>", Escape(feature.as_str())))
+        .collect::<Vec<_>>();
+    if features.is_empty() {
+        return None;
+    }
+    Some(format!(
+        "<div class=\"stab features\">Requires the crate feature{} {}</div>",
+        if features.len() == 1 { "" } else { "s" },
+        features.join(", "),
+    ))
+}
+
 /// Render the stability, deprecation and portability information that is displayed at the top of
 /// the item's documentation.
 fn short_item_info(
@@ -590,13 +624,44 @@ fn short_item_info(
         extra_info.push(format!("<div class=\"stab unstable\">{}</div>", message));
     }
 
+    if parent.is_none()
+        && let Some(did) = item.item_id.as_def_id()
+        && let Some(chain) = cx.cache().reexport_chains.get(&did)
+    {
+        let tcx = cx.tcx();
+        let chain = chain
+            .iter()
+            .map(|&module| format!("<code>{}</code>", module_path(tcx, module)))
+            .collect::<Vec<_>>()
+            .join(", ");
+        let mut message = format!("Re-exported from {}", chain);
+        if let Some(module) = find_nearest_parent_module(tcx, did) {
+            message.push_str(&format!(
+                ", originally defined in <code>{}</code>",
+                module_path(tcx, module)
+            ));
+        }
+        extra_info.push(format!("<div class=\"stab reexport\">{}</div>", message));
+    }
+
     if let Some(portability) = portability(item, parent) {
         extra_info.push(portability);
     }
 
+    if let Some(features) = required_features(item, cx, parent) {
+        extra_info.push(features);
+    }
+
     extra_info
 }
 
+/// Returns the path of a module, starting with the name of its crate.
+fn module_path(tcx: TyCtxt<'_>, did: DefId) -> String {
+    let mut path = vec![tcx.crate_name(did.krate)];
+    path.extend(tcx.def_path(did).data.into_iter().filter_map(|elem| elem.data.get_opt_name()));
+    join_with_double_colon(&path)
+}
+
 // Render the list of items inside one of the sections "Trait Implementations",
 // "Auto Trait Implementations," "Blanket Trait Implementations" (on struct/enum pages).
 fn render_impls(
@@ -692,7 +757,7 @@ fn assoc_const(
     w: &mut Buffer,
     it: &clean::Item,
     ty: &clean::Type,
-    default: Option<&clean::ConstantKind>,
+    repr: Option<&clean::ConstantExprAndValue>,
     link: AssocItemLink<'_>,
     extra: &str,
     cx: &Context<'_>,
@@ -706,13 +771,14 @@ fn assoc_const(
         name = it.name.as_ref().unwrap(),
         ty = ty.print(cx),
     );
-    if let Some(default) = default {
-        // FIXME: `.value()` uses `clean::utils::format_integer_with_underscore_sep` under the
-        //        hood which adds noisy underscores and a type suffix to number literals.
-        //        This hurts readability in this context especially when more complex expressions
-        //        are involved and it doesn't add much of value.
-        //        Find a way to print constants here without all that jazz.
-        write!(w, " = {}", default.value(cx.tcx()).unwrap_or_else(|| default.expr(cx.tcx())));
+    let format = cx.shared.number_format;
+    if let Some(repr) = repr && repr.show_expr(cx.tcx(), format) {
+        // The declaration is followed by a `;` or the end of the heading, so the value can't be
+        // shown in a line comment like on the page of a free constant.
+        write!(w, " = {}", Escape(repr.expr(cx.tcx())));
+        if let Some(value) = repr.distinct_value(cx.tcx(), format) {
+            write!(w, " /* {} */", Escape(value));
+        }
     }
 }
 
@@ -806,7 +872,7 @@ fn assoc_method(
         href = href,
         name = name,
         generics = g.print(cx),
-        decl = d.full_print(header_len, indent, header.asyncness, cx),
+        decl = d.full_print(header_len, indent, header.asyncness, false, cx),
         notable_traits = notable_traits_decl(d, cx),
         where_clause = print_where_clause(g, cx, indent, end_newline),
     )
@@ -901,13 +967,13 @@ fn render_assoc_item(
         clean::MethodItem(m, _) => {
             assoc_method(w, item, &m.generics, &m.decl, link, parent, cx, render_mode)
         }
-        kind @ (clean::TyAssocConstItem(ty) | clean::AssocConstItem(ty, _)) => assoc_const(
+        kind @ (clean::TyAssocConstItem(ty) | clean::AssocConstItem(ty, ..)) => assoc_const(
             w,
             item,
             ty,
             match kind {
                 clean::TyAssocConstItem(_) => None,
-                clean::AssocConstItem(_, default) => Some(default),
+                clean::AssocConstItem(.., repr) => Some(repr),
                 _ => unreachable!(),
             },
             link,
@@ -1046,6 +1112,17 @@ fn render_assoc_items_inner(
                 );
                 (RenderMode::ForDeref { mut_: deref_mut_ }, cx.derive_id(id))
             }
+            AssocItemRender::AliasedType { type_ } => {
+                write!(
+                    tmp_buf,
+                    "<h2 id=\"aliased-type-implementations\" class=\"small-section-header\">\
+                         Implementations on {type_}\
+                         <a href=\"#aliased-type-implementations\" class=\"anchor\"></a>\
+                     </h2>",
+                    type_ = type_.print(cx),
+                );
+                (RenderMode::Normal, "aliased-type-implementations-list".to_owned())
+            }
         };
         let mut impls_buf = Buffer::empty_from(w);
         for i in &non_trait {
@@ -1075,12 +1152,25 @@ fn render_assoc_items_inner(
         }
     }
 
+    // Trait impls of the aliased type are shown on its own page, as they don't depend on the name
+    // the type is referred to with.
+    if let AssocItemRender::AliasedType { .. } = what {
+        return;
+    }
+
     if !traits.is_empty() {
         let deref_impl =
             traits.iter().find(|t| t.trait_did() == cx.tcx().lang_items().deref_trait());
         if let Some(impl_) = deref_impl {
-            let has_deref_mut =
-                traits.iter().any(|t| t.trait_did() == cx.tcx().lang_items().deref_mut_trait());
+            // Methods taking `&mut self` are only reachable if every type along the deref chain
+            // implements `DerefMut`, not just the one we're currently looking at.
+            let outer_deref_mut = match what {
+                AssocItemRender::DerefFor { deref_mut_, .. } => deref_mut_,
+                AssocItemRender::All => true,
+                AssocItemRender::AliasedType { .. } => unreachable!(),
+            };
+            let has_deref_mut = outer_deref_mut
+                && traits.iter().any(|t| t.trait_did() == cx.tcx().lang_items().deref_mut_trait());
             render_deref_methods(w, cx, impl_, containing_item, has_deref_mut, derefs);
         }
 
@@ -1418,7 +1508,7 @@ fn doc_impl_item(
                     w.write_str("</section>");
                 }
             }
-            kind @ (clean::TyAssocConstItem(ty) | clean::AssocConstItem(ty, _)) => {
+            kind @ (clean::TyAssocConstItem(ty) | clean::AssocConstItem(ty, ..)) => {
                 let source_id = format!("{}.{}", item_type, name);
                 let id = cx.derive_id(source_id.clone());
                 write!(
@@ -1435,7 +1525,7 @@ fn doc_impl_item(
                     ty,
                     match kind {
                         clean::TyAssocConstItem(_) => None,
-                        clean::AssocConstItem(_, default) => Some(default),
+                        clean::AssocConstItem(.., repr) => Some(repr),
                         _ => unreachable!(),
                     },
                     link.anchor(if trait_.is_some() { &source_id } else { &id }),
@@ -1734,6 +1824,58 @@ pub(crate) fn render_impl_summary(
         }
     }
 
+    // Blanket impls are shown as they're written in the crate defining them, so tell which type
+    // their parameter stands for here, and where to find them.
+    if let Some(blanket_ty) = i.inner_impl().kind.as_blanket_ty()
+        && let Some(ref trait_) = i.inner_impl().trait_
+    {
+        let source = match href(trait_.def_id(), cx) {
+            Ok((url, _, _)) => format!(
+                ", listed in the <a href=\"{url}#implementors\">\
+                     implementors of <code>{}</code></a>",
+                trait_.last(),
+            ),
+            Err(_) => String::new(),
+        };
+        write!(
+            w,
+            "<span class=\"item-info\"><div class=\"stab blanket-impl\">\
+                 Blanket impl applied with <code>{} = {}</code>{}\
+             </div></span>",
+            blanket_ty.print(cx),
+            i.inner_impl().for_.print(cx),
+            source,
+        );
+    }
+
+    if let clean::ImplKind::Auto { blocking_fields, private_blocking_fields } = &i.inner_impl().kind
+        && (!blocking_fields.is_empty() || *private_blocking_fields > 0)
+    {
+        let mut blockers = Vec::new();
+        if !blocking_fields.is_empty() {
+            let fields = blocking_fields
+                .iter()
+                .map(|(name, ty)| format!("<code>{}: {}</code>", name, ty.print(cx)))
+                .collect::<Vec<_>>()
+                .join(", ");
+            let plural = if blocking_fields.len() > 1 { "s" } else { "" };
+            blockers.push(format!("the field{} {}", plural, fields));
+        }
+        // The private fields are only counted, their names and types aren't documented.
+        match *private_blocking_fields {
+            0 => {}
+            1 => blockers.push("a private field".to_owned()),
+            _ => blockers.push("private fields".to_owned()),
+        }
+        write!(
+            w,
+            "<span class=\"item-info\"><div class=\"stab auto-trait-blocker\">\
+                 Not implemented because of {}\
+             </div></span>",
+            blockers.join(" and "),
+        );
+    }
+
     w.write_str("</section>");
 }
 
@@ -1966,7 +2108,9 @@ fn sidebar_assoc_items(cx: &Context<'_>, out: &mut Buffer, it: &clean::Item) {
             {
                 let mut derefs = FxHashSet::default();
                 derefs.insert(did);
-                sidebar_deref_methods(cx, out, impl_, v, &mut derefs);
+                let deref_mut =
+                    v.iter().any(|i| i.trait_did() == cx.tcx().lang_items().deref_mut_trait());
+                sidebar_deref_methods(cx, out, impl_, deref_mut, &mut derefs);
             }
 
             let format_impls = |impls: Vec<&Impl>, id_map: &mut IdMap| {
@@ -2039,7 +2183,7 @@ fn sidebar_deref_methods(
     cx: &Context<'_>,
     out: &mut Buffer,
     impl_: &Impl,
-    v: &[Impl],
+    deref_mut: bool,
     derefs: &mut FxHashSet<DefId>,
 ) {
     let c = cx.cache();
@@ -2064,7 +2208,6 @@ fn sidebar_deref_methods(
                 }
             }
         }
-        let deref_mut = v.iter().any(|i| i.trait_did() == cx.tcx().lang_items().deref_mut_trait());
         let inner_impl = target
             .def_id(c)
             .or_else(|| {
@@ -2108,7 +2251,12 @@ fn sidebar_deref_methods(
                         .map(|t| Some(t.def_id()) == cx.tcx().lang_items().deref_trait())
                         .unwrap_or(false)
                 }) {
-                    sidebar_deref_methods(cx, out, target_deref_impl, target_impls, derefs);
+                    // `&mut self` methods further down the chain need `DerefMut` at every level.
+                    let deref_mut = deref_mut
+                        && target_impls
+                            .iter()
+                            .any(|i| i.trait_did() == cx.tcx().lang_items().deref_mut_trait());
+                    sidebar_deref_methods(cx, out, target_deref_impl, deref_mut, derefs);
                 }
             }
         }
@@ -23,12 +23,13 @@
     AssocItemLink, Context, ImplRenderingParameters,
 };
 use crate::clean;
+use crate::config::ItemOrder;
 use crate::formats::item_type::ItemType;
 use crate::formats::{AssocItemRender, Impl, RenderMode};
 use crate::html::escape::Escape;
 use crate::html::format::{
-    join_with_double_colon, print_abi_with_space, print_constness_with_space, print_where_clause,
-    Buffer, PrintWithSpace,
+    href, join_with_double_colon, keyword_link, print_abi_with_space, print_constness_with_space,
+    print_where_clause, Buffer, PrintWithSpace,
 };
 use crate::html::highlight;
 use crate::html::layout::Page;
@@ -161,7 +162,7 @@ pub(super) fn print_item(
         clean::ProcMacroItem(ref m) => item_proc_macro(buf, cx, item, m),
         clean::PrimitiveItem(_) => item_primitive(buf, cx, item),
         clean::StaticItem(ref i) | clean::ForeignStaticItem(ref i) => item_static(buf, cx, item, i),
-        clean::ConstantItem(ref c) => item_constant(buf, cx, item, c),
+        clean::ConstantItem(ref c, ref repr) => item_constant(buf, cx, item, c, repr),
         clean::ForeignTypeItem => item_foreign_type(buf, cx, item),
         clean::KeywordItem(_) => item_keyword(buf, cx, item),
         clean::OpaqueTyItem(ref e) => item_opaque_ty(buf, cx, item, e),
@@ -246,8 +247,13 @@ fn cmp(
         compare_names(lhs.as_str(), rhs.as_str())
     }
 
-    if cx.shared.sort_modules_alphabetically {
-        indices.sort_by(|&i1, &i2| cmp(&items[i1], &items[i2], i1, i2, cx.tcx()));
+    match cx.cache().item_order {
+        ItemOrder::Alphabetical => {
+            indices.sort_by(|&i1, &i2| cmp(&items[i1], &items[i2], i1, i2, cx.tcx()))
+        }
+        // The items are in source order, only keep each kind of item in a single section.
+        ItemOrder::Source => indices.sort_by_key(|&i| reorder(items[i].type_())),
+        ItemOrder::Unsorted => {}
     }
     // This call is to remove re-export duplicates in cases such as:
     //
@@ -336,7 +342,7 @@ fn cmp(
                     let import_item = clean::Item {
                         item_id: import_def_id.into(),
                         attrs: import_attrs,
-                        cfg: ast_attrs.cfg(cx.tcx(), &cx.cache().hidden_cfg),
+                        cfg: ast_attrs.cfg(cx.tcx(), cx.cache()),
                         ..myitem.clone()
                     };
 
@@ -495,9 +501,10 @@ fn item_function(w: &mut Buffer, cx: &mut Context<'_>, it: &clean::Item, f: &cle
             w.reserve(header_len);
             write!(
                 w,
-                "{vis}{constness}{asyncness}{unsafety}{abi}fn \
+                "{vis}{constness}{asyncness}{unsafety}{abi}{fn_} \
                  {name}{generics}{decl}{notable_traits}{where_clause}",
                 vis = visibility,
+                fn_ = keyword_link(kw::Fn, cx),
                 constness = constness,
                 asyncness = asyncness,
                 unsafety = unsafety,
@@ -505,10 +512,24 @@ fn item_function(w: &mut Buffer, cx: &mut Context<'_>, it: &clean::Item, f: &cle
                 name = name,
                 generics = f.generics.print(cx),
                 where_clause = print_where_clause(&f.generics, cx, 0, true),
-                decl = f.decl.full_print(header_len, 0, header.asyncness, cx),
+                decl = f.decl.full_print(header_len, 0, header.asyncness, true, cx),
                 notable_traits = notable_traits_decl(&f.decl, cx),
             );
         });
+        // The long `impl Trait` arguments are collapsed in the signature, so their full types are
+        // shown below it.
+        if let Some(args) = f.decl.print_collapsed_impl_trait_args(cx) {
+            write!(
+                w,
+                "<details class=\"rustdoc-toggle impl-trait-args\">\
+                    <summary class=\"hideme\">\
+                        <span>Show full <code>impl Trait</code> arguments</span>\
+                    </summary>\
+                    <pre class=\"rust\"><code>{}</code></pre>\
+                </details>",
+                args
+            );
+        }
     });
     document(w, cx, it, None, HeadingOffset::H2)
 }
@@ -531,10 +552,11 @@ fn item_trait(w: &mut Buffer, cx: &mut Context<'_>, it: &clean::Item, t: &clean:
             render_attributes_in_pre(w, it, "");
             write!(
                 w,
-                "{}{}{}trait {}{}{}",
+                "{}{}{}{} {}{}{}",
                 it.visibility.print_with_space(it.item_id, cx),
                 t.unsafety.print_with_space(),
                 if t.is_auto { "auto " } else { "" },
+                keyword_link(kw::Trait, cx),
                 it.name.unwrap(),
                 t.generics.print(cx),
                 bounds
@@ -1008,7 +1030,8 @@ fn item_trait_alias(w: &mut Buffer, cx: &mut Context<'_>, it: &clean::Item, t: &
             render_attributes_in_pre(w, it, "");
             write!(
                 w,
-                "trait {}{}{} = {};",
+                "{} {}{}{} = {};",
+                keyword_link(kw::Trait, cx),
                 it.name.unwrap(),
                 t.generics.print(cx),
                 print_where_clause(&t.generics, cx, 0, true),
@@ -1032,8 +1055,9 @@ fn item_opaque_ty(w: &mut Buffer, cx: &mut Context<'_>, it: &clean::Item, t: &cl
             render_attributes_in_pre(w, it, "");
             write!(
                 w,
-                "type {}{}{where_clause} = impl {bounds};",
+                "{type_} {}{}{where_clause} = impl {bounds};",
                 it.name.unwrap(),
+                type_ = keyword_link(kw::Type, cx),
                 t.generics.print(cx),
                 where_clause = print_where_clause(&t.generics, cx, 0, true),
                 bounds = bounds(&t.bounds, false, cx),
@@ -1057,8 +1081,9 @@ fn write_content(w: &mut Buffer, cx: &Context<'_>, it: &clean::Item, t: &clean::
             write!(w, "{}", it.visibility.print_with_space(it.item_id, cx));
             write!(
                 w,
-                "type {}{}{where_clause} = {type_};",
+                "{type_kw} {}{}{where_clause} = {type_};",
                 it.name.unwrap(),
+                type_kw = keyword_link(kw::Type, cx),
                 t.generics.print(cx),
                 where_clause = print_where_clause(&t.generics, cx, 0, true),
                 type_ = t.type_.print(cx),
@@ -1070,12 +1095,34 @@ fn write_content(w: &mut Buffer, cx: &Context<'_>, it: &clean::Item, t: &clean::
 
     document(w, cx, it, None, HeadingOffset::H2);
 
+    // If the alias refers to another alias, or to an associated type, show what it ends up being.
+    let aliased_type = t.item_type.as_ref().unwrap_or(&t.type_);
+    if aliased_type != &t.type_ {
+        write!(
+            w,
+            "<h2 id=\"aliased-type\" class=\"small-section-header\">\
+                 Aliased type<a href=\"#aliased-type\" class=\"anchor\"></a></h2>"
+        );
+        wrap_into_docblock(w, |w| {
+            wrap_item(w, "typedef", |w| write!(w, "{}", aliased_type.print(cx)));
+        });
+    }
+
     let def_id = it.item_id.expect_def_id();
     // Render any items associated directly to this alias, as otherwise they
-    // won't be visible anywhere in the docs. It would be nice to also show
-    // associated items from the aliased type (see discussion in #32077), but
-    // we need #14072 to make sense of the generics.
+    // won't be visible anywhere in the docs.
     render_assoc_items(w, cx, it, def_id, AssocItemRender::All);
+    // Also render the inherent impls of the aliased type. Their generics aren't substituted with
+    // the alias' arguments (see #14072), so they're shown as they're written on the type.
+    if let Some(aliased_def_id) = aliased_type.def_id(cx.cache()) && aliased_def_id != def_id {
+        render_assoc_items(
+            w,
+            cx,
+            it,
+            aliased_def_id,
+            AssocItemRender::AliasedType { type_: aliased_type },
+        );
+    }
     document_type_layout(w, cx, def_id);
 }
 
@@ -1148,8 +1195,9 @@ fn item_enum(w: &mut Buffer, cx: &mut Context<'_>, it: &clean::Item, e: &clean::
             render_attributes_in_pre(w, it, "");
             write!(
                 w,
-                "{}enum {}{}{}",
+                "{}{} {}{}{}",
                 it.visibility.print_with_space(it.item_id, cx),
+                keyword_link(kw::Enum, cx),
                 it.name.unwrap(),
                 e.generics.print(cx),
                 print_where_clause(&e.generics, cx, 0, true),
@@ -1301,6 +1349,7 @@ fn item_macro(w: &mut Buffer, cx: &mut Context<'_>, it: &clean::Item, t: &clean:
             None,
             None,
             None,
+            Some(&t.arms),
         );
     });
     document(w, cx, it, None, HeadingOffset::H2)
@@ -1323,11 +1372,26 @@ fn item_proc_macro(w: &mut Buffer, cx: &mut Context<'_>, it: &clean::Item, m: &c
             MacroKind::Derive => {
                 wrap_item(w, "derive", |w| {
                     write!(w, "#[derive({})]", name);
-                    if !m.helpers.is_empty() {
+                    if m.derived_trait.is_some() || !m.helpers.is_empty() {
                         w.push_str("\n{\n");
-                        w.push_str("    // Attributes available to this derive:\n");
-                        for attr in &m.helpers {
-                            writeln!(w, "    #[{}]", attr);
+                        if let Some(trait_) = m.derived_trait {
+                            w.push_str("    // Implements the trait:\n");
+                            match href(trait_, cx) {
+                                Ok((url, _, path)) => writeln!(
+                                    w,
+                                    "    <a class=\"trait\" href=\"{}\" title=\"trait {}\">{}</a>",
+                                    url,
+                                    join_with_double_colon(&path),
+                                    name,
+                                ),
+                                Err(_) => writeln!(w, "    {}", name),
+                            }
+                        }
+                        if !m.helpers.is_empty() {
+                            w.push_str("    // Attributes available to this derive:\n");
+                            for attr in &m.helpers {
+                                writeln!(w, "    #[{}]", attr);
+                            }
                         }
                         w.push_str("}\n");
                     }
@@ -1343,39 +1407,40 @@ fn item_primitive(w: &mut Buffer, cx: &mut Context<'_>, it: &clean::Item) {
     render_assoc_items(w, cx, it, it.item_id.expect_def_id(), AssocItemRender::All)
 }
 
-fn item_constant(w: &mut Buffer, cx: &mut Context<'_>, it: &clean::Item, c: &clean::Constant) {
+fn item_constant(
+    w: &mut Buffer,
+    cx: &mut Context<'_>,
+    it: &clean::Item,
+    c: &clean::Constant,
+    repr: &clean::ConstantExprAndValue,
+) {
     wrap_into_docblock(w, |w| {
         wrap_item(w, "const", |w| {
             render_attributes_in_code(w, it);
 
             write!(
                 w,
-                "{vis}const {name}: {typ}",
+                "{vis}{const_} {name}: {typ}",
                 vis = it.visibility.print_with_space(it.item_id, cx),
+                const_ = keyword_link(kw::Const, cx),
                 name = it.name.unwrap(),
                 typ = c.type_.print(cx),
             );
 
-            let value = c.value(cx.tcx());
-            let is_literal = c.is_literal(cx.tcx());
-            let expr = c.expr(cx.tcx());
-            if value.is_some() || is_literal {
-                write!(w, " = {expr};", expr = Escape(&expr));
-            } else {
-                w.write_str(";");
+            let format = cx.shared.number_format;
+            if repr.show_expr(cx.tcx(), format) {
+                let (shown, rest) = clean::utils::split_const_expr(repr.expr(cx.tcx()));
+                write!(w, " = {shown}", shown = Escape(shown));
+                if let Some(rest) = rest {
+                    toggle_open(w, "full constant");
+                    write!(w, "{rest}", rest = Escape(rest));
+                    toggle_close(w);
+                }
             }
+            w.write_str(";");
 
-            if !is_literal {
-                if let Some(value) = &value {
-                    let value_lowercase = value.to_lowercase();
-                    let expr_lowercase = expr.to_lowercase();
-
-                    if value_lowercase != expr_lowercase
-                        && value_lowercase.trim_end_matches("i32") != expr_lowercase
-                    {
-                        write!(w, " // {value}", value = Escape(value));
-                    }
-                }
+            if let Some(value) = repr.distinct_value(cx.tcx(), format) {
+                write!(w, " // {value}", value = Escape(value));
             }
         });
     });
@@ -1442,8 +1507,9 @@ fn item_static(w: &mut Buffer, cx: &mut Context<'_>, it: &clean::Item, s: &clean
             render_attributes_in_code(w, it);
             write!(
                 w,
-                "{vis}static {mutability}{name}: {typ}",
+                "{vis}{static_} {mutability}{name}: {typ}",
                 vis = it.visibility.print_with_space(it.item_id, cx),
+                static_ = keyword_link(kw::Static, cx),
                 mutability = s.mutability.print_with_space(),
                 name = it.name.unwrap(),
                 typ = s.type_.print(cx)
@@ -1460,8 +1526,9 @@ fn item_foreign_type(w: &mut Buffer, cx: &mut Context<'_>, it: &clean::Item) {
             render_attributes_in_code(w, it);
             write!(
                 w,
-                "    {}type {};\n}}",
+                "    {}{} {};\n}}",
                 it.visibility.print_with_space(it.item_id, cx),
+                keyword_link(kw::Type, cx),
                 it.name.unwrap(),
             );
         });
@@ -1630,7 +1697,13 @@ fn render_union(
     tab: &str,
     cx: &Context<'_>,
 ) {
-    write!(w, "{}union {}", it.visibility.print_with_space(it.item_id, cx), it.name.unwrap());
+    write!(
+        w,
+        "{}{} {}",
+        it.visibility.print_with_space(it.item_id, cx),
+        keyword_link(kw::Union, cx),
+        it.name.unwrap()
+    );
     if let Some(g) = g {
         write!(w, "{}", g.print(cx));
         write!(w, "{}", print_where_clause(g, cx, 0, true));
@@ -1676,13 +1749,11 @@ fn render_struct(
     structhead: bool,
     cx: &Context<'_>,
 ) {
-    write!(
-        w,
-        "{}{}{}",
-        it.visibility.print_with_space(it.item_id, cx),
-        if structhead { "struct " } else { "" },
-        it.name.unwrap()
-    );
+    write!(w, "{}", it.visibility.print_with_space(it.item_id, cx));
+    if structhead {
+        write!(w, "{} ", keyword_link(kw::Struct, cx));
+    }
+    write!(w, "{}", it.name.unwrap());
     if let Some(g) = g {
         write!(w, "{}", g.print(cx))
     }
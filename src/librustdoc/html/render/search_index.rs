@@ -1,7 +1,10 @@
 use std::collections::hash_map::Entry;
 use std::collections::BTreeMap;
 
-use rustc_data_structures::fx::FxHashMap;
+use rustc_ast::token;
+use rustc_ast::tokenstream::{TokenStream, TokenTree};
+use rustc_data_structures::fx::{FxHashMap, FxHashSet};
+use rustc_hir::def_id::DefId;
 use rustc_middle::ty::TyCtxt;
 use rustc_span::symbol::{kw, Symbol};
 use serde::ser::{Serialize, SerializeStruct, Serializer};
@@ -10,9 +13,12 @@
 use crate::clean::types::{FnRetTy, Function, GenericBound, Generics, Type, WherePredicate};
 use crate::formats::cache::{Cache, OrphanImplItem};
 use crate::formats::item_type::ItemType;
+use crate::formats::Impl;
 use crate::html::format::join_with_double_colon;
 use crate::html::markdown::short_markdown_summary;
-use crate::html::render::{IndexItem, IndexItemFunctionType, RenderType, TypeWithKind};
+use crate::html::render::{
+    should_render_item, IndexItem, IndexItemFunctionType, RenderType, TypeWithKind,
+};
 
 /// Builds the search index from the collected metadata
 pub(crate) fn build_index<'tcx>(
@@ -39,10 +45,14 @@ pub(crate) fn build_index<'tcx>(
                 parent_idx: None,
                 search_type: get_function_type_for_search(item, tcx, impl_generics.as_ref(), cache),
                 aliases: item.attrs.get_doc_aliases(),
+                via_deref: false,
             });
         }
     }
 
+    let deref_methods = index_deref_methods(cache, tcx);
+    cache.search_index.extend(deref_methods);
+
     let crate_doc = krate
         .module
         .doc_value()
@@ -120,8 +130,11 @@ fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
             S: Serializer,
         {
             let has_aliases = !self.aliases.is_empty();
-            let mut crate_data =
-                serializer.serialize_struct("CrateData", if has_aliases { 9 } else { 8 })?;
+            let has_deref_methods = self.items.iter().any(|item| item.via_deref);
+            let mut crate_data = serializer.serialize_struct(
+                "CrateData",
+                8 + has_aliases as usize + has_deref_methods as usize,
+            )?;
             crate_data.serialize_field("doc", &self.doc)?;
             crate_data.serialize_field(
                 "t",
@@ -166,6 +179,18 @@ fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
             if has_aliases {
                 crate_data.serialize_field("a", &self.aliases)?;
             }
+            if has_deref_methods {
+                crate_data.serialize_field(
+                    "r",
+                    &self
+                        .items
+                        .iter()
+                        .enumerate()
+                        .filter(|(_, item)| item.via_deref)
+                        .map(|(i, _)| i)
+                        .collect::<Vec<_>>(),
+                )?;
+            }
             crate_data.end()
         }
     }
@@ -189,6 +214,78 @@ fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     )
 }
 
+/// Indexes the methods which can be called on the documented types through their `Deref`
+/// implementations, which are listed in the "Methods from Deref" sections of their pages.
+fn index_deref_methods(cache: &Cache, tcx: TyCtxt<'_>) -> Vec<IndexItem> {
+    let Some(deref_trait) = tcx.lang_items().deref_trait() else { return Vec::new() };
+    let deref_mut_trait = tcx.lang_items().deref_mut_trait();
+    let mut index = Vec::new();
+    for (&did, impls) in &cache.impls {
+        let Some(&(ref fqp, _)) = cache.paths.get(&did) else { continue };
+        let Some(mut target) = deref_target(cache, impls, deref_trait) else { continue };
+        let mut deref_mut = impls.iter().any(|i| i.trait_did() == deref_mut_trait);
+        // The methods of a type shadow the ones of its `Deref` targets with the same name.
+        let mut names: FxHashSet<Symbol> =
+            inherent_methods(impls).filter_map(|(_, item)| item.name).collect();
+        let mut visited = FxHashSet::from_iter([did]);
+        // Follow the whole chain of `Deref` impls, like the "Methods from Deref" sections.
+        while visited.insert(target) {
+            let Some(target_impls) = cache.impls.get(&target) else { break };
+            for (i, item) in inherent_methods(target_impls) {
+                let Some(name) = item.name else { continue };
+                if !should_render_item(item, deref_mut, tcx) || !names.insert(name) {
+                    continue;
+                }
+                let impl_ = i.inner_impl();
+                let impl_generics = (impl_.for_.clone(), impl_.generics.clone());
+                let desc = item.doc_value().map_or_else(String::new, |s| {
+                    short_markdown_summary(&s, &item.link_names(cache))
+                });
+                index.push(IndexItem {
+                    ty: item.type_(),
+                    name: name.to_string(),
+                    path: join_with_double_colon(&fqp[..fqp.len() - 1]),
+                    desc,
+                    parent: Some(did),
+                    parent_idx: None,
+                    search_type: get_function_type_for_search(
+                        item,
+                        tcx,
+                        Some(&impl_generics),
+                        cache,
+                    ),
+                    aliases: item.attrs.get_doc_aliases(),
+                    via_deref: true,
+                });
+            }
+            deref_mut &= target_impls.iter().any(|i| i.trait_did() == deref_mut_trait);
+            match deref_target(cache, target_impls, deref_trait) {
+                Some(next) => target = next,
+                None => break,
+            }
+        }
+    }
+    index
+}
+
+/// Returns the `DefId` of the `Deref::Target` of a type, given its implementations.
+fn deref_target(cache: &Cache, impls: &[Impl], deref_trait: DefId) -> Option<DefId> {
+    let deref_impl = impls.iter().find(|i| i.trait_did() == Some(deref_trait))?;
+    let target = deref_impl.inner_impl().items.iter().find_map(|item| match *item.kind {
+        clean::AssocTypeItem(ref t, _) => Some(t.item_type.as_ref().unwrap_or(&t.type_)),
+        _ => None,
+    })?;
+    target.def_id(cache)
+}
+
+fn inherent_methods(impls: &[Impl]) -> impl Iterator<Item = (&Impl, &clean::Item)> {
+    impls
+        .iter()
+        .filter(|i| i.inner_impl().trait_.is_none())
+        .flat_map(|i| i.inner_impl().items.iter().map(move |item| (i, item)))
+        .filter(|(_, item)| matches!(*item.kind, clean::MethodItem(..)))
+}
+
 pub(crate) fn get_function_type_for_search<'tcx>(
     item: &clean::Item,
     tcx: TyCtxt<'tcx>,
@@ -199,6 +296,7 @@ pub(crate) fn get_function_type_for_search<'tcx>(
         clean::FunctionItem(ref f) => get_fn_inputs_and_outputs(f, tcx, impl_generics, cache),
         clean::MethodItem(ref m, _) => get_fn_inputs_and_outputs(m, tcx, impl_generics, cache),
         clean::TyMethodItem(ref m) => get_fn_inputs_and_outputs(m, tcx, impl_generics, cache),
+        clean::MacroItem(ref m) => (get_macro_inputs(m), Vec::new()),
         _ => return None,
     };
 
@@ -208,6 +306,42 @@ pub(crate) fn get_function_type_for_search<'tcx>(
     Some(IndexItemFunctionType { inputs, output })
 }
 
+/// Returns the fragment specifiers used by the arms of a `macro_rules!` macro, so that it can be
+/// found by searching for e.g. `expr, ident`.
+fn get_macro_inputs(m: &clean::Macro) -> Vec<TypeWithKind> {
+    let mut fragments = Vec::new();
+    for matcher in &m.matchers {
+        if let TokenTree::Delimited(_, _, tts) = matcher {
+            collect_macro_fragments(tts, &mut fragments);
+        }
+    }
+    fragments
+        .into_iter()
+        .map(|name| (RenderType { name: Some(name), generics: None }, ItemType::Primitive).into())
+        .collect()
+}
+
+fn collect_macro_fragments(tts: &TokenStream, fragments: &mut Vec<String>) {
+    let trees: Vec<_> = tts.trees().collect();
+    for (i, tree) in trees.iter().enumerate() {
+        match (tree, &trees[i + 1..]) {
+            (TokenTree::Delimited(_, _, inner), _) => collect_macro_fragments(inner, fragments),
+            (
+                TokenTree::Token(dollar),
+                [TokenTree::Token(name), TokenTree::Token(colon), TokenTree::Token(fragment), ..],
+            ) if dollar.kind == token::Dollar && name.is_ident() && colon.kind == token::Colon => {
+                if let Some((fragment, _)) = fragment.ident() {
+                    let fragment = fragment.as_str().to_owned();
+                    if !fragments.contains(&fragment) {
+                        fragments.push(fragment);
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
 fn get_index_type(clean_type: &clean::Type, generics: Vec<TypeWithKind>) -> RenderType {
     RenderType {
         name: get_index_type_name(clean_type).map(|s| s.as_str().to_ascii_lowercase()),
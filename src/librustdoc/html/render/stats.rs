@@ -0,0 +1,162 @@
+//! Statistics about the documented crate, written out with `--crate-stats`.
+
+use std::collections::BTreeMap;
+
+use rustc_span::Symbol;
+use serde::Serialize;
+
+use crate::clean;
+use crate::html::escape::Escape;
+use crate::html::format::{join_with_double_colon, Buffer};
+use crate::visit::DocVisitor;
+
+/// How many modules are listed in [`CrateStats::largest_modules`].
+const LARGEST_MODULES: usize = 10;
+
+#[derive(Serialize, Default, Debug)]
+pub(crate) struct CrateStats {
+    /// The number of items of each kind, by the name of the kind as used in URLs. Synthetic impls
+    /// aren't counted here.
+    kinds: BTreeMap<&'static str, KindStats>,
+    synthetic_impls: SyntheticImpls,
+    /// The modules with the most items, with the most items first. Impls aren't counted.
+    largest_modules: Vec<ModuleStats>,
+}
+
+#[derive(Serialize, Default, Clone, Copy, Debug)]
+struct KindStats {
+    total: usize,
+    documented: usize,
+}
+
+impl KindStats {
+    fn documented_percentage(&self) -> f64 {
+        if self.total == 0 { 0.0 } else { self.documented as f64 * 100.0 / self.total as f64 }
+    }
+}
+
+/// The impls generated by rustdoc rather than written in the source code.
+#[derive(Serialize, Default, Debug)]
+struct SyntheticImpls {
+    auto: usize,
+    blanket: usize,
+}
+
+#[derive(Serialize, Debug)]
+struct ModuleStats {
+    path: String,
+    items: usize,
+}
+
+impl CrateStats {
+    pub(crate) fn new(krate: &clean::Crate) -> Self {
+        let mut collector = StatsCollector { stats: CrateStats::default(), path: Vec::new() };
+        // Only the items of the crate itself, not the ones of the external traits.
+        collector.visit_item(&krate.module);
+
+        let mut stats = collector.stats;
+        stats
+            .largest_modules
+            .sort_by(|a, b| b.items.cmp(&a.items).then_with(|| a.path.cmp(&b.path)));
+        stats.largest_modules.truncate(LARGEST_MODULES);
+        stats
+    }
+
+    pub(crate) fn print(&self, f: &mut Buffer) {
+        f.write_str(
+            "<h1 class=\"fqn\">\
+                 <span class=\"in-band\">Crate statistics</span>\
+             </h1>",
+        );
+
+        let total = self.kinds.values().fold(KindStats::default(), |total, kind| KindStats {
+            total: total.total + kind.total,
+            documented: total.documented + kind.documented,
+        });
+        f.write_str(
+            "<h3 id=\"items\">Items</h3>\
+             <table class=\"crate-stats\">\
+                <thead><tr><th>Kind</th><th>Items</th><th>Documented</th></tr></thead>\
+                <tbody>",
+        );
+        let rows = self.kinds.iter().map(|(kind, stats)| (*kind, stats));
+        for (kind, stats) in rows.chain([("total", &total)]) {
+            write!(
+                f,
+                "<tr><td>{}</td><td>{}</td><td>{} ({:.1}%)</td></tr>",
+                kind,
+                stats.total,
+                stats.documented,
+                stats.documented_percentage(),
+            );
+        }
+        f.write_str("</tbody></table>");
+
+        write!(
+            f,
+            "<h3 id=\"synthetic-impls\">Synthetic implementations</h3>\
+             <ul class=\"docblock\">\
+                <li>Auto trait implementations: {}</li>\
+                <li>Blanket implementations: {}</li>\
+             </ul>",
+            self.synthetic_impls.auto, self.synthetic_impls.blanket,
+        );
+
+        f.write_str("<h3 id=\"largest-modules\">Largest modules</h3><ol class=\"docblock\">");
+        for module in &self.largest_modules {
+            write!(f, "<li><code>{}</code>: {} items</li>", Escape(&module.path), module.items);
+        }
+        f.write_str("</ol>");
+    }
+}
+
+struct StatsCollector {
+    stats: CrateStats,
+    /// The path of the current module.
+    path: Vec<Symbol>,
+}
+
+impl DocVisitor for StatsCollector {
+    fn visit_item(&mut self, item: &clean::Item) {
+        if item.is_stripped() {
+            return;
+        }
+
+        match *item.kind {
+            clean::ImplItem(ref i) if i.kind.is_auto() => self.stats.synthetic_impls.auto += 1,
+            clean::ImplItem(ref i) if i.kind.is_blanket() => {
+                self.stats.synthetic_impls.blanket += 1
+            }
+            clean::ModuleItem(ref m) => {
+                self.count(item);
+                self.path.extend(item.name);
+                // Impls aren't listed on the page of their module.
+                let items = m
+                    .items
+                    .iter()
+                    .filter(|i| !i.is_stripped() && !matches!(*i.kind, clean::ImplItem(_)))
+                    .count();
+                let path = join_with_double_colon(&self.path);
+                self.stats.largest_modules.push(ModuleStats { path, items });
+                self.visit_item_recur(item);
+                if item.name.is_some() {
+                    self.path.pop();
+                }
+            }
+            _ => {
+                self.count(item);
+                self.visit_item_recur(item);
+            }
+        }
+    }
+}
+
+impl StatsCollector {
+    fn count(&mut self, item: &clean::Item) {
+        let kind = self.stats.kinds.entry(item.type_().as_str()).or_default();
+        kind.total += 1;
+        if !item.attrs.doc_strings.is_empty() {
+            kind.documented += 1;
+        }
+    }
+}
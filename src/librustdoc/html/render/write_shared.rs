@@ -10,7 +10,7 @@
 use itertools::Itertools;
 use rustc_data_structures::flock;
 use rustc_data_structures::fx::{FxHashMap, FxHashSet};
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 
 use super::{collect_paths_for_type, ensure_trailing_slash, Context, BASIC_KEYWORDS};
 use crate::clean::Crate;
@@ -149,6 +149,9 @@ pub(super) fn write_shared(
     let lock_file = cx.dst.join(".lock");
     let _lock = try_err!(flock::Lock::new(&lock_file, true, true, true), &lock_file);
 
+    let mut index_part =
+        IndexPart { krate: krate.name(cx.tcx()).to_string(), ..IndexPart::default() };
+
     // Minified resources are usually toolchain resources. If they're not, they should use `cx.write_minify` directly.
     fn write_minify(
         basename: &'static str,
@@ -284,64 +287,6 @@ fn ver_url(cx: &Context<'_>, basename: &'static str) -> String {
         cx.write_shared(SharedResource::Unversioned { name }, contents, &options.emit)?;
     }
 
-    fn collect(path: &Path, krate: &str, key: &str) -> io::Result<(Vec<String>, Vec<String>)> {
-        let mut ret = Vec::new();
-        let mut krates = Vec::new();
-
-        if path.exists() {
-            let prefix = format!(r#"{}["{}"]"#, key, krate);
-            for line in BufReader::new(File::open(path)?).lines() {
-                let line = line?;
-                if !line.starts_with(key) {
-                    continue;
-                }
-                if line.starts_with(&prefix) {
-                    continue;
-                }
-                ret.push(line.to_string());
-                krates.push(
-                    line[key.len() + 2..]
-                        .split('"')
-                        .next()
-                        .map(|s| s.to_owned())
-                        .unwrap_or_else(String::new),
-                );
-            }
-        }
-        Ok((ret, krates))
-    }
-
-    fn collect_json(path: &Path, krate: &str) -> io::Result<(Vec<String>, Vec<String>)> {
-        let mut ret = Vec::new();
-        let mut krates = Vec::new();
-
-        if path.exists() {
-            let prefix = format!("\"{}\"", krate);
-            for line in BufReader::new(File::open(path)?).lines() {
-                let line = line?;
-                if !line.starts_with('"') {
-                    continue;
-                }
-                if line.starts_with(&prefix) {
-                    continue;
-                }
-                if line.ends_with(",\\") {
-                    ret.push(line[..line.len() - 2].to_string());
-                } else {
-                    // Ends with "\\" (it's the case for the last added crate line)
-                    ret.push(line[..line.len() - 1].to_string());
-                }
-                krates.push(
-                    line.split('"')
-                        .find(|s| !s.is_empty())
-                        .map(|s| s.to_owned())
-                        .unwrap_or_else(String::new),
-                );
-            }
-        }
-        Ok((ret, krates))
-    }
-
     use std::ffi::OsString;
 
     #[derive(Debug)]
@@ -409,28 +354,27 @@ fn to_json_string(&self) -> String {
         }
 
         let dst = cx.dst.join(&format!("source-files{}.js", cx.shared.resource_suffix));
+        let sources = format!(
+            "sourcesIndex[\"{}\"] = {};",
+            &krate.name(cx.tcx()),
+            hierarchy.to_json_string()
+        );
         let make_sources = || {
             let (mut all_sources, _krates) =
                 try_err!(collect(&dst, krate.name(cx.tcx()).as_str(), "sourcesIndex"), &dst);
-            all_sources.push(format!(
-                "sourcesIndex[\"{}\"] = {};",
-                &krate.name(cx.tcx()),
-                hierarchy.to_json_string()
-            ));
+            all_sources.push(sources.clone());
             all_sources.sort();
-            Ok(format!(
-                "var sourcesIndex = {{}};\n{}\ncreateSourceSidebar();\n",
-                all_sources.join("\n")
-            )
-            .into_bytes())
+            Ok(source_files_js(&all_sources).into_bytes())
         };
         write_crate("source-files.js", &make_sources)?;
+        index_part.sources = Some(sources);
     }
 
     // Update the search index and crate list.
     let dst = cx.dst.join(&format!("search-index{}.js", cx.shared.resource_suffix));
     let (mut all_indexes, mut krates) =
         try_err!(collect_json(&dst, krate.name(cx.tcx()).as_str()), &dst);
+    index_part.search_index = search_index.clone();
     all_indexes.push(search_index);
     krates.push(krate.name(cx.tcx()).to_string());
     krates.sort();
@@ -438,23 +382,8 @@ fn to_json_string(&self) -> String {
     // Sort the indexes by crate so the file will be generated identically even
     // with rustdoc running in parallel.
     all_indexes.sort();
-    write_crate("search-index.js", &|| {
-        let mut v = String::from("var searchIndex = JSON.parse('{\\\n");
-        v.push_str(&all_indexes.join(",\\\n"));
-        v.push_str(
-            r#"\
-}');
-if (typeof window !== 'undefined' && window.initSearch) {window.initSearch(searchIndex)};
-if (typeof exports !== 'undefined') {exports.searchIndex = searchIndex};
-"#,
-        );
-        Ok(v.into_bytes())
-    })?;
-
-    write_crate("crates.js", &|| {
-        let krates = krates.iter().map(|k| format!("\"{}\"", k)).join(",");
-        Ok(format!("window.ALL_CRATES = [{}];", krates).into_bytes())
-    })?;
+    write_crate("search-index.js", &|| Ok(search_index_js(&all_indexes).into_bytes()))?;
+    write_crate("crates.js", &|| Ok(crates_js(&krates).into_bytes()))?;
 
     if options.enable_index_page {
         if let Some(index_page) = options.index_page.clone() {
@@ -498,7 +427,6 @@ fn to_json_string(&self) -> String {
     }
 
     // Update the list of all implementors for traits
-    let dst = cx.dst.join("implementors");
     let cache = cx.cache();
     for (&did, imps) in &cache.implementors {
         // Private modules can leak through to this phase of rustdoc, which
@@ -561,33 +489,295 @@ struct Implementor {
             serde_json::to_string(&implementors).unwrap()
         );
 
-        let mut mydst = dst.clone();
+        let mut relative_path = PathBuf::from("implementors");
         for part in &remote_path[..remote_path.len() - 1] {
-            mydst.push(part.to_string());
+            relative_path.push(part.to_string());
         }
+        let mut mydst = cx.dst.join(&relative_path);
         cx.shared.ensure_dir(&mydst)?;
-        mydst.push(&format!("{}.{}.js", remote_item_type, remote_path[remote_path.len() - 1]));
+        let file_name = format!("{}.{}.js", remote_item_type, remote_path[remote_path.len() - 1]);
+        mydst.push(&file_name);
+        relative_path.push(file_name);
 
         let (mut all_implementors, _) =
             try_err!(collect(&mydst, krate.name(cx.tcx()).as_str(), "implementors"), &mydst);
+        index_part.implementors.push((relative_path, implementors.clone()));
         all_implementors.push(implementors);
         // Sort the implementors by crate so the file will be generated
         // identically even with rustdoc running in parallel.
         all_implementors.sort();
+        cx.shared.fs.write(mydst, implementors_js(&all_implementors))?;
+    }
+
+    if options.write_index_part {
+        let dir = cx.dst.join(INDEX_PARTS_DIR);
+        cx.shared.ensure_dir(&dir)?;
+        let path = dir.join(format!("{}.json", krate.name(cx.tcx())));
+        cx.shared.fs.write(path, serde_json::to_string(&index_part).unwrap())?;
+    }
+    Ok(())
+}
 
-        let mut v = String::from("(function() {var implementors = {};\n");
-        for implementor in &all_implementors {
-            writeln!(v, "{}", *implementor).unwrap();
+/// The directory in which `--write-index-part` writes the parts of the shared files of each crate.
+const INDEX_PARTS_DIR: &str = "index-parts";
+
+/// The lines a crate adds to the files shared by all the crates documented in an output directory,
+/// written with `--write-index-part` so that the documentation of crates built separately can be
+/// merged with `--merge-index-parts`.
+#[derive(Default, Serialize, Deserialize)]
+struct IndexPart {
+    krate: String,
+    search_index: String,
+    sources: Option<String>,
+    /// The path of each implementors file, relative to the output directory, with the line of
+    /// the crate in it.
+    implementors: Vec<(PathBuf, String)>,
+}
+
+fn collect(path: &Path, krate: &str, key: &str) -> io::Result<(Vec<String>, Vec<String>)> {
+    let mut ret = Vec::new();
+    let mut krates = Vec::new();
+
+    if path.exists() {
+        let prefix = format!(r#"{}["{}"]"#, key, krate);
+        for line in BufReader::new(File::open(path)?).lines() {
+            let line = line?;
+            if !line.starts_with(key) {
+                continue;
+            }
+            if line.starts_with(&prefix) {
+                continue;
+            }
+            ret.push(line.to_string());
+            krates.push(
+                line[key.len() + 2..]
+                    .split('"')
+                    .next()
+                    .map(|s| s.to_owned())
+                    .unwrap_or_else(String::new),
+            );
         }
-        v.push_str(
-            "if (window.register_implementors) {\
-                 window.register_implementors(implementors);\
-             } else {\
-                 window.pending_implementors = implementors;\
-             }",
-        );
-        v.push_str("})()");
-        cx.shared.fs.write(mydst, v)?;
+    }
+    Ok((ret, krates))
+}
+
+fn collect_json(path: &Path, krate: &str) -> io::Result<(Vec<String>, Vec<String>)> {
+    let mut ret = Vec::new();
+    let mut krates = Vec::new();
+
+    if path.exists() {
+        let prefix = format!("\"{}\"", krate);
+        for line in BufReader::new(File::open(path)?).lines() {
+            let line = line?;
+            if !line.starts_with('"') {
+                continue;
+            }
+            if line.starts_with(&prefix) {
+                continue;
+            }
+            if line.ends_with(",\\") {
+                ret.push(line[..line.len() - 2].to_string());
+            } else {
+                // Ends with "\\" (it's the case for the last added crate line)
+                ret.push(line[..line.len() - 1].to_string());
+            }
+            krates.push(
+                line.split('"')
+                    .find(|s| !s.is_empty())
+                    .map(|s| s.to_owned())
+                    .unwrap_or_else(String::new),
+            );
+        }
+    }
+    Ok((ret, krates))
+}
+
+fn search_index_js(all_indexes: &[String]) -> String {
+    let mut v = String::from("var searchIndex = JSON.parse('{\\\n");
+    v.push_str(&all_indexes.join(",\\\n"));
+    v.push_str(
+        r#"\
+}');
+if (typeof window !== 'undefined' && window.initSearch) {window.initSearch(searchIndex)};
+if (typeof exports !== 'undefined') {exports.searchIndex = searchIndex};
+"#,
+    );
+    v
+}
+
+fn crates_js(krates: &[String]) -> String {
+    let krates = krates.iter().map(|k| format!("\"{}\"", k)).join(",");
+    format!("window.ALL_CRATES = [{}];", krates)
+}
+
+fn source_files_js(all_sources: &[String]) -> String {
+    format!("var sourcesIndex = {{}};\n{}\ncreateSourceSidebar();\n", all_sources.join("\n"))
+}
+
+fn implementors_js(all_implementors: &[String]) -> String {
+    let mut v = String::from("(function() {var implementors = {};\n");
+    for implementor in all_implementors {
+        writeln!(v, "{}", *implementor).unwrap();
+    }
+    v.push_str(
+        "if (window.register_implementors) {\
+             window.register_implementors(implementors);\
+         } else {\
+             window.pending_implementors = implementors;\
+         }",
+    );
+    v.push_str("})()");
+    v
+}
+
+/// Copies the documentation of each of `dirs` into `out`, and merges the files they share, for
+/// `--merge-index-parts`.
+///
+/// The lines of the crates documented with `--write-index-part` come from their parts; the lines
+/// of the other crates of a directory are taken from its shared files as they are.
+pub(crate) fn merge_index_parts(
+    dirs: &[PathBuf],
+    out: &Path,
+    resource_suffix: &str,
+) -> Result<(), String> {
+    let err = |path: &Path| move |e: io::Error| format!("{}: {}", path.display(), e);
+    let shared_files = [
+        format!("search-index{}.js", resource_suffix),
+        format!("crates{}.js", resource_suffix),
+        format!("source-files{}.js", resource_suffix),
+    ];
+    for dir in dirs {
+        // The shared files are regenerated below.
+        copy_dir(dir, out, &|path| {
+            path.starts_with("implementors")
+                || shared_files.iter().any(|file| path == Path::new(file))
+        })
+        .map_err(err(dir))?;
+
+        let mut parts = Vec::new();
+        let parts_dir = dir.join(INDEX_PARTS_DIR);
+        if parts_dir.is_dir() {
+            for entry in fs::read_dir(&parts_dir).map_err(err(&parts_dir))? {
+                let path = entry.map_err(err(&parts_dir))?.path();
+                let contents = fs::read_to_string(&path).map_err(err(&path))?;
+                let part: IndexPart = serde_json::from_str(&contents)
+                    .map_err(|e| format!("{}: invalid index part: {}", path.display(), e))?;
+                parts.push(part);
+            }
+        }
+        let with_parts = parts.iter().map(|part| part.krate.clone()).collect::<FxHashSet<_>>();
+        let without_parts = index_parts_from_shared_files(dir, resource_suffix)
+            .map_err(err(dir))?
+            .into_iter()
+            .filter(|part| !with_parts.contains(&part.krate));
+        parts.extend(without_parts);
+
+        for part in &parts {
+            merge_index_part(part, out, resource_suffix).map_err(|e| e.to_string())?;
+        }
+    }
+    Ok(())
+}
+
+/// Rebuilds the parts of the crates documented in `dir` from the lines they added to its shared
+/// files.
+fn index_parts_from_shared_files(dir: &Path, resource_suffix: &str) -> io::Result<Vec<IndexPart>> {
+    fn collect_implementors(
+        dir: &Path,
+        relative_path: &Path,
+        parts: &mut FxHashMap<String, IndexPart>,
+    ) -> io::Result<()> {
+        let path = dir.join(relative_path);
+        if !path.is_dir() {
+            return Ok(());
+        }
+        for entry in fs::read_dir(&path)? {
+            let entry = entry?;
+            let relative_path = relative_path.join(entry.file_name());
+            if entry.file_type()?.is_dir() {
+                collect_implementors(dir, &relative_path, parts)?;
+                continue;
+            }
+            let (lines, krates) = collect(&entry.path(), "", "implementors")?;
+            for (line, krate) in lines.into_iter().zip(krates) {
+                let part = parts
+                    .entry(krate.clone())
+                    .or_insert_with(|| IndexPart { krate, ..IndexPart::default() });
+                part.implementors.push((relative_path.clone(), line));
+            }
+        }
+        Ok(())
+    }
+
+    let mut parts = FxHashMap::default();
+    let (indexes, krates) =
+        collect_json(&dir.join(format!("search-index{}.js", resource_suffix)), "")?;
+    for (search_index, krate) in indexes.into_iter().zip(krates) {
+        parts.insert(krate.clone(), IndexPart { krate, search_index, ..IndexPart::default() });
+    }
+    let (sources, krates) =
+        collect(&dir.join(format!("source-files{}.js", resource_suffix)), "", "sourcesIndex")?;
+    for (sources, krate) in sources.into_iter().zip(krates) {
+        if let Some(part) = parts.get_mut(&krate) {
+            part.sources = Some(sources);
+        }
+    }
+    collect_implementors(dir, Path::new("implementors"), &mut parts)?;
+    Ok(parts.into_values().filter(|part| !part.search_index.is_empty()).collect())
+}
+
+/// Adds the lines of a crate to the shared files in `out`, like documenting it there would have.
+fn merge_index_part(part: &IndexPart, out: &Path, resource_suffix: &str) -> Result<(), Error> {
+    let dst = out.join(format!("search-index{}.js", resource_suffix));
+    let (mut all_indexes, mut krates) = try_err!(collect_json(&dst, &part.krate), &dst);
+    all_indexes.push(part.search_index.clone());
+    all_indexes.sort();
+    try_err!(fs::write(&dst, search_index_js(&all_indexes)), &dst);
+    krates.push(part.krate.clone());
+    krates.sort();
+    let dst = out.join(format!("crates{}.js", resource_suffix));
+    try_err!(fs::write(&dst, crates_js(&krates)), &dst);
+
+    if let Some(ref sources) = part.sources {
+        let dst = out.join(format!("source-files{}.js", resource_suffix));
+        let (mut all_sources, _) = try_err!(collect(&dst, &part.krate, "sourcesIndex"), &dst);
+        all_sources.push(sources.clone());
+        all_sources.sort();
+        try_err!(fs::write(&dst, source_files_js(&all_sources)), &dst);
+    }
+
+    for (relative_path, implementors) in &part.implementors {
+        let dst = out.join(relative_path);
+        if let Some(parent) = dst.parent() {
+            try_err!(fs::create_dir_all(parent), parent);
+        }
+        let (mut all_implementors, _) = try_err!(collect(&dst, &part.krate, "implementors"), &dst);
+        all_implementors.push(implementors.clone());
+        all_implementors.sort();
+        try_err!(fs::write(&dst, implementors_js(&all_implementors)), &dst);
     }
     Ok(())
 }
+
+/// Recursively copies the files of `from` into `to`, except the ones `skip` returns `true` for,
+/// given their path relative to `from`.
+fn copy_dir(from: &Path, to: &Path, skip: &dyn Fn(&Path) -> bool) -> io::Result<()> {
+    fn copy(root: &Path, from: &Path, to: &Path, skip: &dyn Fn(&Path) -> bool) -> io::Result<()> {
+        fs::create_dir_all(to)?;
+        for entry in fs::read_dir(from)? {
+            let entry = entry?;
+            let path = entry.path();
+            if skip(path.strip_prefix(root).unwrap()) {
+                continue;
+            }
+            let dst = to.join(entry.file_name());
+            if entry.file_type()?.is_dir() {
+                copy(root, &path, &dst, skip)?;
+            } else {
+                fs::copy(&path, &dst)?;
+            }
+        }
+        Ok(())
+    }
+    copy(from, from, to, skip)
+}
@@ -299,5 +299,6 @@ pub(crate) fn print_src(
         Some(line_numbers),
         Some(highlight::HrefContext { context, file_span, root_path }),
         decoration_info,
+        None,
     );
 }
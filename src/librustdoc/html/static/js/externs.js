@@ -50,7 +50,8 @@ let ParsedQuery;
  *    parent: (Object|null|undefined),
  *    path: string,
  *    ty: (Number|null|number),
- *    type: (Array<?>|null)
+ *    type: (Array<?>|null),
+ *    viaDeref: boolean,
  * }}
  */
 let Row;
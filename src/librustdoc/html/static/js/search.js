@@ -1171,6 +1171,7 @@ function initSearch(rawSearchIndex) {
                 ty: item.ty,
                 parent: item.parent,
                 type: item.type,
+                viaDeref: item.viaDeref,
                 is_alias: true,
             };
         }
@@ -1604,6 +1605,8 @@ function initSearch(rawSearchIndex) {
                     extra = " <i>(primitive type)</i>";
                 } else if (type === "keyword") {
                     extra = " <i>(keyword)</i>";
+                } else if (item.viaDeref) {
+                    extra = " <i>(via Deref)</i>";
                 }
 
                 const link = document.createElement("a");
@@ -1871,6 +1874,9 @@ function initSearch(rawSearchIndex) {
              *
              * `p` is a mystery and isn't the same length as n/t/d/q/i/f.
              *
+             * `r` contains the offsets of the methods which are reachable through `Deref`
+             * implementations, if any.
+             *
              * @type {{
              *   doc: string,
              *   a: Object,
@@ -1881,6 +1887,7 @@ function initSearch(rawSearchIndex) {
              *   i: Array<Number>,
              *   f: Array<Array<?>>,
              *   p: Array<Object>,
+             *   r: Array<Number>,
              * }}
              */
             const crateCorpus = rawSearchIndex[crate];
@@ -1899,6 +1906,7 @@ function initSearch(rawSearchIndex) {
                 type: null,
                 id: id,
                 normalizedName: crate.indexOf("_") === -1 ? crate : crate.replace(/_/g, ""),
+                viaDeref: false,
             };
             id += 1;
             searchIndex.push(crateRow);
@@ -1922,6 +1930,8 @@ function initSearch(rawSearchIndex) {
             // an array of [(String) alias name
             //             [Number] index to items]
             const aliases = crateCorpus.a;
+            // an array of (Number) indexes of the methods reachable through `Deref`
+            const derefMethods = new Set(crateCorpus.r || []);
 
             // convert `rawPaths` entries into object form
             let len = paths.length;
@@ -1958,6 +1968,7 @@ function initSearch(rawSearchIndex) {
                     type: itemFunctionSearchTypes[i],
                     id: id,
                     normalizedName: word.indexOf("_") === -1 ? word : word.replace(/_/g, ""),
+                    viaDeref: derefMethods.has(i),
                 };
                 id += 1;
                 searchIndex.push(row);
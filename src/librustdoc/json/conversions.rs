@@ -8,6 +8,9 @@
 use std::fmt;
 
 use rustc_ast::ast;
+use rustc_ast::token::{self, Delimiter};
+use rustc_ast::tokenstream::{TokenStream, TokenTree};
+use rustc_ast_pretty::pprust;
 use rustc_hir::{def::CtorKind, def_id::DefId};
 use rustc_middle::ty::{self, TyCtxt};
 use rustc_span::{Pos, Symbol};
@@ -15,8 +18,9 @@
 
 use rustdoc_json_types::*;
 
-use crate::clean::utils::print_const_expr;
+use crate::clean::utils::{print_const_expr, stable_def_path_hash};
 use crate::clean::{self, ItemId};
+use crate::config::NumberFormat;
 use crate::formats::item_type::ItemType;
 use crate::json::JsonRenderer;
 
@@ -41,6 +45,14 @@ pub(super) fn convert_item(&self, item: clean::Item) -> Option<Item> {
             .map(rustc_ast_pretty::pprust::attribute_to_string)
             .collect();
         let span = item.span(self.tcx);
+        let required_features = self
+            .cache
+            .required_features
+            .get(&item.item_id)
+            .into_iter()
+            .flatten()
+            .map(|feature| feature.to_string())
+            .collect();
         let clean::Item { name, attrs: _, kind: _, visibility, item_id, cfg: _ } = item;
         let inner = match *item.kind {
             clean::StrippedItem(_) | clean::KeywordItem(_) => return None,
@@ -50,35 +62,17 @@ pub(super) fn convert_item(&self, item: clean::Item) -> Option<Item> {
             id: from_item_id_with_name(item_id, self.tcx, name),
             crate_id: item_id.krate().as_u32(),
             name: name.map(|sym| sym.to_string()),
-            span: self.convert_span(span),
+            span: convert_span(span, self.tcx),
             visibility: self.convert_visibility(visibility),
             docs,
             attrs,
             deprecation: deprecation.map(from_deprecation),
+            required_features,
             inner,
             links,
         })
     }
 
-    fn convert_span(&self, span: clean::Span) -> Option<Span> {
-        match span.filename(self.sess()) {
-            rustc_span::FileName::Real(name) => {
-                if let Some(local_path) = name.into_local_path() {
-                    let hi = span.hi(self.sess());
-                    let lo = span.lo(self.sess());
-                    Some(Span {
-                        filename: local_path,
-                        begin: (lo.line, lo.col.to_usize()),
-                        end: (hi.line, hi.col.to_usize()),
-                    })
-                } else {
-                    None
-                }
-            }
-            _ => None,
-        }
-    }
-
     fn convert_visibility(&self, v: clean::Visibility) -> Visibility {
         use clean::Visibility::*;
         match v {
@@ -144,12 +138,33 @@ fn from_tcx(arg: clean::GenericArg, tcx: TyCtxt<'_>) -> Self {
     }
 }
 
+fn convert_span(span: clean::Span, tcx: TyCtxt<'_>) -> Option<Span> {
+    match span.filename(tcx.sess) {
+        rustc_span::FileName::Real(name) => {
+            if let Some(local_path) = name.into_local_path() {
+                let hi = span.hi(tcx.sess);
+                let lo = span.lo(tcx.sess);
+                Some(Span {
+                    filename: local_path,
+                    begin: (lo.line, lo.col.to_usize()),
+                    end: (hi.line, hi.col.to_usize()),
+                })
+            } else {
+                None
+            }
+        }
+        _ => None,
+    }
+}
+
 impl FromWithTcx<clean::Constant> for Constant {
     fn from_tcx(constant: clean::Constant, tcx: TyCtxt<'_>) -> Self {
         let expr = constant.expr(tcx);
-        let value = constant.value(tcx);
+        // The values are meant to be read by tools, which shouldn't depend on `--number-format`.
+        let value = constant.value(tcx, NumberFormat::default());
         let is_literal = constant.is_literal(tcx);
-        Constant { type_: constant.type_.into_tcx(tcx), expr, value, is_literal }
+        let span = constant.span(tcx).and_then(|span| convert_span(span, tcx));
+        Constant { type_: constant.type_.into_tcx(tcx), expr, value, is_literal, span }
     }
 }
 
@@ -177,7 +192,9 @@ fn from_tcx(kind: clean::TypeBindingKind, tcx: TyCtxt<'_>) -> Self {
 
 /// It generates an ID as follows:
 ///
-/// `CRATE_ID:ITEM_ID[:NAME_ID]` (if there is no name, NAME_ID is not generated).
+/// `PATH_HASH[:NAME]` (if there is no name, NAME is not generated), where `PATH_HASH` is the
+/// [`stable_def_path_hash`] of the item. This keeps the ID of an item the same across runs as
+/// long as its path and its crate don't change.
 pub(crate) fn from_item_id(item_id: ItemId, tcx: TyCtxt<'_>) -> Id {
     from_item_id_with_name(item_id, tcx, None)
 }
@@ -190,14 +207,10 @@ pub(crate) fn from_item_id_with_name(item_id: ItemId, tcx: TyCtxt<'_>, name: Opt
     impl<'a> fmt::Display for DisplayDefId<'a> {
         fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
             let name = match self.2 {
-                Some(name) => format!(":{}", name.as_u32()),
-                None => self
-                    .1
-                    .opt_item_name(self.0)
-                    .map(|n| format!(":{}", n.as_u32()))
-                    .unwrap_or_default(),
+                Some(name) => format!(":{}", name),
+                None => self.1.opt_item_name(self.0).map(|n| format!(":{}", n)).unwrap_or_default(),
             };
-            write!(f, "{}:{}{}", self.0.krate.as_u32(), u32::from(self.0.index), name)
+            write!(f, "{}{}", stable_def_path_hash(self.1, self.0), name)
         }
     }
 
@@ -209,7 +222,7 @@ fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         ItemId::Auto { for_, trait_ } => {
             Id(format!("a:{}-{}", DisplayDefId(trait_, tcx, None), DisplayDefId(for_, tcx, name)))
         }
-        ItemId::Primitive(ty, krate) => Id(format!("p:{}:{}", krate.as_u32(), ty.as_sym())),
+        ItemId::Primitive(ty, krate) => Id(format!("p:{}:{}", tcx.crate_name(krate), ty.as_sym())),
     }
 }
 
@@ -239,12 +252,12 @@ fn from_clean_item(item: clean::Item, tcx: TyCtxt<'_>) -> ItemEnum {
         ForeignTypeItem => ItemEnum::ForeignType,
         TypedefItem(t) => ItemEnum::Typedef(t.into_tcx(tcx)),
         OpaqueTyItem(t) => ItemEnum::OpaqueTy(t.into_tcx(tcx)),
-        ConstantItem(c) => ItemEnum::Constant(c.into_tcx(tcx)),
-        MacroItem(m) => ItemEnum::Macro(m.source),
+        ConstantItem(c, _) => ItemEnum::Constant(c.into_tcx(tcx)),
+        MacroItem(m) => ItemEnum::Macro(m.into_tcx(tcx)),
         ProcMacroItem(m) => ItemEnum::ProcMacro(m.into_tcx(tcx)),
         PrimitiveItem(p) => ItemEnum::PrimitiveType(p.as_sym().to_string()),
         TyAssocConstItem(ty) => ItemEnum::AssocConst { type_: ty.into_tcx(tcx), default: None },
-        AssocConstItem(ty, default) => {
+        AssocConstItem(ty, default, _) => {
             ItemEnum::AssocConst { type_: ty.into_tcx(tcx), default: Some(default.expr(tcx)) }
         }
         TyAssocTypeItem(g, b) => ItemEnum::AssocType {
@@ -544,13 +557,14 @@ fn from_tcx(trait_: clean::Trait, tcx: TyCtxt<'_>) -> Self {
 impl FromWithTcx<clean::Impl> for Impl {
     fn from_tcx(impl_: clean::Impl, tcx: TyCtxt<'_>) -> Self {
         let provided_trait_methods = impl_.provided_trait_methods(tcx);
+        let deref_target = deref_target(&impl_, tcx).map(|ty| ty.clone().into_tcx(tcx));
         let clean::Impl { unsafety, generics, trait_, for_, items, polarity, kind } = impl_;
         // FIXME: should `trait_` be a clean::Path equivalent in JSON?
         let trait_ = trait_.map(|path| clean::Type::Path { path }.into_tcx(tcx));
         // FIXME: use something like ImplKind in JSON?
         let (synthetic, blanket_impl) = match kind {
             clean::ImplKind::Normal | clean::ImplKind::TupleVaradic => (false, None),
-            clean::ImplKind::Auto => (true, None),
+            clean::ImplKind::Auto { .. } => (true, None),
             clean::ImplKind::Blanket(ty) => (false, Some(*ty)),
         };
         let negative_polarity = match polarity {
@@ -570,10 +584,24 @@ fn from_tcx(impl_: clean::Impl, tcx: TyCtxt<'_>) -> Self {
             negative: negative_polarity,
             synthetic,
             blanket_impl: blanket_impl.map(|x| x.into_tcx(tcx)),
+            deref_target,
+            // Filled in by the `JsonRenderer`, which has access to the impls of the target.
+            deref_impls: Vec::new(),
         }
     }
 }
 
+/// Returns the `Target` type of `impl_` if it's a `Deref` impl.
+pub(crate) fn deref_target<'a>(impl_: &'a clean::Impl, tcx: TyCtxt<'_>) -> Option<&'a clean::Type> {
+    if impl_.trait_.as_ref()?.def_id() != tcx.lang_items().deref_trait()? {
+        return None;
+    }
+    impl_.items.iter().find_map(|item| match *item.kind {
+        clean::AssocTypeItem(ref t, _) => Some(&t.type_),
+        _ => None,
+    })
+}
+
 pub(crate) fn from_function(
     function: clean::Function,
     header: rustc_hir::FnHeader,
@@ -671,6 +699,104 @@ fn from_tcx(import: clean::Import, tcx: TyCtxt<'_>) -> Self {
     }
 }
 
+impl FromWithTcx<clean::Macro> for Macro {
+    fn from_tcx(mac: clean::Macro, tcx: TyCtxt<'_>) -> Self {
+        let arms = mac
+            .matchers
+            .iter()
+            .enumerate()
+            .map(|(i, matcher)| MacroArm {
+                matcher: match matcher {
+                    TokenTree::Delimited(_, _, tts) => from_macro_matcher(tts),
+                    // Not a valid matcher, but convert the token itself rather than dropping it.
+                    TokenTree::Token(_) => from_macro_matcher(&matcher.clone().into()),
+                },
+                span: convert_span(clean::Span::new(matcher.span()), tcx),
+                anchor: format!("arm.{}", i),
+            })
+            .collect();
+        Macro { source: mac.source, arms }
+    }
+}
+
+fn from_macro_matcher(tts: &TokenStream) -> Vec<MacroMatcher> {
+    let trees: Vec<_> = tts.trees().cloned().collect();
+    let mut out = Vec::new();
+    let mut rest = &trees[..];
+    while let [first, tail @ ..] = rest {
+        rest = tail;
+        match first {
+            TokenTree::Token(dollar) if dollar.kind == token::Dollar => match rest {
+                [TokenTree::Delimited(_, Delimiter::Parenthesis, inner), tail @ ..] => {
+                    let (separator, kleene, tail) = from_repetition_op(tail);
+                    out.push(MacroMatcher::Repetition {
+                        tokens: from_macro_matcher(inner),
+                        separator,
+                        kleene,
+                    });
+                    rest = tail;
+                }
+                [TokenTree::Token(name), TokenTree::Token(colon), TokenTree::Token(fragment), tail @ ..]
+                    if colon.kind == token::Colon && name.is_ident() && fragment.is_ident() =>
+                {
+                    out.push(MacroMatcher::MetaVar {
+                        name: pprust::token_to_string(name).into_owned(),
+                        fragment: pprust::token_to_string(fragment).into_owned(),
+                    });
+                    rest = tail;
+                }
+                _ => out.push(MacroMatcher::Token(pprust::token_to_string(dollar).into_owned())),
+            },
+            TokenTree::Token(token) => {
+                out.push(MacroMatcher::Token(pprust::token_to_string(token).into_owned()))
+            }
+            // Invisible delimiters don't have to be written in the input.
+            TokenTree::Delimited(_, Delimiter::Invisible, inner) => {
+                out.extend(from_macro_matcher(inner))
+            }
+            TokenTree::Delimited(_, delim, inner) => {
+                let delimiter = match delim {
+                    Delimiter::Parenthesis => MacroDelimiter::Parenthesis,
+                    Delimiter::Bracket => MacroDelimiter::Bracket,
+                    Delimiter::Brace => MacroDelimiter::Brace,
+                    Delimiter::Invisible => unreachable!(),
+                };
+                out.push(MacroMatcher::Delimited { delimiter, tokens: from_macro_matcher(inner) });
+            }
+        }
+    }
+    out
+}
+
+/// Splits the optional separator and the Kleene operator which follow a `$( ... )` repetition off
+/// the remaining tokens of the matcher.
+fn from_repetition_op(tts: &[TokenTree]) -> (Option<String>, MacroKleeneOp, &[TokenTree]) {
+    if let [op, tail @ ..] = tts
+        && let Some(kleene) = from_kleene_op(op)
+    {
+        (None, kleene, tail)
+    } else if let [TokenTree::Token(separator), op, tail @ ..] = tts
+        && let Some(kleene) = from_kleene_op(op)
+    {
+        (Some(pprust::token_to_string(separator).into_owned()), kleene, tail)
+    } else {
+        // Rejected when the macro is defined, so this doesn't really matter.
+        (None, MacroKleeneOp::ZeroOrMore, tts)
+    }
+}
+
+fn from_kleene_op(tt: &TokenTree) -> Option<MacroKleeneOp> {
+    match tt {
+        TokenTree::Token(token) => match token.kind {
+            token::BinOp(token::Star) => Some(MacroKleeneOp::ZeroOrMore),
+            token::BinOp(token::Plus) => Some(MacroKleeneOp::OneOrMore),
+            token::Question => Some(MacroKleeneOp::ZeroOrOne),
+            _ => None,
+        },
+        TokenTree::Delimited(..) => None,
+    }
+}
+
 impl FromWithTcx<clean::ProcMacro> for ProcMacro {
     fn from_tcx(mac: clean::ProcMacro, _tcx: TyCtxt<'_>) -> Self {
         ProcMacro {
@@ -0,0 +1,86 @@
+//! Rendering with an external backend, for `--backend`.
+//!
+//! The crate is built the same way as for the JSON output, then passed to the backend program
+//! instead of being written to a file, so the backend gets the types, paths, constants and macros
+//! already printed by rustdoc.
+
+use std::fs::create_dir_all;
+use std::io::{BufWriter, Write};
+use std::path::PathBuf;
+use std::process::{Command, Stdio};
+
+use rustc_middle::ty::TyCtxt;
+
+use super::JsonRenderer;
+use crate::config::RenderOptions;
+use crate::docfs::PathError;
+use crate::error::Error;
+use crate::formats::cache::Cache;
+use crate::formats::FormatRenderer;
+use crate::{clean, try_err};
+
+#[derive(Clone)]
+pub(crate) struct ExternalRenderer<'tcx> {
+    json: JsonRenderer<'tcx>,
+    /// The backend program, which reads the crate from its standard input and renders it into
+    /// `out_path`, given as its only argument.
+    program: String,
+    out_path: PathBuf,
+}
+
+impl<'tcx> FormatRenderer<'tcx> for ExternalRenderer<'tcx> {
+    fn descr() -> &'static str {
+        "external"
+    }
+
+    const RUN_ON_MODULE: bool = JsonRenderer::RUN_ON_MODULE;
+
+    fn init(
+        krate: clean::Crate,
+        options: RenderOptions,
+        cache: Cache,
+        tcx: TyCtxt<'tcx>,
+    ) -> Result<(Self, clean::Crate), Error> {
+        let program = options.backend.clone().expect("the external renderer needs `--backend`");
+        let out_path = options.output.clone();
+        let (json, krate) = JsonRenderer::init(krate, options, cache, tcx)?;
+        Ok((ExternalRenderer { json, program, out_path }, krate))
+    }
+
+    fn make_child_renderer(&self) -> Self {
+        self.clone()
+    }
+
+    fn item(&mut self, item: clean::Item) -> Result<(), Error> {
+        self.json.item(item)
+    }
+
+    fn mod_item_in(&mut self, item: &clean::Item) -> Result<(), Error> {
+        self.json.mod_item_in(item)
+    }
+
+    fn after_krate(&mut self) -> Result<(), Error> {
+        let krate = self.json.build_crate();
+        try_err!(create_dir_all(&self.out_path), self.out_path);
+
+        let program = &self.program;
+        let mut child = try_err!(
+            Command::new(program).arg(&self.out_path).stdin(Stdio::piped()).spawn(),
+            program
+        );
+        let mut stdin = BufWriter::new(child.stdin.take().expect("stdin is piped"));
+        try_err!(serde_json::ser::to_writer(&mut stdin, &krate), program);
+        try_err!(stdin.flush(), program);
+        // Close the pipe so that the backend sees the end of its input.
+        drop(stdin);
+        let status = try_err!(child.wait(), program);
+        if !status.success() {
+            return Err(Error::new(format!("the backend failed ({})", status), program));
+        }
+        Ok(())
+    }
+
+    fn cache(&self) -> &Cache {
+        self.json.cache()
+    }
+}
@@ -5,6 +5,7 @@
 //! docs for usage and details.
 
 mod conversions;
+mod external;
 
 use std::cell::RefCell;
 use std::fs::{create_dir_all, File};
@@ -12,10 +13,9 @@
 use std::path::PathBuf;
 use std::rc::Rc;
 
-use rustc_data_structures::fx::FxHashMap;
+use rustc_data_structures::fx::{FxHashMap, FxHashSet};
 use rustc_hir::def_id::DefId;
 use rustc_middle::ty::TyCtxt;
-use rustc_session::Session;
 use rustc_span::def_id::LOCAL_CRATE;
 
 use rustdoc_json_types as types;
@@ -26,7 +26,7 @@
 use crate::error::Error;
 use crate::formats::cache::Cache;
 use crate::formats::FormatRenderer;
-use crate::json::conversions::{from_item_id, from_item_id_with_name, IntoWithTcx};
+use crate::json::conversions::{deref_target, from_item_id, from_item_id_with_name, IntoWithTcx};
 use crate::{clean, try_err};
 
 #[derive(Clone)]
@@ -40,11 +40,9 @@ pub(crate) struct JsonRenderer<'tcx> {
     cache: Rc<Cache>,
 }
 
-impl<'tcx> JsonRenderer<'tcx> {
-    fn sess(&self) -> &'tcx Session {
-        self.tcx.sess
-    }
+pub(crate) use external::ExternalRenderer;
 
+impl<'tcx> JsonRenderer<'tcx> {
     fn get_trait_implementors(&mut self, id: DefId) -> Vec<types::Id> {
         Rc::clone(&self.cache)
             .implementors
@@ -97,6 +95,37 @@ fn get_impls(&mut self, id: DefId) -> Vec<types::Id> {
             .unwrap_or_default()
     }
 
+    /// Gets the inherent impls whose methods can be called through the `Deref` impl `impl_`,
+    /// following the `Deref` impls of its target as well.
+    fn get_deref_impls(&mut self, impl_: &clean::Impl) -> Vec<types::Id> {
+        let cache = Rc::clone(&self.cache);
+        let mut visited = FxHashSet::default();
+        let mut ids = Vec::new();
+        let mut target = deref_target(impl_, self.tcx).cloned();
+        while let Some(ty) = target.take() {
+            let did = ty.def_id(&cache).or_else(|| {
+                ty.primitive_type().and_then(|prim| cache.primitive_locations.get(&prim).copied())
+            });
+            // Avoid infinite cycles, e.g. `impl Deref<Target = S> for S`.
+            let Some(did) = did.filter(|&did| visited.insert(did)) else { break };
+            for i in cache.impls.get(&did).into_iter().flatten() {
+                let inner = i.inner_impl();
+                if inner.trait_.is_none() {
+                    // Like in `get_impls`, only the local and primitive impls are in the index.
+                    let item = &i.impl_item;
+                    let is_primitive_impl = matches!(inner.for_, clean::types::Type::Primitive(_));
+                    if item.item_id.is_local() || is_primitive_impl {
+                        self.item(item.clone()).unwrap();
+                        ids.push(from_item_id_with_name(item.item_id, self.tcx, item.name));
+                    }
+                } else if let Some(next) = deref_target(inner, self.tcx) {
+                    target = Some(next.clone());
+                }
+            }
+        }
+        ids
+    }
+
     fn get_trait_items(&mut self) -> Vec<(types::Id, types::Item)> {
         Rc::clone(&self.cache)
             .traits
@@ -180,6 +209,10 @@ fn item(&mut self, item: clean::Item) -> Result<(), Error> {
 
         let name = item.name;
         let item_id = item.item_id;
+        let deref_impls = match *item.kind {
+            clean::ImplItem(ref impl_) => self.get_deref_impls(impl_),
+            _ => Vec::new(),
+        };
         if let Some(mut new_item) = self.convert_item(item) {
             let can_be_ignored = match new_item.inner {
                 types::ItemEnum::Trait(ref mut t) => {
@@ -198,6 +231,10 @@ fn item(&mut self, item: clean::Item) -> Result<(), Error> {
                     u.impls = self.get_impls(item_id.expect_def_id());
                     false
                 }
+                types::ItemEnum::Impl(ref mut i) => {
+                    i.deref_impls = deref_impls;
+                    false
+                }
 
                 types::ItemEnum::Method(_)
                 | types::ItemEnum::AssocConst { .. }
@@ -210,7 +247,6 @@ fn item(&mut self, item: clean::Item) -> Result<(), Error> {
                 | types::ItemEnum::Variant(_)
                 | types::ItemEnum::Function(_)
                 | types::ItemEnum::TraitAlias(_)
-                | types::ItemEnum::Impl(_)
                 | types::ItemEnum::Typedef(_)
                 | types::ItemEnum::OpaqueTy(_)
                 | types::ItemEnum::Constant(_)
@@ -246,6 +282,31 @@ fn mod_item_in(&mut self, _item: &clean::Item) -> Result<(), Error> {
     fn after_krate(&mut self) -> Result<(), Error> {
         debug!("Done with crate");
 
+        let output = self.build_crate();
+        let out_dir = self.out_path.clone();
+        try_err!(create_dir_all(&out_dir), out_dir);
+
+        let mut p = out_dir;
+        p.push(output.index.get(&output.root).unwrap().name.clone().unwrap());
+        p.set_extension("json");
+        let mut file = BufWriter::new(try_err!(File::create(&p), p));
+        serde_json::ser::to_writer(&mut file, &output).unwrap();
+        try_err!(file.flush(), p);
+
+        Ok(())
+    }
+
+    fn cache(&self) -> &Cache {
+        &self.cache
+    }
+}
+
+impl<'tcx> JsonRenderer<'tcx> {
+    /// Builds the JSON crate from the items rendered so far, once they all were.
+    // This needs to be the default HashMap for compatibility with the public interface for
+    // rustdoc-json-types
+    #[allow(rustc::default_hash_types)]
+    fn build_crate(&mut self) -> types::Crate {
         for primitive in Rc::clone(&self.cache).primitive_locations.values() {
             self.get_impls(*primitive);
         }
@@ -254,11 +315,8 @@ fn after_krate(&mut self) -> Result<(), Error> {
 
         let mut index = (*self.index).clone().into_inner();
         index.extend(self.get_trait_items());
-        // This needs to be the default HashMap for compatibility with the public interface for
-        // rustdoc-json-types
-        #[allow(rustc::default_hash_types)]
-        let output = types::Crate {
-            root: types::Id(format!("0:0:{}", e.name(self.tcx).as_u32())),
+        types::Crate {
+            root: from_item_id_with_name(e.def_id().into(), self.tcx, Some(e.name(self.tcx))),
             crate_version: self.cache.crate_version.clone(),
             includes_private: self.cache.document_private,
             index: index.into_iter().collect(),
@@ -298,21 +356,6 @@ fn after_krate(&mut self) -> Result<(), Error> {
                 })
                 .collect(),
             format_version: types::FORMAT_VERSION,
-        };
-        let out_dir = self.out_path.clone();
-        try_err!(create_dir_all(&out_dir), out_dir);
-
-        let mut p = out_dir;
-        p.push(output.index.get(&output.root).unwrap().name.clone().unwrap());
-        p.set_extension("json");
-        let mut file = BufWriter::new(try_err!(File::create(&p), p));
-        serde_json::ser::to_writer(&mut file, &output).unwrap();
-        try_err!(file.flush(), p);
-
-        Ok(())
-    }
-
-    fn cache(&self) -> &Cache {
-        &self.cache
+        }
     }
 }
@@ -19,6 +19,7 @@
 #![feature(iter_intersperse)]
 #![feature(type_alias_impl_trait)]
 #![feature(generic_associated_types)]
+#![feature(min_specialization)]
 #![recursion_limit = "256"]
 #![warn(rustc::internal)]
 #![allow(clippy::collapsible_if, clippy::collapsible_else_if)]
@@ -74,6 +75,8 @@
 use std::default::Default;
 use std::env::{self, VarError};
 use std::io;
+use std::iter;
+use std::mem;
 use std::process;
 
 use rustc_driver::{abort_on_err, describe_lints};
@@ -84,7 +87,7 @@
 use rustc_session::getopts;
 use rustc_session::{early_error, early_warn};
 
-use crate::clean::utils::DOC_RUST_LANG_ORG_CHANNEL;
+use crate::clean::utils::doc_rust_lang_org_channel;
 use crate::passes::collect_intra_doc_links;
 
 /// A macro to create a FxHashMap.
@@ -108,6 +111,7 @@ macro_rules! map {
 mod clean;
 mod config;
 mod core;
+mod dependency_graph;
 mod docfs;
 mod doctest;
 mod error;
@@ -122,6 +126,7 @@ macro_rules! map {
 mod passes;
 mod scrape_examples;
 mod theme;
+mod trait_cache;
 mod visit;
 mod visit_ast;
 mod visit_lib;
@@ -396,6 +401,14 @@ fn opts() -> Vec<RustcOptGroup> {
                 "sort modules by where they appear in the program, rather than alphabetically",
             )
         }),
+        unstable("item-order", |o| {
+            o.optopt(
+                "",
+                "item-order",
+                "order of the items on module pages, overrides `#![doc(item_order)]`",
+                "alphabetical|source",
+            )
+        }),
         stable("default-theme", |o| {
             o.optopt(
                 "",
@@ -521,6 +534,13 @@ fn opts() -> Vec<RustcOptGroup> {
                 "calculate percentage of public items with documentation",
             )
         }),
+        unstable("report-hidden", |o| {
+            o.optflagmulti(
+                "",
+                "report-hidden",
+                "list the public items hidden from the documentation and why, as JSON",
+            )
+        }),
         unstable("enable-per-target-ignores", |o| {
             o.optflagmulti(
                 "",
@@ -569,6 +589,117 @@ fn opts() -> Vec<RustcOptGroup> {
         unstable("show-type-layout", |o| {
             o.optflagmulti("", "show-type-layout", "Include the memory layout of types in the docs")
         }),
+        unstable("document-macro-bodies", |o| {
+            o.optflagmulti(
+                "",
+                "document-macro-bodies",
+                "Include the body of each macro arm in the docs",
+            )
+        }),
+        unstable("number-format", |o| {
+            o.optmulti(
+                "",
+                "number-format",
+                "Comma separated list of options for the numbers in the values of constants",
+                "[separators,no-separators,decimal,exponent]",
+            )
+        }),
+        unstable("expand-trait-aliases", |o| {
+            o.optflagmulti(
+                "",
+                "expand-trait-aliases",
+                "Show what the trait aliases used in bounds expand to",
+            )
+        }),
+        unstable("auto-cfg", |o| {
+            o.optflagmulti(
+                "",
+                "auto-cfg",
+                "Show the `#[cfg]` requirements of items along with their `#[doc(cfg)]` ones",
+            )
+        }),
+        unstable("inline-dependency-traits", |o| {
+            o.optflagmulti(
+                "",
+                "inline-dependency-traits",
+                "Generate pages for the traits of dependencies implemented in the documentation",
+            )
+        }),
+        unstable("crate-stats", |o| {
+            o.optflagmulti(
+                "",
+                "crate-stats",
+                "Write statistics about the items of the crate to stats.json and stats.html",
+            )
+        }),
+        unstable("dependency-graph", |o| {
+            o.optopt(
+                "",
+                "dependency-graph",
+                "Write a graph of the items the documented items use and re-export to \
+                 CRATE.dependencies.dot or CRATE.dependencies.json",
+                "dot|json",
+            )
+        }),
+        unstable("write-index-part", |o| {
+            o.optflagmulti(
+                "",
+                "write-index-part",
+                "Write the entries of the crate in the search index and the other files shared \
+                 by the crates of the output directory to index-parts/CRATE.json",
+            )
+        }),
+        unstable("merge-index-parts", |o| {
+            o.optmulti(
+                "",
+                "merge-index-parts",
+                "Copy the documentation in the given directories to --out-dir, and merge their \
+                 search indexes and other shared files from their index parts",
+                "DIR",
+            )
+        }),
+        unstable("extern-trait-cache", |o| {
+            o.optopt(
+                "",
+                "extern-trait-cache",
+                "Keep the traits of dependencies cleaned for the documentation in this directory, \
+                 to reuse them in the next runs while the dependencies don't change",
+                "DIR",
+            )
+        }),
+        unstable("auto-trait-fuel", |o| {
+            o.optopt(
+                "",
+                "auto-trait-fuel",
+                "Steps after which to stop finding the auto trait impls of an item, 0 for no limit",
+                "STEPS",
+            )
+        }),
+        unstable("rust-doc-base-url", |o| {
+            o.optopt(
+                "",
+                "rust-doc-base-url",
+                "base URL of the Rust documentation, used instead of doc.rust-lang.org",
+                "URL",
+            )
+        }),
+        unstable("mask-crate", |o| {
+            o.optmulti(
+                "",
+                "mask-crate",
+                "hide trait implementations from this crate, like `#[doc(masked)]` does",
+                "NAME",
+            )
+        }),
+        unstable("backend", |o| {
+            o.optopt(
+                "",
+                "backend",
+                "pass the JSON output to this program, which renders the documentation, \
+                 instead of writing it to a file",
+                "PROGRAM",
+            )
+        }),
         unstable("nocapture", |o| {
             o.optflag("", "nocapture", "Don't capture stdout and stderr of tests")
         }),
@@ -663,7 +794,7 @@ fn usage(argv0: &str) {
     println!("    @path               Read newline separated options from `path`\n");
     println!(
         "More information available at {}/rustdoc/what-is-rustdoc.html",
-        DOC_RUST_LANG_ORG_CHANNEL
+        doc_rust_lang_org_channel()
     );
 }
 
@@ -696,11 +827,50 @@ fn main_args(at_args: &[String]) -> MainResult {
             };
         }
     };
-    rustc_interface::util::run_in_thread_pool_with_globals(
-        options.edition,
-        1, // this runs single-threaded, even in a parallel compiler
-        move || main_options(options),
-    )
+    if let Some(url) = matches.opt_str("rust-doc-base-url") {
+        clean::utils::set_doc_rust_lang_org_channel(&url);
+    }
+    if !options.other_inputs.is_empty() {
+        return document_crate_roots(options);
+    }
+    run_main_options(options)
+}
+
+fn run_main_options(options: config::Options) -> MainResult {
+    // Rustdoc itself runs on a single thread of the pool, the other ones are only used by the
+    // parts of the cleaning that only need the `TyCtxt`, like checking blanket impls.
+    let threads = options.debugging_opts.threads;
+    rustc_interface::util::run_in_thread_pool_with_globals(options.edition, threads, move || {
+        main_options(options)
+    })
+}
+
+/// Documents the crate roots given on the command line one after the other, in the same output
+/// directory. Each of them gets its own compiler session, but the traits of the dependencies
+/// cleaned for one are kept in the `--extern-trait-cache` directory, or a temporary one, so that
+/// the next roots don't clean them again.
+fn document_crate_roots(mut options: config::Options) -> MainResult {
+    let inputs: Vec<_> = iter::once(mem::take(&mut options.input))
+        .chain(mem::take(&mut options.other_inputs))
+        .collect();
+    let _trait_cache_dir = if options.render_options.extern_trait_cache.is_none() {
+        let dir = match tempfile::Builder::new().prefix("rustdoc-traits").tempdir() {
+            Ok(dir) => dir,
+            Err(err) => {
+                let diag = core::new_handler(options.error_format, None, &options.debugging_opts);
+                let msg = format!("failed to create a directory for the shared traits: {}", err);
+                return wrap_return(&diag, Err(msg));
+            }
+        };
+        options.render_options.extern_trait_cache = Some(dir.path().to_path_buf());
+        Some(dir)
+    } else {
+        None
+    };
+    for input in inputs {
+        run_main_options(config::Options { input, ..options.clone() })?;
+    }
+    Ok(())
 }
 
 fn wrap_return(diag: &rustc_errors::Handler, res: Result<(), String>) -> MainResult {
@@ -736,6 +906,18 @@ fn run_renderer<'tcx, T: formats::FormatRenderer<'tcx>>(
 fn main_options(options: config::Options) -> MainResult {
     let diag = core::new_handler(options.error_format, None, &options.debugging_opts);
 
+    if !options.render_options.merge_index_parts.is_empty() {
+        return wrap_return(
+            &diag,
+            html::render::merge_index_parts(
+                &options.render_options.merge_index_parts,
+                &options.output,
+                &options.render_options.resource_suffix,
+            )
+            .map_err(|err| format!("failed to merge the index parts: {}", err)),
+        );
+    }
+
     match (options.should_test, options.markdown_input()) {
         (true, true) => return wrap_return(&diag, markdown::test(options)),
         (true, false) => return doctest::run(options),
@@ -751,6 +933,7 @@ fn main_options(options: config::Options) -> MainResult {
     // need to move these items separately because we lose them by the time the closure is called,
     // but we can't create the Handler ahead of time because it's not Send
     let show_coverage = options.show_coverage;
+    let report_hidden = options.report_hidden;
     let run_check = options.run_check;
 
     // First, parse the crate and extract all relevant information.
@@ -812,6 +995,7 @@ fn main_options(options: config::Options) -> MainResult {
                         resolver,
                         resolver_caches,
                         show_coverage,
+                        report_hidden,
                         render_options,
                         output_format,
                     )
@@ -824,9 +1008,10 @@ fn main_options(options: config::Options) -> MainResult {
 
                 cache.crate_version = crate_version;
 
-                if show_coverage {
-                    // if we ran coverage, bail early, we don't need to also generate docs at this point
-                    // (also we didn't load in any of the useful passes)
+                if show_coverage || report_hidden {
+                    // if we ran coverage or listed the hidden items, bail early, we don't need to
+                    // also generate docs at this point (also we didn't load in any of the useful
+                    // passes)
                     return Ok(());
                 } else if run_check {
                     // Since we're in "check" mode, no need to generate anything beyond this point.
@@ -838,6 +1023,16 @@ fn main_options(options: config::Options) -> MainResult {
                     config::OutputFormat::Html => sess.time("render_html", || {
                         run_renderer::<html::render::Context<'_>>(krate, render_opts, cache, tcx)
                     }),
+                    config::OutputFormat::Json if render_opts.backend.is_some() => {
+                        sess.time("render_external", || {
+                            run_renderer::<json::ExternalRenderer<'_>>(
+                                krate,
+                                render_opts,
+                                cache,
+                                tcx,
+                            )
+                        })
+                    }
                     config::OutputFormat::Json => sess.time("render_json", || {
                         run_renderer::<json::JsonRenderer<'_>>(krate, render_opts, cache, tcx)
                     }),
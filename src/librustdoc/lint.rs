@@ -169,6 +169,28 @@ macro_rules! declare_rustdoc_lint {
    "codeblock could not be parsed as valid Rust or is empty"
 }
 
+declare_rustdoc_lint! {
+    /// The `unused_doc_masked` lint detects `#[doc(masked)]` attributes that
+    /// have no effect. This is a `rustdoc` only lint, see the documentation in
+    /// the [rustdoc book].
+    ///
+    /// [rustdoc book]: ../../../rustdoc/lints.html#unused_doc_masked
+    UNUSED_DOC_MASKED,
+    Warn,
+    "detects `#[doc(masked)]` attributes that have no effect"
+}
+
+declare_rustdoc_lint! {
+    /// The `masked_impls` lint detects implementations of the current crate
+    /// left out of the documentation because of `#[doc(masked)]`. This is a
+    /// `rustdoc` only lint, see the documentation in the [rustdoc book].
+    ///
+    /// [rustdoc book]: ../../../rustdoc/lints.html#masked_impls
+    MASKED_IMPLS,
+    Warn,
+    "detects implementations hidden by `#[doc(masked)]`"
+}
+
 pub(crate) static RUSTDOC_LINTS: Lazy<Vec<&'static Lint>> = Lazy::new(|| {
     vec![
         BROKEN_INTRA_DOC_LINKS,
@@ -180,6 +202,8 @@ macro_rules! declare_rustdoc_lint {
         INVALID_HTML_TAGS,
         BARE_URLS,
         MISSING_CRATE_LEVEL_DOCS,
+        UNUSED_DOC_MASKED,
+        MASKED_IMPLS,
     ]
 });
 
@@ -1,6 +1,7 @@
 //! Calculates information used for the --show-coverage flag.
 use crate::clean;
 use crate::core::DocContext;
+use crate::formats::item_type::ItemType;
 use crate::html::markdown::{find_testable_code, ErrorCodes};
 use crate::passes::check_doc_test_visibility::{should_have_doc_example, Tests};
 use crate::passes::Pass;
@@ -101,8 +102,27 @@ fn add_assign(&mut self, rhs: Self) {
     }
 }
 
+/// The coverage of a single file, with a breakdown per kind of item.
+#[derive(Default, Serialize, Debug)]
+struct FileCount {
+    #[serde(flatten)]
+    count: ItemCount,
+    kinds: BTreeMap<&'static str, ItemCount>,
+}
+
+impl FileCount {
+    /// Adds the count of an item of the given kind to the breakdown, and to the totals of the file
+    /// if `in_total` is set.
+    fn add(&mut self, kind: ItemType, count: ItemCount, in_total: bool) {
+        if in_total {
+            self.count += count;
+        }
+        *self.kinds.entry(kind.as_str()).or_default() += count;
+    }
+}
+
 struct CoverageCalculator<'a, 'b> {
-    items: BTreeMap<FileName, ItemCount>,
+    items: BTreeMap<FileName, FileCount>,
     ctx: &'a mut DocContext<'b>,
 }
 
@@ -117,13 +137,30 @@ fn limit_filename_len(filename: String) -> String {
 }
 
 impl<'a, 'b> CoverageCalculator<'a, 'b> {
+    /// Returns the file `i` is in, whether it has docs, and whether those contain an example.
+    fn docs_of(&self, i: &clean::Item) -> (FileName, bool, bool) {
+        let has_docs = !i.attrs.doc_strings.is_empty();
+        let mut tests = Tests { found_tests: 0 };
+
+        find_testable_code(
+            &i.attrs.collapsed_doc_value().unwrap_or_default(),
+            &mut tests,
+            ErrorCodes::No,
+            false,
+            None,
+        );
+
+        let filename = i.span(self.ctx.tcx).filename(self.ctx.sess());
+        (filename, has_docs, tests.found_tests != 0)
+    }
+
     fn to_json(&self) -> String {
         serde_json::to_string(
             &self
                 .items
                 .iter()
                 .map(|(k, v)| (k.prefer_local().to_string(), v))
-                .collect::<BTreeMap<String, &ItemCount>>(),
+                .collect::<BTreeMap<String, &FileCount>>(),
         )
         .expect("failed to convert JSON data to string")
     }
@@ -159,7 +196,7 @@ fn print_table_record(
         );
         print_table_line();
 
-        for (file, &count) in &self.items {
+        for (file, &FileCount { count, .. }) in &self.items {
             if let Some(percentage) = count.percentage() {
                 print_table_record(
                     &limit_filename_len(file.prefer_local().to_string_lossy().into()),
@@ -201,22 +238,19 @@ fn visit_item(&mut self, i: &clean::Item) {
             clean::ImportItem(..) | clean::ExternCrateItem { .. } => {}
             // Don't count trait impls, the missing-docs lint doesn't so we shouldn't either.
             // Inherent impls *can* be documented, and those docs show up, but in most cases it
-            // doesn't make sense, as all methods on a type are in one single impl block
-            clean::ImplItem(_) => {}
+            // doesn't make sense, as all methods on a type are in one single impl block, so
+            // they're only counted in the breakdown of the file.
+            clean::ImplItem(ref impl_) => {
+                if impl_.trait_.is_none() && i.item_id.as_def_id().is_some() {
+                    let (filename, has_docs, has_doc_example) = self.docs_of(i);
+                    let mut count = ItemCount::default();
+                    count.count_item(has_docs, has_doc_example, false, true);
+                    debug!("counting impl in {:?}", filename);
+                    self.items.entry(filename).or_default().add(ItemType::Impl, count, false);
+                }
+            }
             _ => {
-                let has_docs = !i.attrs.doc_strings.is_empty();
-                let mut tests = Tests { found_tests: 0 };
-
-                find_testable_code(
-                    &i.attrs.collapsed_doc_value().unwrap_or_default(),
-                    &mut tests,
-                    ErrorCodes::No,
-                    false,
-                    None,
-                );
-
-                let filename = i.span(self.ctx.tcx).filename(self.ctx.sess());
-                let has_doc_example = tests.found_tests != 0;
+                let (filename, has_docs, has_doc_example) = self.docs_of(i);
                 // The `expect_def_id()` should be okay because `local_def_id_to_hir_id`
                 // would presumably panic if a fake `DefIndex` were passed.
                 let hir_id = self
@@ -262,12 +296,14 @@ fn visit_item(&mut self, i: &clean::Item) {
                     && (level != lint::Level::Allow || matches!(source, LintLevelSource::Default));
 
                 debug!("counting {:?} {:?} in {:?}", i.type_(), i.name, filename);
-                self.items.entry(filename).or_default().count_item(
+                let mut count = ItemCount::default();
+                count.count_item(
                     has_docs,
                     has_doc_example,
                     should_have_doc_example(self.ctx, i),
                     should_have_docs,
                 );
+                self.items.entry(filename).or_default().add(i.type_(), count, true);
             }
         }
 
@@ -0,0 +1,101 @@
+//! Detects `#[doc(masked)]` attributes that have no effect, and implementations of the current
+//! crate left out of the documentation because of one.
+use super::Pass;
+use crate::clean;
+use crate::core::DocContext;
+use crate::visit::DocVisitor;
+use rustc_data_structures::fx::FxHashSet;
+use rustc_hir::def::DefKind;
+use rustc_hir::def_id::{CrateNum, LocalDefId, CRATE_DEF_ID};
+use rustc_span::symbol::sym;
+
+pub(crate) const CHECK_DOC_MASKED: Pass = Pass {
+    name: "check-doc-masked",
+    run: check_doc_masked,
+    description: "detects `#[doc(masked)]` attributes that have no effect",
+};
+
+struct MaskedImplsLinter<'a, 'tcx> {
+    cx: &'a mut DocContext<'tcx>,
+    /// The masked crates which hid at least one implementation.
+    used: FxHashSet<CrateNum>,
+}
+
+pub(crate) fn check_doc_masked(krate: clean::Crate, cx: &mut DocContext<'_>) -> clean::Crate {
+    let mut linter = MaskedImplsLinter { cx, used: FxHashSet::default() };
+    // This runs after the implementations of private items were stripped, so the ones left would
+    // otherwise be shown.
+    linter.visit_crate(&krate);
+    let MaskedImplsLinter { cx, used } = linter;
+    check_masked_attrs(cx, &used);
+    krate
+}
+
+impl<'a, 'tcx> DocVisitor for MaskedImplsLinter<'a, 'tcx> {
+    fn visit_item(&mut self, item: &clean::Item) {
+        if let Some(krate) = self.cx.cache.masking_crate(item) {
+            self.used.insert(krate);
+            if let Some(hir_id) = DocContext::as_local_hir_id(self.cx.tcx, item.item_id) {
+                let tcx = self.cx.tcx;
+                let sp = item.span(tcx).inner();
+                tcx.struct_span_lint_hir(crate::lint::MASKED_IMPLS, hir_id, sp, |lint| {
+                    lint.build("this implementation is hidden by `#[doc(masked)]`")
+                        .note(&format!(
+                            "implementations involving items of `{}` are not documented",
+                            tcx.crate_name(krate),
+                        ))
+                        .emit();
+                });
+            }
+        }
+        self.visit_item_recur(item);
+    }
+}
+
+/// `krate()` only looks for `#[doc(masked)]` on the `extern crate` items at the root of the crate,
+/// so it's ignored anywhere else.
+fn check_masked_attrs(cx: &DocContext<'_>, used: &FxHashSet<CrateNum>) {
+    let tcx = cx.tcx;
+    let crate_items = tcx.hir_crate_items(());
+    let def_ids = crate_items
+        .items()
+        .map(|id| id.def_id)
+        .chain(crate_items.trait_items().map(|id| id.def_id))
+        .chain(crate_items.impl_items().map(|id| id.def_id))
+        .chain(crate_items.foreign_items().map(|id| id.def_id));
+    for def_id in def_ids {
+        let hir_id = tcx.hir().local_def_id_to_hir_id(def_id);
+        let Some(attr) = tcx.hir().attrs(hir_id).iter().find(|attr| {
+            attr.has_name(sym::doc)
+                && attr
+                    .meta_item_list()
+                    .map_or(false, |list| list.iter().any(|meta| meta.has_name(sym::masked)))
+        }) else {
+            continue;
+        };
+        let Some(note) = unused_masked_note(cx, def_id, used) else { continue };
+        tcx.struct_span_lint_hir(crate::lint::UNUSED_DOC_MASKED, hir_id, attr.span, |lint| {
+            lint.build("`#[doc(masked)]` has no effect").note(&note).emit();
+        });
+    }
+}
+
+/// Explains why `#[doc(masked)]` has no effect on the given item, if that's the case.
+fn unused_masked_note(
+    cx: &DocContext<'_>,
+    def_id: LocalDefId,
+    used: &FxHashSet<CrateNum>,
+) -> Option<String> {
+    let tcx = cx.tcx;
+    if tcx.def_kind(def_id) != DefKind::ExternCrate {
+        return Some("only `extern crate` items can be masked".to_owned());
+    }
+    if tcx.parent_module_from_def_id(def_id) != CRATE_DEF_ID {
+        return Some("only `extern crate` items at the root of the crate can be masked".to_owned());
+    }
+    let krate = tcx.extern_mod_stmt_cnum(def_id)?;
+    if used.contains(&krate) {
+        return None;
+    }
+    Some(format!("no documented implementation involves items of `{}`", tcx.crate_name(krate)))
+}
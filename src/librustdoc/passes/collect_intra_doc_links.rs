@@ -24,7 +24,7 @@
 use std::ops::Range;
 
 use crate::clean::{self, utils::find_nearest_parent_module};
-use crate::clean::{Crate, Item, ItemId, ItemLink, PrimitiveType};
+use crate::clean::{Crate, ExternalCrate, Item, ItemId, ItemLink, PrimitiveType};
 use crate::core::DocContext;
 use crate::html::markdown::{markdown_links, MarkdownLink};
 use crate::lint::{BROKEN_INTRA_DOC_LINKS, PRIVATE_INTRA_DOC_LINKS};
@@ -41,8 +41,7 @@
 };
 
 fn collect_intra_doc_links(krate: Crate, cx: &mut DocContext<'_>) -> Crate {
-    let mut collector =
-        LinkCollector { cx, mod_ids: Vec::new(), visited_links: FxHashMap::default() };
+    let mut collector = LinkCollector { cx, visited_links: FxHashMap::default() };
     collector.visit_crate(&krate);
     krate
 }
@@ -279,11 +278,6 @@ struct DiagnosticInfo<'a> {
 
 struct LinkCollector<'a, 'tcx> {
     cx: &'a mut DocContext<'tcx>,
-    /// A stack of modules used to decide what scope to resolve in.
-    ///
-    /// The last module will be used if the parent scope of the current item is
-    /// unknown.
-    mod_ids: Vec<DefId>,
     /// Cache the resolved links so we can avoid resolving (and emitting errors for) the same link.
     /// The link will be `None` if it could not be resolved (i.e. the error was cached).
     visited_links: FxHashMap<ResolutionInfo, Option<(Res, Option<UrlFragment>)>>,
@@ -831,11 +825,14 @@ fn visit_item(&mut self, item: &Item) {
             trace!("got parent node for {:?} {:?}, id {:?}", item.type_(), item.name, item.item_id);
         }
 
-        let inner_docs = item.inner_docs(self.cx.tcx);
-
-        if item.is_mod() && inner_docs {
-            self.mod_ids.push(item.item_id.expect_def_id());
-        }
+        // If the documentation of this module came from an inner comment (`//!`), we anchor our
+        // name resolution *inside* the module. Otherwise, we anchor it in the parent module, on
+        // the basis that the names used are more likely to be intended to be parent names.
+        let module_id = if item.is_mod() && item.inner_docs(self.cx.tcx) {
+            item.item_id.as_def_id()
+        } else {
+            parent_node
+        };
 
         // We want to resolve in the lexical scope of the documentation.
         // In the presence of re-exports, this is not the same as the module of the item.
@@ -848,7 +845,9 @@ fn visit_item(&mut self, item: &Item) {
             debug!("combined_docs={}", doc);
             // NOTE: if there are links that start in one crate and end in another, this will not resolve them.
             // This is a degenerate case and it's not supported by rustdoc.
-            let parent_node = parent_module.or(parent_node);
+            // Docs added on a re-export are resolved in the module of the `use`, regardless of
+            // where the re-exported item lives or whether its own docs are inner ones.
+            let module_id = parent_module.or(module_id).expect("doc link without parent module");
             let mut tmp_links = self
                 .cx
                 .resolver_caches
@@ -859,7 +858,7 @@ fn visit_item(&mut self, item: &Item) {
                 tmp_links.insert(doc.clone(), preprocessed_markdown_links(&doc));
             }
             for md_link in &tmp_links[&doc] {
-                let link = self.resolve_link(item, &doc, parent_node, md_link);
+                let link = self.resolve_link(item, &doc, module_id, md_link);
                 if let Some(link) = link {
                     self.cx.cache.intra_doc_links.entry(item.item_id).or_default().push(link);
                 }
@@ -867,16 +866,7 @@ fn visit_item(&mut self, item: &Item) {
             self.cx.resolver_caches.markdown_links = Some(tmp_links);
         }
 
-        if item.is_mod() {
-            if !inner_docs {
-                self.mod_ids.push(item.item_id.expect_def_id());
-            }
-
-            self.visit_item_recur(item);
-            self.mod_ids.pop();
-        } else {
-            self.visit_item_recur(item)
-        }
+        self.visit_item_recur(item)
     }
 }
 
@@ -1012,7 +1002,7 @@ fn resolve_link(
         &mut self,
         item: &Item,
         dox: &str,
-        parent_node: Option<DefId>,
+        module_id: DefId,
         link: &PreprocessedMarkdownLink,
     ) -> Option<ItemLink> {
         let PreprocessedMarkdownLink(pp_link, ori_link) = link;
@@ -1029,21 +1019,9 @@ fn resolve_link(
             pp_link.as_ref().map_err(|err| err.report(self.cx, diag_info.clone())).ok()?;
         let disambiguator = *disambiguator;
 
-        // In order to correctly resolve intra-doc links we need to
-        // pick a base AST node to work from.  If the documentation for
-        // this module came from an inner comment (//!) then we anchor
-        // our name resolution *inside* the module.  If, on the other
-        // hand it was an outer comment (///) then we anchor the name
-        // resolution in the parent module on the basis that the names
-        // used are more likely to be intended to be parent names.  For
-        // this, we set base_node to None for inner comments since
-        // we've already pushed this node onto the resolution stack but
-        // for outer comments we explicitly try and resolve against the
-        // parent_node first.
-        let inner_docs = item.inner_docs(self.cx.tcx);
-        let base_node =
-            if item.is_mod() && inner_docs { self.mod_ids.last().copied() } else { parent_node };
-        let module_id = base_node.expect("doc link without parent module");
+        if disambiguator == Some(Disambiguator::Keyword) {
+            return self.resolve_keyword(path_str, extra_fragment, link_text, &diag_info);
+        }
 
         let (mut res, fragment) = self.resolve_with_disambiguator_cached(
             ResolutionInfo {
@@ -1182,7 +1160,7 @@ fn verify_disambiguator(
                 // All of these are valid, so do nothing
                 => {}
                 (actual, Some(Disambiguator::Kind(expected))) if actual == expected => {}
-                (_, Some(specified @ Disambiguator::Kind(_) | specified @ Disambiguator::Primitive)) => {
+                (_, Some(specified @ (Disambiguator::Kind(_) | Disambiguator::Primitive | Disambiguator::Keyword))) => {
                     self.report_disambiguator_mismatch(path_str,ori_link,specified, Res::Def(kind, id),diag_info);
                     return None;
                 }
@@ -1207,6 +1185,36 @@ fn verify_disambiguator(
         Some(())
     }
 
+    /// Resolves a `keyword@` link to the page documenting the keyword, which is usually in `std`.
+    fn resolve_keyword(
+        &self,
+        path_str: &str,
+        extra_fragment: &Option<String>,
+        link_text: &str,
+        diag_info: &DiagnosticInfo<'_>,
+    ) -> Option<ItemLink> {
+        let tcx = self.cx.tcx;
+        let Some(&did) = ExternalCrate::keyword_locations(tcx).get(&Symbol::intern(path_str))
+        else {
+            let msg = format!("unresolved link to `{}`", path_str);
+            report_diagnostic(tcx, BROKEN_INTRA_DOC_LINKS, &msg, diag_info, |diag, sp| {
+                let note = format!("no documentation found for the keyword `{}`", path_str);
+                if let Some(sp) = sp {
+                    diag.span_label(sp, &note);
+                } else {
+                    diag.note(&note);
+                }
+            });
+            return None;
+        };
+        Some(ItemLink {
+            link: diag_info.ori_link.to_owned(),
+            link_text: link_text.to_owned(),
+            did,
+            fragment: extra_fragment.clone().map(UrlFragment::UserWritten),
+        })
+    }
+
     fn report_disambiguator_mismatch(
         &self,
         path_str: &str,
@@ -1417,6 +1425,8 @@ enum Disambiguator {
     ///
     /// This is buggy, see <https://github.com/rust-lang/rust/pull/77875#discussion_r503583103>
     Primitive,
+    /// `keyword@`
+    Keyword,
     /// `struct@` or `f()`
     Kind(DefKind),
     /// `type@`
@@ -1430,7 +1440,7 @@ impl Disambiguator {
     /// `Ok(None)` if no disambiguator was found, or `Err(...)`
     /// if there was a problem with the disambiguator.
     fn from_str(link: &str) -> Result<Option<(Self, &str, &str)>, (String, Range<usize>)> {
-        use Disambiguator::{Kind, Namespace as NS, Primitive};
+        use Disambiguator::{Keyword, Kind, Namespace as NS, Primitive};
 
         if let Some(idx) = link.find('@') {
             let (prefix, rest) = link.split_at(idx);
@@ -1448,6 +1458,7 @@ fn from_str(link: &str) -> Result<Option<(Self, &str, &str)>, (String, Range<usi
                 "value" => NS(Namespace::ValueNS),
                 "macro" => NS(Namespace::MacroNS),
                 "prim" | "primitive" => Primitive,
+                "keyword" => Keyword,
                 _ => return Err((format!("unknown disambiguator `{}`", prefix), 0..idx)),
             };
             Ok(Some((d, &rest[1..], &rest[1..])))
@@ -1477,7 +1488,8 @@ fn ns(self) -> Namespace {
             Self::Kind(k) => {
                 k.ns().expect("only DefKinds with a valid namespace can be disambiguators")
             }
-            Self::Primitive => TypeNS,
+            // Keywords aren't resolved as paths, see `LinkCollector::resolve_keyword`.
+            Self::Primitive | Self::Keyword => TypeNS,
         }
     }
 
@@ -1485,7 +1497,7 @@ fn article(self) -> &'static str {
         match self {
             Self::Namespace(_) => panic!("article() doesn't make sense for namespaces"),
             Self::Kind(k) => k.article(),
-            Self::Primitive => "a",
+            Self::Primitive | Self::Keyword => "a",
         }
     }
 
@@ -1496,6 +1508,7 @@ fn descr(self) -> &'static str {
             // printing "module" vs "crate" so using the wrong ID is not a huge problem
             Self::Kind(k) => k.descr(CRATE_DEF_ID.to_def_id()),
             Self::Primitive => "builtin type",
+            Self::Keyword => "keyword",
         }
     }
 }
@@ -1891,7 +1904,7 @@ fn disambiguator_error(
     report_diagnostic(cx.tcx, BROKEN_INTRA_DOC_LINKS, msg, &diag_info, |diag, _sp| {
         let msg = format!(
             "see {}/rustdoc/linking-to-items-by-name.html#namespaces-and-disambiguators for more info about disambiguators",
-            crate::DOC_RUST_LANG_ORG_CHANNEL
+            crate::doc_rust_lang_org_channel()
         );
         diag.note(&msg);
     });
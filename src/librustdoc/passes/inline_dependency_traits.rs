@@ -0,0 +1,116 @@
+//! Generates pages for the traits of dependencies implemented in the documentation, for the
+//! `--inline-dependency-traits` flag.
+use super::Pass;
+use crate::clean::{self, inline};
+use crate::core::DocContext;
+use crate::visit::DocVisitor;
+use rustc_data_structures::fx::{FxHashSet, FxIndexMap, FxIndexSet};
+use rustc_hir::def::{DefKind, Res};
+use rustc_hir::def_id::{CrateNum, DefId};
+use rustc_span::symbol::sym;
+
+pub(crate) const INLINE_DEPENDENCY_TRAITS: Pass = Pass {
+    name: "inline-dependency-traits",
+    run: inline_dependency_traits,
+    description: "generates pages for the traits of dependencies implemented in the documentation",
+};
+
+pub(crate) fn inline_dependency_traits(
+    mut krate: clean::Crate,
+    cx: &mut DocContext<'_>,
+) -> clean::Crate {
+    if !cx.render_options.inline_dependency_traits {
+        return krate;
+    }
+
+    // This runs after the stripping passes, so only the traits of implementations which show up
+    // in the documentation are collected.
+    let mut collector =
+        TraitCollector { cx, documented: FxHashSet::default(), traits: FxIndexSet::default() };
+    collector.visit_crate(&krate);
+    let TraitCollector { cx, documented, traits } = collector;
+
+    let mut traits_by_crate = FxIndexMap::<CrateNum, Vec<DefId>>::default();
+    for did in traits.into_iter().filter(|did| !documented.contains(did)) {
+        traits_by_crate.entry(did.krate).or_default().push(did);
+    }
+
+    let tcx = cx.tcx;
+    let clean::ModuleItem(ref mut root) = *krate.module.kind else { unreachable!() };
+    for (cnum, traits) in traits_by_crate {
+        let crate_name = tcx.crate_name(cnum);
+        if root.items.iter().any(|item| item.name == Some(crate_name)) {
+            tcx.sess
+                .struct_warn(&format!(
+                    "not generating pages for the traits of `{}`, as an item of this crate has \
+                     the same name",
+                    crate_name,
+                ))
+                .emit();
+            continue;
+        }
+
+        // The traits are put in a module named after their crate, which documents that crate.
+        let crate_def_id = cnum.as_def_id();
+        let mut items = Vec::new();
+        let mut visited = FxHashSet::default();
+        for did in traits {
+            let res = Res::Def(DefKind::Trait, did);
+            let name = tcx.item_name(did);
+            // This also inlines the implementations from the crate of the trait, like for a
+            // re-exported trait.
+            items.extend(
+                inline::try_inline(cx, crate_def_id, None, res, name, None, &mut visited)
+                    .into_iter()
+                    .flatten(),
+            );
+        }
+        let span = clean::Span::new(tcx.def_span(crate_def_id));
+        let module = clean::ModuleItem(clean::Module { items, span });
+        root.items.push(clean::Item::from_def_id_and_parts(
+            crate_def_id,
+            Some(crate_name),
+            module,
+            cx,
+        ));
+    }
+    krate
+}
+
+struct TraitCollector<'a, 'tcx> {
+    cx: &'a mut DocContext<'tcx>,
+    /// The items already documented by this crate, including the re-exported ones.
+    documented: FxHashSet<DefId>,
+    /// The traits of dependencies implemented in the documentation, in the order they were found.
+    traits: FxIndexSet<DefId>,
+}
+
+impl<'a, 'tcx> DocVisitor for TraitCollector<'a, 'tcx> {
+    fn visit_item(&mut self, item: &clean::Item) {
+        match *item.kind {
+            clean::ImplItem(ref i) => {
+                if let Some(did) = i.trait_.as_ref().map(|t| t.def_id())
+                    && !did.is_local()
+                    && !is_standard_library(self.cx, did.krate)
+                    && !self.cx.cache.masked_crates.contains(&did.krate)
+                    && self.cx.cache.access_levels.is_public(did)
+                {
+                    self.traits.insert(did);
+                }
+            }
+            clean::TraitItem(_) => {
+                self.documented.extend(item.item_id.as_def_id());
+            }
+            _ => {}
+        }
+        self.visit_item_recur(item);
+    }
+}
+
+/// The standard library has its own documentation, which is installed along with it.
+fn is_standard_library(cx: &DocContext<'_>, krate: CrateNum) -> bool {
+    matches!(
+        cx.tcx.crate_name(krate),
+        sym::core | sym::alloc | sym::std | sym::proc_macro | sym::test
+    )
+}
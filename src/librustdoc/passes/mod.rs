@@ -42,9 +42,18 @@
 mod calculate_doc_coverage;
 pub(crate) use self::calculate_doc_coverage::CALCULATE_DOC_COVERAGE;
 
+mod report_hidden;
+pub(crate) use self::report_hidden::REPORT_HIDDEN;
+
 mod html_tags;
 pub(crate) use self::html_tags::CHECK_INVALID_HTML_TAGS;
 
+mod check_doc_masked;
+pub(crate) use self::check_doc_masked::CHECK_DOC_MASKED;
+
+mod inline_dependency_traits;
+pub(crate) use self::inline_dependency_traits::INLINE_DEPENDENCY_TRAITS;
+
 /// A single pass over the cleaned documentation.
 ///
 /// Runs in the compiler context, so it has access to types and traits and the like.
@@ -85,8 +94,11 @@ pub(crate) enum Condition {
     CHECK_CODE_BLOCK_SYNTAX,
     COLLECT_TRAIT_IMPLS,
     CALCULATE_DOC_COVERAGE,
+    REPORT_HIDDEN,
     CHECK_INVALID_HTML_TAGS,
     CHECK_BARE_URLS,
+    CHECK_DOC_MASKED,
+    INLINE_DEPENDENCY_TRAITS,
 ];
 
 /// The list of passes run by default.
@@ -96,6 +108,8 @@ pub(crate) enum Condition {
     ConditionalPass::new(STRIP_HIDDEN, WhenNotDocumentHidden),
     ConditionalPass::new(STRIP_PRIVATE, WhenNotDocumentPrivate),
     ConditionalPass::new(STRIP_PRIV_IMPORTS, WhenDocumentPrivate),
+    ConditionalPass::always(CHECK_DOC_MASKED),
+    ConditionalPass::always(INLINE_DEPENDENCY_TRAITS),
     ConditionalPass::always(COLLECT_INTRA_DOC_LINKS),
     ConditionalPass::always(CHECK_CODE_BLOCK_SYNTAX),
     ConditionalPass::always(CHECK_INVALID_HTML_TAGS),
@@ -110,6 +124,10 @@ pub(crate) enum Condition {
     ConditionalPass::always(CALCULATE_DOC_COVERAGE),
 ];
 
+/// The list of default passes run when `--report-hidden` is passed to rustdoc.
+pub(crate) const REPORT_HIDDEN_PASSES: &[ConditionalPass] =
+    &[ConditionalPass::always(COLLECT_TRAIT_IMPLS), ConditionalPass::always(REPORT_HIDDEN)];
+
 impl ConditionalPass {
     pub(crate) const fn always(pass: Pass) -> Self {
         Self::new(pass, Always)
@@ -121,8 +139,14 @@ pub(crate) const fn new(pass: Pass, condition: Condition) -> Self {
 }
 
 /// Returns the given default set of passes.
-pub(crate) fn defaults(show_coverage: bool) -> &'static [ConditionalPass] {
-    if show_coverage { COVERAGE_PASSES } else { DEFAULT_PASSES }
+pub(crate) fn defaults(show_coverage: bool, report_hidden: bool) -> &'static [ConditionalPass] {
+    if show_coverage {
+        COVERAGE_PASSES
+    } else if report_hidden {
+        REPORT_HIDDEN_PASSES
+    } else {
+        DEFAULT_PASSES
+    }
 }
 
 /// Returns a span encompassing all the given attributes.
@@ -1,6 +1,12 @@
-//! Propagates [`#[doc(cfg(...))]`](https://github.com/rust-lang/rust/issues/43781) to child items.
+//! Propagates [`#[doc(cfg(...))]`](https://github.com/rust-lang/rust/issues/43781) to child items,
+//! and computes the crate features required by each item.
+use std::iter;
+use std::mem;
 use std::sync::Arc;
 
+use rustc_middle::ty::{DefIdTree, TyCtxt};
+use rustc_span::symbol::{sym, Symbol};
+
 use crate::clean::cfg::Cfg;
 use crate::clean::{Crate, Item};
 use crate::core::DocContext;
@@ -10,21 +16,37 @@
 pub(crate) const PROPAGATE_DOC_CFG: Pass = Pass {
     name: "propagate-doc-cfg",
     run: propagate_doc_cfg,
-    description: "propagates `#[doc(cfg(...))]` to child items",
+    description: "propagates `#[doc(cfg(...))]` and the required crate features to child items",
 };
 
-pub(crate) fn propagate_doc_cfg(cr: Crate, _: &mut DocContext<'_>) -> Crate {
-    CfgPropagator { parent_cfg: None }.fold_crate(cr)
+pub(crate) fn propagate_doc_cfg(cr: Crate, cx: &mut DocContext<'_>) -> Crate {
+    CfgPropagator { cx, parent_cfg: None, parent_features: Vec::new() }.fold_crate(cr)
 }
 
-struct CfgPropagator {
+struct CfgPropagator<'a, 'tcx> {
+    cx: &'a mut DocContext<'tcx>,
     parent_cfg: Option<Arc<Cfg>>,
+    /// The crate features required by the parent of the current item in the documentation.
+    parent_features: Vec<Symbol>,
 }
 
-impl DocFolder for CfgPropagator {
+impl<'a, 'tcx> DocFolder for CfgPropagator<'a, 'tcx> {
     fn fold_item(&mut self, mut item: Item) -> Option<Item> {
         let old_parent_cfg = self.parent_cfg.clone();
 
+        // An item inlined through a re-export depends on the modules it's documented in as well
+        // as on the ones defining it.
+        let mut features = self.parent_features.clone();
+        for feature in definition_features(self.cx.tcx, &item) {
+            if !features.contains(&feature) {
+                features.push(feature);
+            }
+        }
+        if !features.is_empty() {
+            self.cx.cache.required_features.insert(item.item_id, features.clone());
+        }
+        let old_parent_features = mem::replace(&mut self.parent_features, features);
+
         let new_cfg = match (self.parent_cfg.take(), item.cfg.take()) {
             (None, None) => None,
             (Some(rc), None) | (None, Some(rc)) => Some(rc),
@@ -39,7 +61,30 @@ fn fold_item(&mut self, mut item: Item) -> Option<Item> {
 
         let result = self.fold_item_recur(item);
         self.parent_cfg = old_parent_cfg;
+        self.parent_features = old_parent_features;
 
         Some(result)
     }
 }
+
+/// Returns the crate features required by the `#[cfg]` attributes of a local item and of the items
+/// it's defined in.
+fn definition_features(tcx: TyCtxt<'_>, item: &Item) -> Vec<Symbol> {
+    let mut features = Vec::new();
+    let Some(did) = item.item_id.as_def_id() else { return features };
+    // The features of other crates can't be enabled from this one.
+    let definitions = iter::successors(Some(did), |&did| tcx.opt_parent(did));
+    for did in definitions.take_while(|did| did.is_local()) {
+        for attr in tcx.get_attrs(did, sym::cfg) {
+            let Some(list) = attr.meta_item_list() else { continue };
+            let [cfg] = &list[..] else { continue };
+            let Some(Ok(cfg)) = cfg.meta_item().map(Cfg::parse) else { continue };
+            for feature in cfg.required_features() {
+                if !features.contains(&feature) {
+                    features.push(feature);
+                }
+            }
+        }
+    }
+    features
+}
@@ -0,0 +1,121 @@
+//! Lists the public items which don't show up in the documentation, for the `--report-hidden` flag.
+//!
+//! Items removed by `#[cfg]` are gone before rustdoc ever sees the crate, so they can't be reported
+//! here.
+use crate::clean;
+use crate::clean::utils::has_doc_flag;
+use crate::core::DocContext;
+use crate::html::format::join_with_double_colon;
+use crate::passes::Pass;
+use crate::visit::DocVisitor;
+use crate::visit_ast::def_id_to_path;
+use rustc_hir::def_id::DefId;
+use rustc_span::symbol::{sym, Symbol};
+use serde::Serialize;
+
+use std::mem;
+
+pub(crate) const REPORT_HIDDEN: Pass = Pass {
+    name: "report-hidden",
+    run: report_hidden,
+    description: "lists the public items hidden from the documentation and why",
+};
+
+fn report_hidden(krate: clean::Crate, cx: &mut DocContext<'_>) -> clean::Crate {
+    let mut reporter = HiddenReporter { cx, path: Vec::new(), hidden_by: None, items: Vec::new() };
+    reporter.visit_crate(&krate);
+
+    println!("{}", serde_json::to_string(&reporter.items).expect("failed to convert JSON data"));
+
+    krate
+}
+
+#[derive(Serialize, Debug)]
+struct HiddenItem {
+    path: String,
+    kind: &'static str,
+    #[serde(flatten)]
+    reason: HiddenReason,
+}
+
+/// Why an item is left out of the documentation.
+#[derive(Serialize, Debug)]
+#[serde(tag = "reason", rename_all = "snake_case")]
+enum HiddenReason {
+    /// The item itself is marked `#[doc(hidden)]`.
+    DocHidden,
+    /// The item sits inside a `#[doc(hidden)]` item, whose path is given.
+    InheritedDocHidden { from: String },
+    /// The item is an implementation coming from a crate marked `#[doc(masked)]`.
+    Masked { krate: String },
+}
+
+struct HiddenReporter<'a, 'b> {
+    cx: &'a mut DocContext<'b>,
+    /// The path of the current item.
+    path: Vec<Symbol>,
+    /// The path of the outermost `#[doc(hidden)]` ancestor of the current item, if any.
+    hidden_by: Option<String>,
+    items: Vec<HiddenItem>,
+}
+
+impl<'a, 'b> HiddenReporter<'a, 'b> {
+    fn is_public(&self, item: &clean::Item, did: DefId) -> bool {
+        // Impls are as public as the trait and type they involve, which is checked by
+        // `ImplStripper` later on.
+        matches!(*item.kind, clean::ImplItem(_))
+            || !did.is_local()
+            || self.cx.cache.access_levels.is_public(did)
+    }
+
+    fn masked_crate(&self, item: &clean::Item) -> Option<String> {
+        let krate = self.cx.cache.masking_crate(item)?;
+        Some(self.cx.tcx.crate_name(krate).to_string())
+    }
+}
+
+impl<'a, 'b> DocVisitor for HiddenReporter<'a, 'b> {
+    fn visit_item(&mut self, item: &clean::Item) {
+        // Auto trait and blanket impls are never hidden, and their items belong to the trait.
+        let Some(did) = item.item_id.as_def_id() else { return };
+
+        // Items are listed under the path they would have in the documentation, with the items of
+        // an impl listed under the implementing type.
+        let mut path = self.path.clone();
+        match *item.kind {
+            clean::ImplItem(ref i) => {
+                if let Some(for_did) = i.for_.def_id(&self.cx.cache) {
+                    path = def_id_to_path(self.cx.tcx, for_did);
+                }
+            }
+            clean::ImportItem(clean::Import { kind: clean::ImportKind::Simple(name), .. }) => {
+                path.push(name)
+            }
+            _ => path.extend(item.name),
+        }
+        let own_hidden =
+            !self.cx.render_options.document_hidden && has_doc_flag(self.cx.tcx, did, sym::hidden);
+
+        let reason = if own_hidden {
+            Some(HiddenReason::DocHidden)
+        } else if let Some(from) = &self.hidden_by {
+            Some(HiddenReason::InheritedDocHidden { from: from.clone() })
+        } else {
+            self.masked_crate(item).map(|krate| HiddenReason::Masked { krate })
+        };
+        if let Some(reason) = reason && self.is_public(item, did) {
+            let path = join_with_double_colon(&path);
+            self.items.push(HiddenItem { path, kind: item.type_().as_str(), reason });
+        }
+
+        let old_hidden_by = if own_hidden && self.hidden_by.is_none() {
+            self.hidden_by.replace(join_with_double_colon(&path))
+        } else {
+            self.hidden_by.clone()
+        };
+        let old_path = mem::replace(&mut self.path, path);
+        self.visit_item_recur(item);
+        self.path = old_path;
+        self.hidden_by = old_hidden_by;
+    }
+}
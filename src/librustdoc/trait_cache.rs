@@ -0,0 +1,292 @@
+//! The traits of other crates cleaned by [`record_extern_trait`], kept across runs with
+//! `--extern-trait-cache` so that the traits of the dependencies that didn't change don't need to
+//! be cleaned again.
+//!
+//! The traits of each crate are kept in their own file, named after the crate and its SVH, so that
+//! a file is only used as long as its crate stays the same. `DefId`s and `CrateNum`s are written as
+//! the `DefPathHash`es and `StableCrateId`s they map to, which don't change from one run to the
+//! next. Spans aren't written at all since they do change: the spans of the items of a loaded trait
+//! are found from their `DefId`s like for any other item, only the spans of its attributes are
+//! dummies.
+//!
+//! [`record_extern_trait`]: crate::clean::inline::record_extern_trait
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use rustc_data_structures::fx::{FxHashMap, FxHashSet};
+use rustc_hir::def_id::{CrateNum, DefId, DefPathHash, StableCrateId};
+use rustc_macros::{Decodable, Encodable};
+use rustc_middle::ty::TyCtxt;
+use rustc_serialize::opaque::{MemDecoder, MemEncoder};
+use rustc_serialize::{Decodable, Decoder, Encodable, Encoder};
+use rustc_span::{Span, DUMMY_SP};
+
+use crate::clean::cfg::Cfg;
+use crate::clean::TraitWithExtraInfo;
+use crate::formats::cache::Cache;
+use crate::formats::item_type::ItemType;
+
+/// A call to one of the `record_extern_*` functions made while cleaning a trait. The calls are
+/// made again when the trait is loaded, since they fill in what the documentation of the trait
+/// refers to.
+#[derive(Clone, Debug, Encodable, Decodable)]
+pub(crate) enum RecordedExtern {
+    Fqn(DefId, ItemType),
+    Trait(DefId),
+    TraitAlias(DefId),
+}
+
+#[derive(Clone, Debug, Encodable, Decodable)]
+pub(crate) struct CachedTrait {
+    pub(crate) trait_: TraitWithExtraInfo,
+    pub(crate) recorded: Vec<RecordedExtern>,
+}
+
+/// What a cleaned trait depends on besides the crate defining it: the `cfg` of its items depends
+/// on the settings of the documented crate. Files written with other settings are ignored.
+#[derive(Encodable, Decodable)]
+struct Settings {
+    doc_cfg: bool,
+    doc_auto_cfg: bool,
+    auto_cfg: bool,
+    hidden_cfg: Vec<Cfg>,
+}
+
+impl Settings {
+    fn new(tcx: TyCtxt<'_>, cache: &Cache) -> Settings {
+        Settings {
+            doc_cfg: tcx.features().doc_cfg,
+            doc_auto_cfg: tcx.features().doc_auto_cfg,
+            auto_cfg: cache.auto_cfg,
+            hidden_cfg: cache.hidden_cfg.iter().cloned().collect(),
+        }
+    }
+
+    fn matches(&self, other: &Settings) -> bool {
+        self.doc_cfg == other.doc_cfg
+            && self.doc_auto_cfg == other.doc_auto_cfg
+            && self.auto_cfg == other.auto_cfg
+            && self.hidden_cfg.iter().collect::<FxHashSet<_>>()
+                == other.hidden_cfg.iter().collect::<FxHashSet<_>>()
+    }
+}
+
+/// The version of rustdoc, which the format of the files depends on.
+fn version() -> &'static str {
+    option_env!("CFG_VERSION").unwrap_or("unknown version")
+}
+
+#[derive(Default)]
+struct CrateTraits {
+    traits: FxHashMap<DefPathHash, CachedTrait>,
+    /// Whether traits were added since the file of the crate was read.
+    changed: bool,
+}
+
+pub(crate) struct TraitCache {
+    dir: PathBuf,
+    crates: FxHashMap<CrateNum, CrateTraits>,
+}
+
+impl TraitCache {
+    pub(crate) fn new(dir: PathBuf) -> TraitCache {
+        TraitCache { dir, crates: FxHashMap::default() }
+    }
+
+    fn path(&self, tcx: TyCtxt<'_>, krate: CrateNum) -> PathBuf {
+        self.dir.join(format!("{}-{}.bin", tcx.crate_name(krate), tcx.crate_hash(krate)))
+    }
+
+    fn crate_traits(
+        &mut self,
+        tcx: TyCtxt<'_>,
+        cache: &Cache,
+        krate: CrateNum,
+    ) -> &mut CrateTraits {
+        if !self.crates.contains_key(&krate) {
+            let traits = read(tcx, cache, &self.path(tcx, krate)).unwrap_or_default();
+            self.crates.insert(krate, CrateTraits { traits, changed: false });
+        }
+        self.crates.get_mut(&krate).unwrap()
+    }
+
+    /// The trait `did` as cleaned by a previous run, if its crate didn't change since.
+    pub(crate) fn get(
+        &mut self,
+        tcx: TyCtxt<'_>,
+        cache: &Cache,
+        did: DefId,
+    ) -> Option<CachedTrait> {
+        let hash = tcx.def_path_hash(did);
+        self.crate_traits(tcx, cache, did.krate).traits.get(&hash).cloned()
+    }
+
+    pub(crate) fn insert(
+        &mut self,
+        tcx: TyCtxt<'_>,
+        cache: &Cache,
+        did: DefId,
+        trait_: CachedTrait,
+    ) {
+        let hash = tcx.def_path_hash(did);
+        let crate_traits = self.crate_traits(tcx, cache, did.krate);
+        crate_traits.traits.insert(hash, trait_);
+        crate_traits.changed = true;
+    }
+
+    /// Writes the files of the crates which had traits added.
+    pub(crate) fn write(&self, tcx: TyCtxt<'_>, cache: &Cache) -> Result<(), String> {
+        fs::create_dir_all(&self.dir)
+            .map_err(|e| format!("failed to create `{}`: {}", self.dir.display(), e))?;
+        let settings = Settings::new(tcx, cache);
+        for (&krate, crate_traits) in &self.crates {
+            if !crate_traits.changed {
+                continue;
+            }
+            let mut encoder = TraitCacheEncoder { tcx, encoder: MemEncoder::new() };
+            version().encode(&mut encoder);
+            settings.encode(&mut encoder);
+            let traits: Vec<_> = crate_traits.traits.iter().collect();
+            traits.encode(&mut encoder);
+            let path = self.path(tcx, krate);
+            fs::write(&path, encoder.encoder.finish())
+                .map_err(|e| format!("failed to write `{}`: {}", path.display(), e))?;
+        }
+        Ok(())
+    }
+}
+
+fn read(
+    tcx: TyCtxt<'_>,
+    cache: &Cache,
+    path: &Path,
+) -> Option<FxHashMap<DefPathHash, CachedTrait>> {
+    let bytes = fs::read(path).ok()?;
+    let mut decoder = TraitCacheDecoder { tcx, decoder: MemDecoder::new(&bytes, 0) };
+    if decoder.read_str() != version()
+        || !Settings::decode(&mut decoder).matches(&Settings::new(tcx, cache))
+    {
+        return None;
+    }
+    let traits: Vec<(DefPathHash, CachedTrait)> = Decodable::decode(&mut decoder);
+    Some(traits.into_iter().collect())
+}
+
+struct TraitCacheEncoder<'tcx> {
+    tcx: TyCtxt<'tcx>,
+    encoder: MemEncoder,
+}
+
+macro_rules! encoder_methods {
+    ($($name:ident($ty:ty);)*) => {
+        $(fn $name(&mut self, value: $ty) {
+            self.encoder.$name(value)
+        })*
+    }
+}
+
+impl Encoder for TraitCacheEncoder<'_> {
+    encoder_methods! {
+        emit_usize(usize);
+        emit_u128(u128);
+        emit_u64(u64);
+        emit_u32(u32);
+        emit_u16(u16);
+        emit_u8(u8);
+
+        emit_isize(isize);
+        emit_i128(i128);
+        emit_i64(i64);
+        emit_i32(i32);
+        emit_i16(i16);
+        emit_i8(i8);
+
+        emit_bool(bool);
+        emit_f64(f64);
+        emit_f32(f32);
+        emit_char(char);
+        emit_str(&str);
+        emit_raw_bytes(&[u8]);
+    }
+}
+
+impl<'tcx> Encodable<TraitCacheEncoder<'tcx>> for DefId {
+    fn encode(&self, s: &mut TraitCacheEncoder<'tcx>) {
+        s.tcx.def_path_hash(*self).encode(s);
+    }
+}
+
+impl<'tcx> Encodable<TraitCacheEncoder<'tcx>> for CrateNum {
+    fn encode(&self, s: &mut TraitCacheEncoder<'tcx>) {
+        s.tcx.stable_crate_id(*self).encode(s);
+    }
+}
+
+impl<'tcx> Encodable<TraitCacheEncoder<'tcx>> for Span {
+    fn encode(&self, _: &mut TraitCacheEncoder<'tcx>) {}
+}
+
+struct TraitCacheDecoder<'a, 'tcx> {
+    tcx: TyCtxt<'tcx>,
+    decoder: MemDecoder<'a>,
+}
+
+macro_rules! decoder_methods {
+    ($($name:ident -> $ty:ty;)*) => {
+        $(fn $name(&mut self) -> $ty {
+            self.decoder.$name()
+        })*
+    }
+}
+
+impl<'a> Decoder for TraitCacheDecoder<'a, '_> {
+    decoder_methods! {
+        read_usize -> usize;
+        read_u128 -> u128;
+        read_u64 -> u64;
+        read_u32 -> u32;
+        read_u16 -> u16;
+        read_u8 -> u8;
+
+        read_isize -> isize;
+        read_i128 -> i128;
+        read_i64 -> i64;
+        read_i32 -> i32;
+        read_i16 -> i16;
+        read_i8 -> i8;
+
+        read_bool -> bool;
+        read_f64 -> f64;
+        read_f32 -> f32;
+        read_char -> char;
+        read_str -> &str;
+    }
+
+    fn read_raw_bytes(&mut self, len: usize) -> &[u8] {
+        self.decoder.read_raw_bytes(len)
+    }
+}
+
+impl<'a, 'tcx> Decodable<TraitCacheDecoder<'a, 'tcx>> for DefId {
+    fn decode(d: &mut TraitCacheDecoder<'a, 'tcx>) -> DefId {
+        let def_path_hash = DefPathHash::decode(d);
+        // The crate defining the trait didn't change, so neither did the crates it depends on.
+        d.tcx.def_path_hash_to_def_id(def_path_hash, &mut || {
+            panic!("failed to convert DefPathHash {:?}", def_path_hash)
+        })
+    }
+}
+
+impl<'a, 'tcx> Decodable<TraitCacheDecoder<'a, 'tcx>> for CrateNum {
+    fn decode(d: &mut TraitCacheDecoder<'a, 'tcx>) -> CrateNum {
+        let stable_crate_id = StableCrateId::decode(d);
+        d.tcx.stable_crate_id_to_crate_num(stable_crate_id)
+    }
+}
+
+impl<'a, 'tcx> Decodable<TraitCacheDecoder<'a, 'tcx>> for Span {
+    fn decode(_: &mut TraitCacheDecoder<'a, 'tcx>) -> Span {
+        DUMMY_SP
+    }
+}
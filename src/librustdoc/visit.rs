@@ -28,7 +28,7 @@ fn visit_inner_recur(&mut self, kind: &ItemKind) {
             | TypedefItem(_)
             | OpaqueTyItem(_)
             | StaticItem(_)
-            | ConstantItem(_)
+            | ConstantItem(..)
             | TraitAliasItem(_)
             | TyMethodItem(_)
             | MethodItem(_, _)
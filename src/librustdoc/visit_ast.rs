@@ -9,7 +9,7 @@
 use rustc_hir::CRATE_HIR_ID;
 use rustc_middle::middle::privacy::AccessLevel;
 use rustc_middle::ty::TyCtxt;
-use rustc_span::def_id::{CRATE_DEF_ID, LOCAL_CRATE};
+use rustc_span::def_id::{LocalDefId, CRATE_DEF_ID, LOCAL_CRATE};
 use rustc_span::symbol::{kw, sym, Symbol};
 use rustc_span::Span;
 
@@ -42,7 +42,7 @@ pub(crate) fn where_outer(&self, tcx: TyCtxt<'_>) -> Span {
 }
 
 // FIXME: Should this be replaced with tcx.def_path_str?
-fn def_id_to_path(tcx: TyCtxt<'_>, did: DefId) -> Vec<Symbol> {
+pub(crate) fn def_id_to_path(tcx: TyCtxt<'_>, did: DefId) -> Vec<Symbol> {
     let crate_name = tcx.crate_name(did.krate);
     let relative = tcx.def_path(did).data.into_iter().filter_map(|elem| elem.data.get_opt_name());
     std::iter::once(crate_name).chain(relative).collect()
@@ -58,6 +58,27 @@ pub(crate) fn inherits_doc_hidden(tcx: TyCtxt<'_>, mut node: hir::HirId) -> bool
     false
 }
 
+/// Returns whether the glob import of `item` into `module` is shadowed by an item or an explicit
+/// import of the same name. Only the items defining a name are checked, `use` items for instance
+/// are handled when visiting them.
+fn is_shadowed_definition(tcx: TyCtxt<'_>, module: LocalDefId, item: &hir::Item<'_>) -> bool {
+    match item.kind {
+        hir::ItemKind::Mod(_)
+        | hir::ItemKind::Struct(..)
+        | hir::ItemKind::Union(..)
+        | hir::ItemKind::Enum(..)
+        | hir::ItemKind::Trait(..)
+        | hir::ItemKind::TraitAlias(..)
+        | hir::ItemKind::TyAlias(..)
+        | hir::ItemKind::Fn(..)
+        | hir::ItemKind::Const(..)
+        | hir::ItemKind::Static(..) => {
+            !clean::utils::is_imported_as(tcx, module, item.ident.name, item.def_id.to_def_id())
+        }
+        _ => false,
+    }
+}
+
 // Also, is there some reason that this doesn't use the 'visit'
 // framework from syntax?.
 
@@ -240,9 +261,13 @@ fn maybe_inline_local(
 
         let ret = match tcx.hir().get(res_hir_id) {
             Node::Item(&hir::Item { kind: hir::ItemKind::Mod(ref m), .. }) if glob => {
+                let importing_mod = tcx.parent_module(id);
                 let prev = mem::replace(&mut self.inlining, true);
                 for &i in m.item_ids {
                     let i = self.cx.tcx.hir().item(i);
+                    if is_shadowed_definition(tcx, importing_mod, i) {
+                        continue;
+                    }
                     self.visit_item(i, None, om);
                 }
                 self.inlining = prev;
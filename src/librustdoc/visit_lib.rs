@@ -1,9 +1,34 @@
 use rustc_data_structures::fx::FxHashSet;
+use rustc_data_structures::sync::{par_iter, ParallelIterator};
 use rustc_hir::def::{DefKind, Res};
 use rustc_hir::def_id::{CrateNum, DefId};
 use rustc_middle::middle::privacy::{AccessLevel, AccessLevels};
 use rustc_middle::ty::TyCtxt;
 
+/// Analyzes the doc-reachability of the items of `crates` and records it in `access_levels`.
+///
+/// Levels only ever grow, so the crates can be visited independently, in parallel in a parallel
+/// compiler, and the levels found for each of them merged afterwards.
+pub(crate) fn visit_libs(
+    tcx: TyCtxt<'_>,
+    access_levels: &mut AccessLevels<DefId>,
+    crates: &[CrateNum],
+) {
+    let crate_access_levels: Vec<AccessLevels<DefId>> = par_iter(crates)
+        .map(|&cnum| {
+            let mut access_levels = AccessLevels::default();
+            LibEmbargoVisitor::with_access_levels(tcx, &mut access_levels).visit_lib(cnum);
+            access_levels
+        })
+        .collect();
+    for crate_access_levels in crate_access_levels {
+        for (did, level) in crate_access_levels.map {
+            let old_level = access_levels.map.entry(did).or_insert(level);
+            *old_level = (*old_level).max(level);
+        }
+    }
+}
+
 // FIXME: this may not be exhaustive, but is sufficient for rustdocs current uses
 
 /// Similar to `librustc_privacy::EmbargoVisitor`, but also takes
@@ -20,9 +45,18 @@ pub(crate) struct LibEmbargoVisitor<'a, 'tcx> {
 
 impl<'a, 'tcx> LibEmbargoVisitor<'a, 'tcx> {
     pub(crate) fn new(cx: &'a mut crate::core::DocContext<'tcx>) -> LibEmbargoVisitor<'a, 'tcx> {
+        LibEmbargoVisitor::with_access_levels(cx.tcx, &mut cx.cache.access_levels)
+    }
+
+    /// Creates a visitor recording the reachable items in `access_levels` rather than in the
+    /// cache, see [`visit_libs`].
+    pub(crate) fn with_access_levels(
+        tcx: TyCtxt<'tcx>,
+        access_levels: &'a mut AccessLevels<DefId>,
+    ) -> LibEmbargoVisitor<'a, 'tcx> {
         LibEmbargoVisitor {
-            tcx: cx.tcx,
-            access_levels: &mut cx.cache.access_levels,
+            tcx,
+            access_levels,
             prev_level: Some(AccessLevel::Public),
             visited_mods: FxHashSet::default(),
         }
@@ -9,7 +9,7 @@
 use serde::{Deserialize, Serialize};
 
 /// rustdoc format-version.
-pub const FORMAT_VERSION: u32 = 15;
+pub const FORMAT_VERSION: u32 = 21;
 
 /// A `Crate` is the root of the emitted JSON blob. It contains all type/documentation information
 /// about the language items in the local crate, as well as info about external items to allow
@@ -79,6 +79,9 @@ pub struct Item {
     /// Stringified versions of the attributes on this item (e.g. `"#[inline]"`)
     pub attrs: Vec<String>,
     pub deprecation: Option<Deprecation>,
+    /// The crate features which must be enabled for this item to exist, because of the
+    /// `#[cfg(feature = "...")]` attributes on it or on the items containing it.
+    pub required_features: Vec<String>,
     #[serde(flatten)]
     pub inner: ItemEnum,
 }
@@ -140,6 +143,9 @@ pub struct Constant {
     pub expr: String,
     pub value: Option<String>,
     pub is_literal: bool,
+    /// The source location of `expr`, absent for constants defined in other crates or without a
+    /// body of their own (e.g. generic arguments referring to a const parameter).
+    pub span: Option<Span>,
 }
 
 #[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
@@ -222,7 +228,7 @@ pub enum ItemEnum {
     ForeignType,
 
     /// Declarative macro_rules! macro
-    Macro(String),
+    Macro(Macro),
     ProcMacro(ProcMacro),
 
     PrimitiveType(String),
@@ -548,6 +554,13 @@ pub struct Impl {
     pub negative: bool,
     pub synthetic: bool,
     pub blanket_impl: Option<Type>,
+    /// The `Target` type of a `Deref` impl.
+    pub deref_target: Option<Type>,
+    /// The inherent impls whose methods can be called through a `Deref` impl.
+    ///
+    /// This follows the whole chain of `Deref` impls, so for `impl Deref<Target = String>` it
+    /// contains the impls of both `String` and `str`, in that order.
+    pub deref_impls: Vec<Id>,
 }
 
 #[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
@@ -564,6 +577,61 @@ pub struct Import {
     pub glob: bool,
 }
 
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
+pub struct Macro {
+    /// The declaration of the macro as it is rendered in the HTML output, with the body of each
+    /// arm collapsed to `{ ... }`.
+    pub source: String,
+    /// The arms of the macro, in the order they are declared in.
+    pub arms: Vec<MacroArm>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
+pub struct MacroArm {
+    /// The tokens the input of the macro has to match for this arm to be used, without the
+    /// delimiters around the whole matcher.
+    pub matcher: Vec<MacroMatcher>,
+    /// The source location of the matcher, including its delimiters.
+    pub span: Option<Span>,
+    /// The fragment identifier of the arm in the HTML documentation of the macro, e.g. `arm.0`.
+    pub anchor: String,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum MacroMatcher {
+    /// A token which has to appear as-is in the input, e.g. `,` or `=>`.
+    Token(String),
+    /// A metavariable: `$name:fragment`.
+    MetaVar { name: String, fragment: String },
+    /// Tokens which have to appear in the input enclosed in the given delimiters.
+    Delimited { delimiter: MacroDelimiter, tokens: Vec<MacroMatcher> },
+    /// A repetition: `$(tokens) separator kleene`.
+    Repetition { tokens: Vec<MacroMatcher>, separator: Option<String>, kleene: MacroKleeneOp },
+}
+
+#[derive(Clone, Copy, Debug, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum MacroDelimiter {
+    /// `( ... )`
+    Parenthesis,
+    /// `[ ... ]`
+    Bracket,
+    /// `{ ... }`
+    Brace,
+}
+
+#[derive(Clone, Copy, Debug, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum MacroKleeneOp {
+    /// `*`
+    ZeroOrMore,
+    /// `+`
+    OneOrMore,
+    /// `?`
+    ZeroOrOne,
+}
+
 #[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
 pub struct ProcMacro {
     pub kind: MacroKind,
@@ -0,0 +1,16 @@
+/// Converts a value to its encoded form.
+pub trait Encode {
+    /// The form the value is encoded to.
+    type Output: Default;
+
+    /// Writes the value.
+    fn encode(&self) -> Self::Output;
+
+    /// Writes the value, after its length.
+    fn encode_with_len(&self, len: usize) -> (usize, Self::Output) {
+        (len, self.encode())
+    }
+}
+
+/// Something that can be encoded to text.
+pub trait EncodeText: Encode<Output = String> {}
@@ -0,0 +1,17 @@
+extern crate dep;
+
+use dep::{Encode, EncodeText};
+
+pub struct Foo;
+
+impl Encode for Foo {
+    type Output = String;
+
+    fn encode(&self) -> String {
+        String::new()
+    }
+}
+
+impl EncodeText for Foo {}
+
+pub fn use_bound<T: EncodeText>(_: T) {}
@@ -0,0 +1,9 @@
+extern crate dep;
+
+pub struct Bar;
+
+impl dep::Show for Bar {
+    fn show(&self) -> String {
+        "Bar".to_string()
+    }
+}
@@ -0,0 +1,8 @@
+/// A value which can be shown.
+pub trait Show {
+    fn show(&self) -> String;
+
+    fn show_twice(&self) -> String {
+        self.show() + &self.show()
+    }
+}
@@ -0,0 +1,9 @@
+extern crate dep;
+
+pub struct Foo;
+
+impl dep::Show for Foo {
+    fn show(&self) -> String {
+        "Foo".to_string()
+    }
+}
@@ -0,0 +1,23 @@
+// exact-check
+
+const QUERY = ['inner_method', 'inner_mut_method'];
+
+const EXPECTED = [
+    {
+        'others': [
+            { 'path': 'deref_methods::Inner', 'name': 'inner_method' },
+            {
+                'path': 'deref_methods::Outer',
+                'name': 'inner_method',
+                'href': '../deref_methods/struct.Outer.html#method.inner_method',
+                'viaDeref': true,
+            },
+        ],
+    },
+    {
+        // `Outer` doesn't implement `DerefMut`.
+        'others': [
+            { 'path': 'deref_methods::Inner', 'name': 'inner_mut_method' },
+        ],
+    },
+];
@@ -0,0 +1,22 @@
+use std::ops::Deref;
+
+pub struct Inner;
+
+impl Inner {
+    pub fn inner_method(&self) {}
+    pub fn inner_mut_method(&mut self) {}
+}
+
+pub struct Outer;
+
+impl Outer {
+    pub fn outer_method(&self) {}
+}
+
+impl Deref for Outer {
+    type Target = Inner;
+
+    fn deref(&self) -> &Inner {
+        &Inner
+    }
+}
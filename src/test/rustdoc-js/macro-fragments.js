@@ -0,0 +1,19 @@
+// exact-check
+
+const QUERY = [
+    'expr, ident',
+    'ty',
+];
+
+const EXPECTED = [
+    {
+        'in_args': [
+            { 'path': 'macro_fragments', 'name': 'pair' },
+        ],
+    },
+    {
+        'in_args': [
+            { 'path': 'macro_fragments', 'name': 'many' },
+        ],
+    },
+];
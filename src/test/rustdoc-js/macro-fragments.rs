@@ -0,0 +1,10 @@
+#[macro_export]
+macro_rules! pair {
+    ($e:expr, $name:ident) => {};
+    ($e:expr) => {};
+}
+
+#[macro_export]
+macro_rules! many {
+    ($($t:ty),*) => {};
+}
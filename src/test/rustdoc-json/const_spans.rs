@@ -0,0 +1,13 @@
+// ignore-tidy-linelength
+
+// @is const_spans.json "$.index[*][?(@.name=='DAY')].inner.span.begin" '[5, 21]'
+// @is - "$.index[*][?(@.name=='DAY')].inner.span.end" '[5, 33]'
+pub const DAY: u32 = 24 * 60 * 60;
+
+pub struct Grid<const N: usize>;
+
+// Anonymous constants, such as const generic arguments, have a span too.
+// @is - "$.index[*][?(@.name=='grid')].inner.decl.output.inner.args.angle_bracketed.args[0].const.span.begin" '[11, 22]'
+pub fn grid() -> Grid<{ 2 + 2 }> {
+    Grid
+}
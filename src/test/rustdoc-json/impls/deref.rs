@@ -0,0 +1,49 @@
+// ignore-tidy-linelength
+
+use std::cell::Cell;
+use std::ops::Deref;
+
+// @set c = deref.json "$.index[*][?(@.name=='C')].id"
+pub struct C;
+
+impl C {
+    pub fn c(&self) {}
+}
+
+// @set b = - "$.index[*][?(@.name=='B')].id"
+pub struct B(C);
+
+impl B {
+    pub fn b(&self) {}
+}
+
+// @has - "$.index[*][?(@.kind=='impl')].inner.deref_target.inner.id" $c
+// @count - "$.index[*][?(@.inner.deref_target.inner.name=='C')].inner.deref_impls[*]" 1
+impl Deref for B {
+    type Target = C;
+    fn deref(&self) -> &C {
+        &self.0
+    }
+}
+
+pub struct A(B);
+
+// @has - "$.index[*][?(@.kind=='impl')].inner.deref_target.inner.id" $b
+// @count - "$.index[*][?(@.inner.deref_target.inner.name=='B')].inner.deref_impls[*]" 2
+impl Deref for A {
+    type Target = B;
+    fn deref(&self) -> &B {
+        &self.0
+    }
+}
+
+pub struct D(Cell<u8>);
+
+// The inherent impls of other crates aren't in the index, so their ids aren't listed.
+// @count - "$.index[*][?(@.inner.deref_target.inner.name=='Cell')].inner.deref_impls[*]" 0
+impl Deref for D {
+    type Target = Cell<u8>;
+    fn deref(&self) -> &Cell<u8> {
+        &self.0
+    }
+}
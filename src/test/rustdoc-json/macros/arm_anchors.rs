@@ -0,0 +1,10 @@
+#![feature(no_core)]
+#![no_core]
+
+// @is arm_anchors.json "$.index[*][?(@.name=='either')].inner.arms[0].anchor" '"arm.0"'
+// @is - "$.index[*][?(@.name=='either')].inner.arms[1].anchor" '"arm.1"'
+#[macro_export]
+macro_rules! either {
+    ($a:expr) => { $a };
+    ($a:expr, $b:expr) => { $b };
+}
@@ -0,0 +1,12 @@
+#![feature(no_core)]
+#![no_core]
+
+// @is matcher_spans.json "$.index[*][?(@.name=='pair')].inner.arms[0].span.begin" '[10, 4]'
+// @is - "$.index[*][?(@.name=='pair')].inner.arms[0].span.end" '[10, 22]'
+// @is - "$.index[*][?(@.name=='pair')].inner.arms[1].span.begin" '[11, 4]'
+// @is - "$.index[*][?(@.name=='pair')].inner.arms[1].span.end" '[11, 13]'
+#[macro_export]
+macro_rules! pair {
+    ($a:expr, $b:expr) => { ($a, $b) };
+    [$a:expr] => { ($a, $a) };
+}
@@ -0,0 +1,28 @@
+// ignore-tidy-linelength
+
+#![feature(no_core)]
+#![no_core]
+
+// @count matchers.json "$.index[*][?(@.name=='sum')].inner.arms[*]" 2
+// @is - "$.index[*][?(@.name=='sum')].inner.arms[0].matcher" '[]'
+// @count - "$.index[*][?(@.name=='sum')].inner.arms[1].matcher[*]" 2
+// @is - "$.index[*][?(@.name=='sum')].inner.arms[1].matcher[0].meta_var" '{"name": "first", "fragment": "expr"}'
+// @is - "$.index[*][?(@.name=='sum')].inner.arms[1].matcher[1].repetition.separator" 'null'
+// @is - "$.index[*][?(@.name=='sum')].inner.arms[1].matcher[1].repetition.kleene" '"zero_or_more"'
+// @is - "$.index[*][?(@.name=='sum')].inner.arms[1].matcher[1].repetition.tokens[0].token" '","'
+// @is - "$.index[*][?(@.name=='sum')].inner.arms[1].matcher[1].repetition.tokens[1].meta_var.name" '"rest"'
+#[macro_export]
+macro_rules! sum {
+    () => { 0 };
+    ($first:expr $(, $rest:expr)*) => { $first $(+ $rest)* };
+}
+
+// @count - "$.index[*][?(@.name=='fields')].inner.arms[0].matcher[*]" 2
+// @is - "$.index[*][?(@.name=='fields')].inner.arms[0].matcher[0].token" '"struct"'
+// @is - "$.index[*][?(@.name=='fields')].inner.arms[0].matcher[1].delimited.delimiter" '"brace"'
+// @is - "$.index[*][?(@.name=='fields')].inner.arms[0].matcher[1].delimited.tokens[0].repetition.separator" '","'
+// @is - "$.index[*][?(@.name=='fields')].inner.arms[0].matcher[1].delimited.tokens[0].repetition.kleene" '"one_or_more"'
+#[macro_export]
+macro_rules! fields {
+    (struct { $($field:ident),+ }) => {};
+}
@@ -0,0 +1,20 @@
+// ignore-tidy-linelength
+
+// compile-flags:--cfg feature="serde" --cfg feature="std"
+
+#![no_core]
+#![feature(no_core)]
+
+// @is required_features.json "$.index[*][?(@.name=='serialize')].required_features" '["serde"]'
+#[cfg(feature = "serde")]
+pub fn serialize() {}
+
+#[cfg(feature = "std")]
+pub mod io {
+    // @is required_features.json "$.index[*][?(@.name=='read')].required_features" '["std", "serde"]'
+    #[cfg(feature = "serde")]
+    pub fn read() {}
+}
+
+// @is required_features.json "$.index[*][?(@.name=='always')].required_features" []
+pub fn always() {}
@@ -0,0 +1,6 @@
+// Tests that finding the auto trait impls of an item gives up once `--auto-trait-fuel` is spent.
+// check-pass
+// compile-flags: -Z unstable-options --auto-trait-fuel 3
+
+pub struct Nested<T>(Vec<Vec<Box<T>>>);
+//~^ WARN not documenting the auto trait implementations of `Nested`
@@ -0,0 +1,5 @@
+// Checks that the `--backend` option can only be used with JSON output.
+
+// compile-flags: -Zunstable-options --backend my-backend
+
+pub fn f() {}
@@ -0,0 +1,27 @@
+// check-pass
+// compile-flags:-Z unstable-options --output-format json --show-coverage
+
+//! Macros are counted like the other items, while inherent impls only show up in the breakdown
+//! per kind.
+
+/// A documented macro.
+#[macro_export]
+macro_rules! documented {
+    () => {};
+}
+
+#[macro_export]
+macro_rules! undocumented {
+    ($e:expr) => {};
+}
+
+/// A struct.
+pub struct S;
+
+/// A documented impl.
+impl S {}
+
+impl S {
+    /// A method.
+    pub fn m(&self) {}
+}
@@ -0,0 +1,27 @@
+// aux-build:masked.rs
+// aux-build:empty-fn.rs
+
+#![feature(doc_masked)]
+#![deny(rustdoc::unused_doc_masked, rustdoc::masked_impls)]
+
+#[doc(masked)]
+extern crate masked;
+
+#[doc(masked)] //~ ERROR `#[doc(masked)]` has no effect
+extern crate empty_fn;
+
+pub mod inner {
+    #[doc(masked)] //~ ERROR `#[doc(masked)]` has no effect
+    extern crate masked;
+}
+
+#[doc(masked)] //~ ERROR `#[doc(masked)]` has no effect
+pub struct MyStruct;
+
+impl masked::MaskedTrait for MyStruct { fn masked_method() {} }
+//~^ ERROR this implementation is hidden by `#[doc(masked)]`
+
+// Private types are not documented anyway.
+struct Private;
+
+impl masked::MaskedTrait for Private { fn masked_method() {} }
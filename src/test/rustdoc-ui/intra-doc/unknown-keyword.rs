@@ -0,0 +1,7 @@
+#![deny(rustdoc::broken_intra_doc_links)]
+
+//! [keyword@banana]
+//~^ ERROR unresolved link to `banana`
+//! [the `match` keyword](keyword@match)
+
+fn main() {}
@@ -0,0 +1,27 @@
+// check-pass
+// compile-flags: -Z unstable-options --report-hidden
+
+#![crate_name = "foo"]
+
+#[doc(hidden)]
+pub mod hidden {
+    pub struct Inner;
+
+    #[doc(hidden)]
+    pub struct AlsoHidden;
+}
+
+pub struct Shown;
+
+impl Shown {
+    #[doc(hidden)]
+    pub fn hidden_method(&self) {}
+
+    pub fn shown_method(&self) {}
+}
+
+#[doc(hidden)]
+pub use hidden::Inner as Reexport;
+
+#[doc(hidden)]
+fn private_hidden() {}
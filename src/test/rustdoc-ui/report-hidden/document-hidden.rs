@@ -0,0 +1,5 @@
+// check-pass
+// compile-flags: -Z unstable-options --report-hidden --document-hidden-items
+
+#[doc(hidden)]
+pub struct Hidden;
@@ -0,0 +1,6 @@
+// compile-flags: -Z unstable-options --rust-doc-base-url https://docs.example.com/rust/
+// error-pattern: no documentation found
+#![deny(rustdoc::missing_crate_level_docs)]
+//^~ NOTE defined here
+
+pub fn foo() {}
@@ -0,0 +1,15 @@
+// aux-build:vendor-core.rs
+// compile-flags: -Z allow-doc-primitive
+// ignore-tidy-linelength
+
+#![crate_name = "foo"]
+
+extern crate vendor_core;
+
+// The primitives documented by the crate replacing `core` take precedence over `std`...
+// @has foo/fn.f.html '//a[@class="primitive"][@href="https://example.com/vendor_core/primitive.u8.html"]' 'u8'
+pub fn f(_: u8) {}
+
+// ... while the other primitives still link to `std`.
+// @has foo/fn.g.html '//a[@class="primitive"][@href="{{channel}}/std/primitive.u16.html"]' 'u16'
+pub fn g(_: u16) {}
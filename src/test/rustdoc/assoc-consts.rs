@@ -1,6 +1,6 @@
 pub trait Foo {
     // @has assoc_consts/trait.Foo.html '//*[@class="rust trait"]' \
-    //      'const FOO: usize = 13usize;'
+    //      'const FOO: usize = 12 + 1 /* 13usize */;'
     // @has - '//*[@id="associatedconstant.FOO"]' 'const FOO: usize'
     const FOO: usize = 12 + 1;
     // @has - '//*[@id="associatedconstant.FOO_NO_DEFAULT"]' 'const FOO_NO_DEFAULT: bool'
@@ -29,6 +29,12 @@ impl Bar {
     pub const BAR: usize = 3;
 }
 
+impl Bar {
+    // @has assoc_consts/struct.Bar.html '//*[@id="associatedconstant.WEEK"]' \
+    //      'const WEEK: u64 = 7 * 24 * 60 * 60 /* 604_800u64 */'
+    pub const WEEK: u64 = 7 * 24 * 60 * 60;
+}
+
 pub struct Baz<'a, U: 'a, T>(T, &'a [U]);
 
 impl Bar {
@@ -97,3 +103,28 @@ impl Qux for Bar {
     // @has - '//*[@id="associatedconstant.QUX_DEFAULT2"]' 'const QUX_DEFAULT2: u32'
     // @has - '//div[@class="impl-items"]//*[@class="docblock"]' "Docs for QUX_DEFAULT2 in trait."
 }
+
+pub trait Limits {
+    const MAX: usize;
+}
+
+impl Limits for Bar {
+    // @has assoc_consts/struct.Bar.html '//*[@id="associatedconstant.MAX"]' \
+    //      'const MAX: usize = 1 << 20 /* 1_048_576usize */'
+    const MAX: usize = 1 << 20;
+}
+
+pub struct Wrapper<T>(T);
+
+impl<T> Wrapper<T> {
+    // The constant isn't evaluated, so its expression isn't shown either, like for a free
+    // constant which can't be evaluated.
+    // @has assoc_consts/struct.Wrapper.html '//*[@id="associatedconstant.SHIFT"]' \
+    //      'const SHIFT: usize'
+    // @!has - '//*[@id="associatedconstant.SHIFT"]' '1 << 3'
+    // @!has - '//*[@id="associatedconstant.SHIFT"]' '/*'
+    pub const SHIFT: usize = 1 << 3;
+
+    // @has - '//*[@id="associatedconstant.UNIT"]' 'const UNIT: usize = 1'
+    pub const UNIT: usize = 1;
+}
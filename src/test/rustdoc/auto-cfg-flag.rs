@@ -0,0 +1,27 @@
+// compile-flags: -Z unstable-options --auto-cfg
+// compile-flags: --cfg feature="serde" --cfg feature="epopoeist"
+// Checks that `--auto-cfg` shows the `#[cfg]` of items without `#![feature(doc_auto_cfg)]`, and
+// adds their `#[doc(cfg)]` to it rather than replacing it.
+
+#![crate_name = "foo"]
+#![feature(doc_cfg)]
+
+// @has 'foo/struct.Serde.html'
+// @count - '//*[@class="stab portability"]' 1
+// @matches - '//*[@class="stab portability"]' 'crate feature serde'
+#[cfg(feature = "serde")]
+pub struct Serde;
+
+// @has 'foo/struct.Both.html'
+// @count - '//*[@class="stab portability"]' 1
+// @has - '//*[@class="stab portability"]//code' 'epopoeist'
+// @has - '//*[@class="stab portability"]//code' 'pliothermic'
+#[cfg(feature = "epopoeist")]
+#[doc(cfg(feature = "pliothermic"))]
+pub struct Both;
+
+// `doc` is hidden like with `doc_auto_cfg`.
+// @has 'foo/struct.OnlyDoc.html'
+// @count - '//*[@class="stab portability"]' 0
+#[cfg(doc)]
+pub struct OnlyDoc;
@@ -0,0 +1,3 @@
+extern crate blanket_impl_dedup;
+
+pub use blanket_impl_dedup::Marker;
@@ -0,0 +1,6 @@
+#![feature(specialization)]
+#![allow(incomplete_features)]
+
+pub trait Marker {}
+impl<T> Marker for T {}
+impl<T: Copy> Marker for T {}
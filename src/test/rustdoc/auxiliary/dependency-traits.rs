@@ -0,0 +1,17 @@
+//! Traits to implement.
+
+/// Says hello.
+pub trait Greet {
+    fn greet(&self) -> String;
+}
+
+pub struct World;
+
+impl Greet for World {
+    fn greet(&self) -> String {
+        "hello, world".to_owned()
+    }
+}
+
+/// Never implemented by the crate being documented.
+pub trait Unused {}
@@ -0,0 +1,12 @@
+mod inner {
+    pub struct Defined;
+
+    /// The one from the glob.
+    pub struct Shadowed;
+}
+
+pub mod prelude {
+    pub use crate::inner::{Defined, Shadowed};
+
+    pub struct Direct;
+}
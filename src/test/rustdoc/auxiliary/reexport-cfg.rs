@@ -0,0 +1,6 @@
+#![feature(doc_cfg)]
+
+#[doc(cfg(feature = "ext"))]
+pub mod ext {
+    pub struct External;
+}
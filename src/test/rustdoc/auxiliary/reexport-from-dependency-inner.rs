@@ -0,0 +1,4 @@
+#[doc(hidden)]
+pub mod __private {
+    pub struct Inner;
+}
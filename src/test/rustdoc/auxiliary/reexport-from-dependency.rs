@@ -0,0 +1,3 @@
+extern crate reexport_from_dependency_inner;
+
+pub use reexport_from_dependency_inner::__private::Inner;
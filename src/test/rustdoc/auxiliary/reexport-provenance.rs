@@ -0,0 +1,7 @@
+mod inner {
+    pub struct Defined;
+}
+
+pub mod facade {
+    pub use crate::inner::Defined;
+}
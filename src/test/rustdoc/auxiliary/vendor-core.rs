@@ -0,0 +1,8 @@
+// compile-flags: -Z allow-doc-primitive
+
+#![no_std]
+#![doc(html_root_url = "https://example.com/")]
+
+#[doc(primitive = "u8")]
+/// The vendor's own docs for `u8`.
+mod prim_u8 {}
@@ -0,0 +1,19 @@
+// aux-build:blanket-impl-dedup.rs
+// aux-build:blanket-impl-dedup-reexport.rs
+// Tests that a blanket impl of another crate is only listed once, even when its trait is
+// reachable through several crates.
+
+#![crate_name = "foo"]
+
+extern crate blanket_impl_dedup;
+extern crate blanket_impl_dedup_reexport;
+
+pub use blanket_impl_dedup::Marker;
+pub mod reexport {
+    pub use blanket_impl_dedup_reexport::Marker;
+}
+
+// @has foo/struct.S.html
+// @count - '//*[@id="blanket-implementations-list"]//h3[@class="code-header in-band"]' 'Marker for T' 1
+#[derive(Clone, Copy)]
+pub struct S;
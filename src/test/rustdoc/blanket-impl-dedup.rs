@@ -0,0 +1,20 @@
+// Tests that a blanket impl and its specializations are only listed once.
+
+#![feature(specialization)]
+#![allow(incomplete_features)]
+#![crate_name = "foo"]
+
+pub trait Marker {}
+impl<T> Marker for T {}
+impl<T: Copy> Marker for T {}
+
+pub trait Convert<U> {}
+impl<T> Convert<u8> for T {}
+impl<T> Convert<u16> for T {}
+
+// @has foo/struct.S.html
+// @count - '//*[@id="blanket-implementations-list"]//h3[@class="code-header in-band"]' 'Marker for T' 1
+// @count - '//*[@id="blanket-implementations-list"]//h3[@class="code-header in-band"]' 'Convert<u8> for T' 1
+// @count - '//*[@id="blanket-implementations-list"]//h3[@class="code-header in-band"]' 'Convert<u16> for T' 1
+#[derive(Clone, Copy)]
+pub struct S;
@@ -0,0 +1,13 @@
+// ignore-tidy-linelength
+
+#![crate_name = "foo"]
+
+pub trait Describe {}
+
+impl<T: Clone> Describe for T {}
+
+// @has foo/struct.MyType.html
+// @has - '//*[@id="blanket-implementations-list"]//*[@class="stab blanket-impl"]' 'Blanket impl applied with T = MyType, listed in the implementors of Describe'
+// @has - '//*[@id="blanket-implementations-list"]//*[@class="stab blanket-impl"]//a[@href="trait.Describe.html#implementors"]' 'implementors of Describe'
+#[derive(Clone)]
+pub struct MyType;
@@ -0,0 +1,20 @@
+// Test that very long constant expressions are cut short in the declaration, with the rest of
+// the expression hidden behind a toggle.
+
+#![crate_name = "foo"]
+
+// @has foo/constant.SHORT.html //pre '= [1, 2, 3];'
+// @!has foo/constant.SHORT.html 'Show full constant'
+pub const SHORT: [u32; 3] = [1, 2, 3];
+
+// @has foo/constant.LONG.html //pre '0x0000_0001, 0x0000_0002,'
+// @has foo/constant.LONG.html //pre//details/summary 'Show full constant'
+// @has foo/constant.LONG.html //pre//details '0x0000_0020,'
+// @!has foo/constant.LONG.html //pre//details '0x0000_0001,'
+pub const LONG: [u32; 32] = [
+    0x0000_0001, 0x0000_0002, 0x0000_0003, 0x0000_0004, 0x0000_0005, 0x0000_0006, 0x0000_0007,
+    0x0000_0008, 0x0000_0009, 0x0000_000a, 0x0000_000b, 0x0000_000c, 0x0000_000d, 0x0000_000e,
+    0x0000_000f, 0x0000_0010, 0x0000_0011, 0x0000_0012, 0x0000_0013, 0x0000_0014, 0x0000_0015,
+    0x0000_0016, 0x0000_0017, 0x0000_0018, 0x0000_0019, 0x0000_001a, 0x0000_001b, 0x0000_001c,
+    0x0000_001d, 0x0000_001e, 0x0000_001f, 0x0000_0020,
+];
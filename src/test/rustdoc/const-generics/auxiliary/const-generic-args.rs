@@ -0,0 +1,13 @@
+pub struct Grid<const W: usize = 8, const H: usize = { 2 * 4 }>;
+
+pub fn explicit() -> Grid<4, 2> {
+    Grid
+}
+
+pub fn defaults() -> Grid<8, 8> {
+    Grid
+}
+
+pub fn computed() -> Grid<{ 3 + 1 }> {
+    Grid
+}
@@ -0,0 +1,22 @@
+// aux-build:const-generic-args.rs
+// ignore-tidy-linelength
+#![crate_name = "foo"]
+
+extern crate const_generic_args;
+
+// The const arguments are shown instead of `_`, and the ones equal to the default of their
+// parameter are left out.
+
+// @has foo/struct.Grid.html '//pre[@class="rust struct"]' \
+//      'pub struct Grid<const W: usize = 8, const H: usize = { 2 * 4 }>;'
+pub use const_generic_args::Grid;
+
+// @has foo/fn.explicit.html '//pre[@class="rust fn"]' 'pub fn explicit() -> Grid<4, 2>'
+pub use const_generic_args::explicit;
+
+// @has foo/fn.defaults.html '//pre[@class="rust fn"]' 'pub fn defaults() -> Grid'
+// @!has - '//pre[@class="rust fn"]' 'Grid<'
+pub use const_generic_args::defaults;
+
+// @has foo/fn.computed.html '//pre[@class="rust fn"]' 'pub fn computed() -> Grid<{ 3 + 1 }>'
+pub use const_generic_args::computed;
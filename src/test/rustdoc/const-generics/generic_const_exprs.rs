@@ -5,3 +5,38 @@
 // @has foo/struct.Ice.html '//pre[@class="rust struct"]' \
 //      'pub struct Ice<const N: usize>;'
 pub struct Ice<const N: usize> where [(); N + 1]:;
+
+// Simple const expressions are pretty-printed.
+// @has foo/fn.grow.html '//pre[@class="rust fn"]' \
+//      'pub fn grow<const N: usize>(a: [u8; N]) -> [u8; N + 1]'
+pub fn grow<const N: usize>(a: [u8; N]) -> [u8; N+1]
+where
+    [(); N + 1]:,
+{
+    todo!()
+}
+
+// @has foo/fn.double.html '//pre[@class="rust fn"]' \
+//      'pub fn double<const N: usize>() -> Ice<{ N * 2 }>'
+pub fn double<const N: usize>() -> Ice<{N*2}>
+where
+    [(); { N * 2 } + 1]:,
+{
+    Ice
+}
+
+pub mod consts {
+    pub const EXTRA: usize = 4;
+}
+
+use consts::EXTRA;
+
+// Constants are printed by their full path, however they were named.
+// @has foo/fn.pad.html '//pre[@class="rust fn"]' \
+//      'pub fn pad<const N: usize>() -> [u8; consts::EXTRA * (N + 1)]'
+pub fn pad<const N: usize>() -> [u8; EXTRA * (N + 1)]
+where
+    [(); EXTRA * (N + 1)]:,
+{
+    todo!()
+}
@@ -0,0 +1,25 @@
+// compile-flags: -Z unstable-options --crate-stats
+
+#![crate_name = "foo"]
+
+// @has foo/stats.json '"fn":{"total":2,"documented":1}'
+// @has - '"struct":{"total":1,"documented":0}'
+// @has - '"largest_modules":[{"path":"foo","items":4},{"path":"foo::inner","items":2}]'
+
+// @has foo/stats.html '//h1' 'Crate statistics'
+// @has - '//table[@class="crate-stats"]//tr' 'fn21 (50.0%)'
+// @has - '//*[@id="largest-modules"]/following-sibling::ol/li' 'foo::inner: 2 items'
+
+/// Documented.
+pub fn documented() {}
+
+pub fn undocumented() {}
+
+pub struct S;
+
+pub mod inner {
+    pub const A: u8 = 0;
+    pub const B: u8 = 1;
+
+    mod private {}
+}
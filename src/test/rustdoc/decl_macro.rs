@@ -2,16 +2,12 @@
 
 #![feature(decl_macro)]
 
-// @has decl_macro/macro.my_macro.html //pre 'pub macro my_macro() {'
-// @has - //pre '...'
-// @has - //pre '}'
+// @has decl_macro/macro.my_macro.html //pre 'pub macro my_macro() { ... }'
 pub macro my_macro() {
 
 }
 
-// @has decl_macro/macro.my_macro_2.html //pre 'pub macro my_macro_2($($tok:tt)*) {'
-// @has - //pre '...'
-// @has - //pre '}'
+// @has decl_macro/macro.my_macro_2.html //pre 'pub macro my_macro_2($($tok:tt)*) { ... }'
 pub macro my_macro_2($($tok:tt)*) {
 
 }
@@ -33,21 +29,19 @@
     }
 }
 
-// @has decl_macro/macro.by_example_single.html //pre 'pub macro by_example_single($foo:expr) {'
-// @has - //pre '...'
-// @has - //pre '}'
+// @has decl_macro/macro.by_example_single.html //pre 'pub macro by_example_single($foo:expr) { ... }'
 pub macro by_example_single {
     ($foo:expr) => {}
 }
 
 mod a {
     mod b {
-        // @has decl_macro/a/b/macro.by_example_vis.html //pre 'pub(super) macro by_example_vis($foo:expr) {'
+        // @has decl_macro/a/b/macro.by_example_vis.html //pre 'pub(super) macro by_example_vis($foo:expr) { ... }'
         pub(in super) macro by_example_vis {
             ($foo:expr) => {}
         }
         mod c {
-            // @has decl_macro/a/b/c/macro.by_example_vis_named.html //pre 'pub(in a) macro by_example_vis_named($foo:expr) {'
+            // @has decl_macro/a/b/c/macro.by_example_vis_named.html //pre 'pub(in a) macro by_example_vis_named($foo:expr) { ... }'
             pub(in a) macro by_example_vis_named {
                 ($foo:expr) => {}
             }
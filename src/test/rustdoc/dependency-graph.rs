@@ -0,0 +1,28 @@
+// compile-flags: -Z unstable-options --dependency-graph json
+// edition:2018
+
+#![crate_name = "foo"]
+
+// @has foo.dependencies.json \
+//      '{"from":"crate::Wrapper","to":"crate::inner::Inner","kind":"uses"}'
+// @has - '{"from":"crate::reexports","to":"crate::inner::Inner","kind":"reexports"}'
+// @has - '{"from":"crate::Trait::method","to":"crate::Wrapper","kind":"uses"}'
+// Items which aren't documented aren't part of the graph.
+// @!has - '"from":"crate::private"'
+
+pub struct Wrapper(pub inner::Inner);
+
+pub trait Trait {
+    fn method(&self) -> Wrapper;
+}
+
+pub mod inner {
+    pub struct Inner;
+}
+
+pub mod reexports {
+    #[doc(no_inline)]
+    pub use crate::inner::Inner;
+}
+
+fn private(_: Wrapper) {}
@@ -0,0 +1,31 @@
+// Methods taking `&mut self` are only reachable through a chain of `Deref` impls if every type
+// along the chain implements `DerefMut`.
+
+#![crate_name = "foo"]
+
+use std::ops::{Deref, DerefMut};
+
+// @has 'foo/struct.ReadOnly.html'
+// @has '-' '//*[@class="impl-items"]//*[@id="method.to_uppercase"]' 'pub fn to_uppercase(&self)'
+// @!has '-' '//*[@class="impl-items"]//*[@id="method.make_ascii_uppercase"]' 'make_ascii_uppercase'
+// @!has '-' '//*[@class="sidebar-elems"]//a[@href="#method.make_ascii_uppercase"]' 'make_ascii_uppercase'
+pub struct ReadOnly(String);
+
+impl Deref for ReadOnly {
+    type Target = String;
+    fn deref(&self) -> &String { &self.0 }
+}
+
+// @has 'foo/struct.ReadWrite.html'
+// @has '-' '//*[@class="impl-items"]//*[@id="method.make_ascii_uppercase"]' 'make_ascii_uppercase'
+// @has '-' '//*[@class="sidebar-elems"]//a[@href="#method.make_ascii_uppercase"]' 'make_ascii_uppercase'
+pub struct ReadWrite(String);
+
+impl Deref for ReadWrite {
+    type Target = String;
+    fn deref(&self) -> &String { &self.0 }
+}
+
+impl DerefMut for ReadWrite {
+    fn deref_mut(&mut self) -> &mut String { &mut self.0 }
+}
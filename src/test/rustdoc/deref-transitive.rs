@@ -0,0 +1,20 @@
+// Methods reachable through a chain of `Deref` impls ending in a primitive type must be
+// documented, even if only the first type in the chain is local.
+// For other recursive `Deref` cases, look at `deref-recursive.rs`.
+
+// @has 'foo/struct.MyBox.html'
+// @has '-' '//*[@id="deref-methods-String"]' 'Methods from Deref<Target = String>'
+// @has '-' '//*[@class="impl-items"]//*[@id="method.capacity"]' 'pub fn capacity(&self)'
+// @has '-' '//*[@id="deref-methods-str"]' 'Methods from Deref<Target = str>'
+// @has '-' '//*[@class="impl-items"]//*[@id="method.to_uppercase"]' 'pub fn to_uppercase(&self)'
+
+#![crate_name = "foo"]
+
+use std::ops::Deref;
+
+pub struct MyBox(String);
+
+impl Deref for MyBox {
+    type Target = String;
+    fn deref(&self) -> &String { &self.0 }
+}
@@ -0,0 +1,43 @@
+// Tests that items inlined through re-exports keep the `cfg`s of the modules defining and
+// re-exporting them.
+
+// aux-build:reexport-cfg.rs
+
+#![feature(doc_cfg)]
+#![crate_name = "foo"]
+
+extern crate reexport_cfg;
+
+#[doc(cfg(feature = "outer"))]
+pub mod outer {
+    #[doc(cfg(feature = "inner"))]
+    pub mod inner {
+        pub struct Deep;
+    }
+}
+
+mod private {
+    pub struct Chained;
+}
+
+#[doc(cfg(feature = "middle"))]
+pub mod middle {
+    pub use crate::private::Chained;
+}
+
+// @has foo/struct.Deep.html
+// @has - '//*[@id="main-content"]/*[@class="item-info"]/*[@class="stab portability"]' \
+//  'Available on crate features inner and outer only.'
+#[doc(inline)]
+pub use outer::inner::Deep;
+
+// @has foo/struct.Chained.html
+// @has - '//*[@id="main-content"]/*[@class="item-info"]/*[@class="stab portability"]' \
+//  'Available on crate feature middle only.'
+#[doc(inline)]
+pub use middle::Chained;
+
+// @has foo/struct.External.html
+// @has - '//*[@id="main-content"]/*[@class="item-info"]/*[@class="stab portability"]' \
+//  'Available on crate feature ext only.'
+pub use reexport_cfg::ext::External;
@@ -0,0 +1,12 @@
+#![crate_name = "foo"]
+
+pub struct Wrapper<T>(pub T);
+
+// @has foo/fn.vec.html '//*[@class="rust fn"]' 'pub fn vec(Wrapper<Vec<u8>>(v): Wrapper<Vec<u8>>)'
+pub fn vec(Wrapper::<Vec<u8>>(v): Wrapper<Vec<u8>>) {}
+
+// @has foo/fn.nested.html '//*[@class="rust fn"]' 'pub fn nested(Wrapper<Wrapper<Wrapper<Wrapper<..>>>>(w):'
+pub fn nested(
+    Wrapper::<Wrapper<Wrapper<Wrapper<u8>>>>(w): Wrapper<Wrapper<Wrapper<Wrapper<u8>>>>,
+) {
+}
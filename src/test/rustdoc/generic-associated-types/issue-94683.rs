@@ -8,6 +8,6 @@ pub trait Trait {
 // Make sure that the elided lifetime shows up
 
 // @has foo/type.T.html
-// @has - "pub type T = "
+// @has - '//pre' 'pub type T = '
 // @has - "&lt;'_&gt;"
 pub type T = fn(&<() as Trait>::Gat<'_>);
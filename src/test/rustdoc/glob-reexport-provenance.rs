@@ -0,0 +1,36 @@
+// ignore-tidy-linelength
+// aux-build:glob-reexport-provenance.rs
+// build-aux-docs
+// ignore-cross-compile
+
+#![crate_name = "foo"]
+
+extern crate glob_reexport_provenance;
+
+// @has 'foo/struct.Defined.html'
+// @has - '//*[@class="stab reexport"]' 'Re-exported from glob_reexport_provenance::prelude, originally defined in glob_reexport_provenance::inner'
+// The module defining an item doesn't re-export it.
+// @has 'foo/struct.Direct.html'
+// @!has - '//*[@class="stab reexport"]' ''
+pub use glob_reexport_provenance::prelude::*;
+
+// The items of the crate shadow the ones of a glob import.
+// @count 'foo/index.html' '//a[@href="struct.Shadowed.html"]' 1
+// @has 'foo/struct.Shadowed.html' '//*[@class="docblock"]' 'The local one.'
+/// The local one.
+pub struct Shadowed;
+
+mod private {
+    /// The one from the glob.
+    pub fn shadowed_fn() {}
+
+    pub fn kept_fn() {}
+}
+
+// @count 'foo/index.html' '//a[@href="fn.shadowed_fn.html"]' 1
+// @has 'foo/fn.shadowed_fn.html' '//*[@class="docblock"]' 'The local one.'
+// @has 'foo/fn.kept_fn.html'
+pub use private::*;
+
+/// The local one.
+pub fn shadowed_fn() {}
@@ -0,0 +1,25 @@
+// Checks that long `impl Trait` arguments are collapsed to their first bound, with their full
+// types shown below the signature.
+
+#![crate_name = "foo"]
+
+// @has foo/fn.long.html
+// @has - '//pre[@class="rust fn"]' "pub fn long(_names: impl Iterator<…> + …)"
+// @has - '//pre[@class="rust fn"]//span[@class="impl-trait-arg"]' "impl Iterator<…> + …"
+// @!has - '//pre[@class="rust fn"]//details' ''
+// @has - '//details[@class="rustdoc-toggle impl-trait-args"]//pre' \
+//      "_names: impl Iterator<Item = impl AsRef<str>> + Send + 'static"
+pub fn long(_names: impl Iterator<Item = impl AsRef<str>> + Send + 'static) {}
+
+// @has foo/fn.short.html
+// @!has - '//span[@class="impl-trait-arg"]' ''
+// @!has - '//details[@class="rustdoc-toggle impl-trait-args"]' ''
+pub fn short(_name: impl AsRef<str>) {}
+
+// Only arguments are collapsed.
+// @has foo/fn.ret.html
+// @!has - '//span[@class="impl-trait-arg"]' ''
+// @!has - '//details[@class="rustdoc-toggle impl-trait-args"]' ''
+pub fn ret() -> impl Iterator<Item = impl AsRef<str>> + Send + 'static {
+    std::iter::empty::<&'static str>()
+}
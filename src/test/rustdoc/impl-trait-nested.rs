@@ -0,0 +1,16 @@
+// ignore-tidy-linelength
+
+#![crate_name = "foo"]
+
+use std::fmt::Display;
+
+// @has foo/fn.nested_arg.html '//pre[@class="rust fn"]' 'pub fn nested_arg(_x: impl Iterator<Item = impl Display>)'
+pub fn nested_arg(_x: impl Iterator<Item = impl Display>) {}
+
+pub struct Foo;
+
+impl Foo {
+    // @has foo/struct.Foo.html '//*[@id="method.nested_method"]//h4[@class="code-header"]' \
+    //      'pub fn nested_method(&self, _x: impl IntoIterator<Item = impl Into<u32>>)'
+    pub fn nested_method(&self, _x: impl IntoIterator<Item = impl Into<u32>>) {}
+}
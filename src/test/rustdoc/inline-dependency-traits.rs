@@ -0,0 +1,34 @@
+// aux-build:dependency-traits.rs
+// compile-flags: -Z unstable-options --inline-dependency-traits
+
+#![crate_name = "foo"]
+
+extern crate dependency_traits;
+
+// @has foo/dependency_traits/index.html
+// @has - '//*[@class="docblock"]' 'Traits to implement.'
+// @has foo/dependency_traits/trait.Greet.html
+// @has - '//*[@class="docblock"]' 'Says hello.'
+// The implementations from both crates are listed.
+// @has - '//*[@id="implementors-list"]//h3[@class="code-header in-band"]' 'impl Greet for Person'
+// @has - '//*[@id="implementors-list"]//h3[@class="code-header in-band"]' 'impl Greet for World'
+// @!has foo/dependency_traits/trait.Unused.html
+
+// The implementation links to the local page of the trait.
+// @has foo/struct.Person.html
+// @has - '//a[@href="dependency_traits/trait.Greet.html"]' 'Greet'
+pub struct Person;
+
+impl dependency_traits::Greet for Person {
+    fn greet(&self) -> String {
+        "hello".to_owned()
+    }
+}
+
+// Standard library traits keep linking to the documentation of the standard library.
+// @!has foo/core/index.html
+impl Clone for Person {
+    fn clone(&self) -> Self {
+        Person
+    }
+}
@@ -0,0 +1,23 @@
+use std::collections::hash_map::RandomState;
+use std::collections::HashMap;
+
+#[derive(Default)]
+pub struct MyHasher;
+
+pub struct Pair<A, B = u8>(pub A, pub B);
+
+pub fn default_hasher() -> HashMap<u8, u8, RandomState> {
+    HashMap::new()
+}
+
+pub fn custom_hasher() -> HashMap<u8, u8, MyHasher> {
+    HashMap::with_hasher(MyHasher)
+}
+
+pub fn pair_default() -> Pair<u16, u8> {
+    Pair(0, 0)
+}
+
+pub fn pair_custom() -> Pair<u16, u16> {
+    Pair(0, 0)
+}
@@ -0,0 +1,11 @@
+pub fn returns_dyn() -> Box<dyn Fn(u8, u16) -> u32> {
+    Box::new(|a, b| a as u32 + b as u32)
+}
+
+pub fn returns_impl() -> impl FnMut(u8) -> bool {
+    |a| a == 0
+}
+
+pub fn returns_unit() -> Box<dyn FnOnce(u8)> {
+    Box::new(|_| {})
+}
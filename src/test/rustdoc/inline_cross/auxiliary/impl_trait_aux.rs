@@ -19,6 +19,12 @@ pub async fn async_fn() {}
 
 impl Foo {
     pub fn method<'a>(_x: impl Clone + Into<Vec<u8>> + 'a) {}
+
+    pub fn nested_arg(&self, _x: impl IntoIterator<Item = impl Into<u32>>) {}
+
+    pub fn nested_ret(&self) -> impl Iterator<Item = impl Clone> {
+        std::iter::empty::<u8>()
+    }
 }
 
 pub struct Bar;
@@ -0,0 +1,22 @@
+// aux-build:default-generic-args.rs
+
+// Generic arguments which are equal to the default of their parameter are not shown.
+
+#![crate_name = "foo"]
+
+extern crate default_generic_args;
+
+// @has foo/fn.default_hasher.html '//pre[@class="rust fn"]' \
+// 'pub fn default_hasher() -> HashMap<u8, u8>'
+pub use default_generic_args::default_hasher;
+
+// @has foo/fn.custom_hasher.html '//pre[@class="rust fn"]' \
+// 'pub fn custom_hasher() -> HashMap<u8, u8, MyHasher>'
+pub use default_generic_args::custom_hasher;
+
+// @has foo/fn.pair_default.html '//pre[@class="rust fn"]' 'pub fn pair_default() -> Pair<u16>'
+pub use default_generic_args::pair_default;
+
+// @has foo/fn.pair_custom.html '//pre[@class="rust fn"]' \
+// 'pub fn pair_custom() -> Pair<u16, u16>'
+pub use default_generic_args::pair_custom;
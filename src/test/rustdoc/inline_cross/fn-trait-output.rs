@@ -0,0 +1,20 @@
+// aux-build:fn-trait-output.rs
+
+// The return type of `Fn` traits comes from the `Output` projection and must be shown.
+
+#![crate_name = "foo"]
+
+extern crate fn_trait_output;
+
+// @has foo/fn.returns_dyn.html '//pre[@class="rust fn"]' \
+// 'pub fn returns_dyn() -> Box<dyn Fn(u8, u16) -> u32>'
+pub use fn_trait_output::returns_dyn;
+
+// @has foo/fn.returns_impl.html '//pre[@class="rust fn"]' \
+// 'pub fn returns_impl() -> impl FnMut(u8) -> bool'
+pub use fn_trait_output::returns_impl;
+
+// @has foo/fn.returns_unit.html '//pre[@class="rust fn"]' \
+// 'pub fn returns_unit() -> Box<dyn FnOnce(u8)>'
+// @!has - '//pre[@class="rust fn"]' '-> ()'
+pub use fn_trait_output::returns_unit;
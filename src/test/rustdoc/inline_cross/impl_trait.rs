@@ -4,7 +4,7 @@
 extern crate impl_trait_aux;
 
 // @has impl_trait/fn.func.html
-// @has - '//pre[@class="rust fn"]' "pub fn func<'a>(_x: impl Clone + Into<Vec<u8, Global>> + 'a)"
+// @has - '//pre[@class="rust fn"]' "pub fn func<'a>(_x: impl Clone + Into<Vec<u8>> + 'a)"
 // @!has - '//pre[@class="rust fn"]' 'where'
 pub use impl_trait_aux::func;
 
@@ -31,8 +31,10 @@
 pub use impl_trait_aux::async_fn;
 
 // @has impl_trait/struct.Foo.html
-// @has - '//*[@id="method.method"]//h4[@class="code-header"]' "pub fn method<'a>(_x: impl Clone + Into<Vec<u8, Global>> + 'a)"
+// @has - '//*[@id="method.method"]//h4[@class="code-header"]' "pub fn method<'a>(_x: impl Clone + Into<Vec<u8>> + 'a)"
 // @!has - '//*[@id="method.method"]//h4[@class="code-header"]' 'where'
+// @has - '//*[@id="method.nested_arg"]//h4[@class="code-header"]' "pub fn nested_arg(&self, _x: impl IntoIterator<Item = impl Into<u32>>)"
+// @has - '//*[@id="method.nested_ret"]//h4[@class="code-header"]' "pub fn nested_ret(&self) -> impl Iterator<Item = impl Clone>"
 pub use impl_trait_aux::Foo;
 
 // @has impl_trait/struct.Bar.html
@@ -0,0 +1,19 @@
+// aux-build:proc_macro.rs
+// build-aux-docs
+
+// Tests that a derive macro links to the trait of the same name next to it.
+
+extern crate some_macros;
+
+// @has proc_macro_derived_trait/derive.SomeDerive.html
+// @has - '//pre[@class="rust derive"]' '// Implements the trait:'
+// @has - '//pre[@class="rust derive"]//a[@class="trait"]/@href' 'trait.SomeDerive.html'
+pub use some_macros::SomeDerive;
+
+pub trait SomeDerive {}
+
+pub mod no_trait {
+    // @has proc_macro_derived_trait/no_trait/derive.SomeDerive.html
+    // @!has - '//pre[@class="rust derive"]' '// Implements the trait:'
+    pub use some_macros::SomeDerive;
+}
@@ -0,0 +1,12 @@
+#![crate_name = "relative"]
+
+pub mod outer {
+    pub mod inner {
+        //! Inner docs linking to [self::Item].
+
+        /// Links to [super::Sibling].
+        pub struct Item;
+    }
+
+    pub struct Sibling;
+}
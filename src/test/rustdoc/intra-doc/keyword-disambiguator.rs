@@ -0,0 +1,10 @@
+#![deny(rustdoc::broken_intra_doc_links)]
+#![crate_name = "foo"]
+
+// @has foo/index.html
+// @has - '//a[@href="{{channel}}/std/keyword.match.html"]' 'match'
+// @has - '//a[@href="{{channel}}/std/keyword.match.html"]' 'matching'
+// @has - '//a[@href="{{channel}}/std/keyword.for.html#for-loops"]' 'for'
+// @has - '//a[@href="{{channel}}/std/primitive.usize.html"]' 'usize'
+//! Keywords can be linked to: [keyword@match], [matching](keyword@match), [keyword@for#for-loops].
+//! Like primitives: [usize].
@@ -0,0 +1,18 @@
+// aux-build:relative-reexport.rs
+// build-aux-docs
+#![crate_name = "foo"]
+extern crate relative;
+
+// Relative paths in the docs of the re-exported item are resolved from its own parent module.
+// @has foo/struct.Item.html '//a[@href="../relative/outer/struct.Sibling.html"]' 'super::Sibling'
+pub use relative::outer::inner::Item;
+
+// Docs added on the re-export are resolved from the module of the `use`, even if the module
+// re-exported has inner docs.
+// @has foo/inner/index.html '//a[@href="../struct.Local.html"]' 'self::Local'
+// @has - '//a' 'self::Item'
+/// Also see [self::Local].
+#[doc(inline)]
+pub use relative::outer::inner;
+
+pub struct Local;
@@ -0,0 +1,12 @@
+// `--item-order` takes precedence over `#![doc(item_order)]`.
+// compile-flags: -Z unstable-options --item-order alphabetical
+#![feature(rustdoc_internals)]
+#![doc(item_order = "source")]
+#![crate_name = "foo"]
+
+pub fn zebra() {}
+
+pub fn aardvark() {}
+
+// @matches 'foo/index.html' '(?s)aardvark.*zebra'
+// @matches 'foo/sidebar-items.js' '"aardvark".*"zebra"'
@@ -0,0 +1,22 @@
+// Checks that `#![doc(item_order = "source")]` lists the items of module pages in the order they
+// are declared in, while still grouping them by kind.
+#![feature(rustdoc_internals)]
+#![doc(item_order = "source")]
+#![crate_name = "foo"]
+
+pub fn zebra() {}
+
+pub mod second {}
+
+pub struct Walrus;
+
+pub fn aardvark() {}
+
+pub mod first {}
+
+pub struct Ant;
+
+// @matches 'foo/index.html' '(?s)second.*first.*Walrus.*Ant.*zebra.*aardvark'
+// @count - '//h2[@id="functions"]' 1
+// @count - '//h2[@id="structs"]' 1
+// @matches 'foo/sidebar-items.js' '"zebra".*"aardvark"'
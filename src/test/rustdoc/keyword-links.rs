@@ -0,0 +1,38 @@
+// ignore-tidy-linelength
+
+#![crate_name = "foo"]
+#![feature(rustdoc_internals)]
+
+// The declaration keywords link to the keyword pages of the current crate when it has them...
+
+// @has foo/fn.bar.html '//pre[@class="rust fn"]//a[@class="keyword"][@href="keyword.fn.html"]' 'fn'
+pub fn bar() {}
+
+// @has foo/struct.Baz.html '//pre[@class="rust struct"]//a[@class="keyword"][@href="keyword.struct.html"]' 'struct'
+pub struct Baz;
+
+pub mod inner {
+    // @has foo/inner/fn.qux.html '//pre[@class="rust fn"]//a[@class="keyword"][@href="../keyword.fn.html"]' 'fn'
+    pub fn qux() {}
+}
+
+// ... and to the ones of `std` otherwise.
+
+// @has foo/enum.Quux.html '//pre[@class="rust enum"]//a[@class="keyword"][@href="{{channel}}/std/keyword.enum.html"]' 'enum'
+pub enum Quux {}
+
+// @has foo/constant.C.html '//pre[@class="rust const"]//a[@class="keyword"][@href="{{channel}}/std/keyword.const.html"]' 'const'
+pub const C: u8 = 0;
+
+// The text of the declaration is unchanged.
+// @has foo/trait.Tr.html '//pre[@class="rust trait"]' 'pub trait Tr'
+// @has - '//pre[@class="rust trait"]//a[@class="keyword"]' 'trait'
+pub trait Tr {}
+
+#[doc(keyword = "fn")]
+/// The `fn` keyword.
+mod fn_keyword {}
+
+#[doc(keyword = "struct")]
+/// The `struct` keyword.
+mod struct_keyword {}
@@ -0,0 +1,20 @@
+#![feature(decl_macro)]
+#![crate_name = "foo"]
+
+// @has foo/macro.pair.html
+// @has - '//pre[@class="rust macro"]//span[@id="arm.0"][@class="macro-arm"]' '() => { ... }'
+// @has - '//pre[@class="rust macro"]//span[@id="arm.1"][@class="macro-arm"]' \
+//      '($a:expr, $b:expr) => { ... }'
+// @!has - '//span[@id="arm.2"]'
+#[macro_export]
+macro_rules! pair {
+    () => { () };
+    ($a:expr, $b:expr) => { ($a, $b) };
+}
+
+// The short form of a decl macro with a single arm still has an anchor.
+// @has foo/macro.single.html
+// @has - '//pre[@class="rust macro"]//span[@id="arm.0"]' '($a:expr) { ... }'
+pub macro single($a:expr) {
+    $a
+}
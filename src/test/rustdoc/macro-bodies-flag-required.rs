@@ -0,0 +1,10 @@
+// Tests that `--document-macro-bodies` is required in order to show macro bodies.
+
+#![crate_name = "foo"]
+
+// @has foo/macro.add.html //pre '($a:expr, $b:expr) => { ... };'
+// @!has foo/macro.add.html //pre '$a + $b'
+#[macro_export]
+macro_rules! add {
+    ($a:expr, $b:expr) => { $a + $b };
+}
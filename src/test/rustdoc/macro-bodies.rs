@@ -0,0 +1,25 @@
+// compile-flags: -Z unstable-options --document-macro-bodies
+// Tests that `--document-macro-bodies` shows the transcriber of each macro arm.
+
+#![crate_name = "foo"]
+#![feature(decl_macro)]
+
+// @has foo/macro.add.html //pre '($a:expr, $b:expr) => { $a + $b };'
+// @has foo/macro.add.html //pre '($a:expr) => { add!($a, 0) };'
+// @!has foo/macro.add.html //pre '{ ... }'
+#[macro_export]
+macro_rules! add {
+    ($a:expr, $b:expr) => { $a + $b };
+    ($a:expr) => { add!($a, 0) };
+}
+
+// @has foo/macro.double.html //pre 'pub macro double($a:expr) { $a * 2 }'
+pub macro double($a:expr) { $a * 2 }
+
+// @has foo/macro.either.html //pre 'pub macro either {'
+// @has - //pre '($a:expr) => { $a },'
+// @has - //pre '($a:expr, $b:expr) => { $b },'
+pub macro either {
+    ($a:expr) => { $a },
+    ($a:expr, $b:expr) => { $b },
+}
@@ -0,0 +1,17 @@
+// Checks that the fragment specifiers of macro metavariables link to the reference.
+
+#![crate_name = "foo"]
+
+// @has foo/macro.make.html
+// @has - '//a[@class="macro-fragment"][@href="{{channel}}/reference/identifiers.html"]' 'ident'
+// @has - '//a[@class="macro-fragment"][@title="a type"]' 'ty'
+// @has - '//a[@class="macro-fragment"][@href="{{channel}}/reference/expressions.html"]' 'expr'
+// @count - '//a[@class="macro-fragment"]' 3
+// The metavariables themselves aren't links.
+// @has - '//span[@class="macro-nonterminal"]' 'name'
+#[macro_export]
+macro_rules! make {
+    ($name:ident: $t:ty = $value:expr) => {
+        let $name: $t = $value;
+    };
+}
@@ -13,7 +13,7 @@
 // @has - '//span[@class="macro-nonterminal"]' '$'
 // @has - '//span[@class="macro-nonterminal"]' 'arg'
 // @has - ':'
-// @has - '//span[@class="ident"]' 'tt'
+// @has - '//a[@class="macro-fragment"]' 'tt'
 // @has - '),'
 // @has - '//span[@class="op"]' '+'
 // @has - ') =&gt; { ... }; }'
@@ -27,7 +27,7 @@ mod mod1 {
     // @has - '//span[@class="macro-nonterminal"]' '$'
     // @has - '//span[@class="macro-nonterminal"]' 'arg'
     // @has - ':'
-    // @has - 'expr'
+    // @has - '//a[@class="macro-fragment"]' 'expr'
     // @has - '),'
     // @has - '+'
     // @has - ') =&gt; { ... }; }'
@@ -0,0 +1,13 @@
+#![feature(rustdoc_internals)]
+#![doc(no_synthetic_impls)]
+#![crate_name = "foo"]
+
+// @has foo/struct.Foo.html
+// @!has - '//*[@id="synthetic-implementations"]' 'Auto Trait Implementations'
+// @!has - '//*[@id="blanket-implementations"]' 'Blanket Implementations'
+pub struct Foo;
+
+// @has foo/enum.Bar.html
+// @!has - '//*[@id="synthetic-implementations"]' 'Auto Trait Implementations'
+// @!has - '//*[@id="blanket-implementations"]' 'Blanket Implementations'
+pub enum Bar {}
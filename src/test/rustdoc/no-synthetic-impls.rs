@@ -0,0 +1,20 @@
+#![feature(rustdoc_internals)]
+#![crate_name = "foo"]
+
+// @has foo/struct.Shown.html
+// @has - '//*[@id="synthetic-implementations"]' 'Auto Trait Implementations'
+// @has - '//*[@id="blanket-implementations"]' 'Blanket Implementations'
+pub struct Shown<T>(T);
+
+// @has foo/struct.Hidden.html
+// @!has - '//*[@id="synthetic-implementations"]' 'Auto Trait Implementations'
+// @!has - '//*[@id="blanket-implementations"]' 'Blanket Implementations'
+// @has - '//*[@id="trait-implementations-list"]//h3[@class="code-header in-band"]' 'impl<T> Clone for Hidden<T>'
+#[doc(no_synthetic_impls)]
+pub struct Hidden<T>(T);
+
+impl<T> Clone for Hidden<T> {
+    fn clone(&self) -> Self {
+        unimplemented!()
+    }
+}
@@ -0,0 +1,16 @@
+// compile-flags: -Z unstable-options --number-format no-separators,exponent
+
+#![crate_name = "foo"]
+
+// @has foo/constant.MILLION.html '//pre[@class="rust const"]' '= 1000 * 1000; // 1000000u32'
+pub const MILLION: u32 = 1000 * 1000;
+
+// @has foo/constant.FLOAT.html '//pre[@class="rust const"]' '= 1000.0 + 500.0; // 1.5e3f64'
+pub const FLOAT: f64 = 1000.0 + 500.0;
+
+pub struct S;
+
+impl S {
+    // @has foo/struct.S.html '//*[@id="associatedconstant.NEG"]' 'const NEG: i64 = -(1 << 20) /* -1048576i64 */'
+    pub const NEG: i64 = -(1 << 20);
+}
@@ -0,0 +1,16 @@
+// aux-build:reexport-from-dependency-inner.rs
+// aux-build:reexport-from-dependency.rs
+// build-aux-docs
+// ignore-cross-compile
+
+// Tests that an item of another crate is linked to when it's only reachable through a re-export
+// in a crate depending on its own.
+
+#![crate_name = "foo"]
+
+extern crate reexport_from_dependency;
+
+// @has foo/fn.inner.html '//a[@class="struct"]' 'Inner'
+pub fn inner() -> reexport_from_dependency::Inner {
+    reexport_from_dependency::Inner
+}
@@ -0,0 +1,16 @@
+// ignore-tidy-linelength
+// aux-build:reexport-provenance.rs
+// build-aux-docs
+// ignore-cross-compile
+
+#![crate_name = "foo"]
+
+extern crate reexport_provenance;
+
+mod hop {
+    pub use reexport_provenance::facade::Defined;
+}
+
+// @has 'foo/struct.Defined.html'
+// @has - '//*[@class="stab reexport"]' 'Re-exported from foo::hop, reexport_provenance::facade, originally defined in reexport_provenance::inner'
+pub use hop::Defined;
@@ -0,0 +1,43 @@
+// compile-flags:--cfg feature="serde" --cfg feature="std" --cfg platform
+
+#![crate_name = "foo"]
+
+// @has 'foo/struct.Serializer.html'
+// @has - '//*[@class="stab features"]' 'Requires the crate feature serde'
+#[cfg(feature = "serde")]
+pub struct Serializer;
+
+impl Serializer {
+    // @has - '//*[@class="stab features"]' 'Requires the crate feature std'
+    #[cfg(feature = "std")]
+    pub fn flush(&self) {}
+}
+
+// The features required by the modules containing an item are listed too.
+#[cfg(all(feature = "std", platform))]
+pub mod io {
+    // @has 'foo/io/fn.read.html'
+    // @has - '//*[@class="stab features"]' 'Requires the crate features std, serde'
+    #[cfg(feature = "serde")]
+    pub fn read() {}
+
+    // @has 'foo/io/fn.write.html'
+    // @has - '//*[@class="stab features"]' 'Requires the crate feature std'
+    pub fn write() {}
+}
+
+// Neither feature of an `any` is required.
+// @has 'foo/fn.either.html'
+// @!has - '//*[@class="stab features"]' ''
+#[cfg(any(feature = "serde", feature = "std"))]
+pub fn either() {}
+
+// Items inlined from a private module require the features of that module.
+#[cfg(feature = "serde")]
+mod private {
+    pub struct Inlined;
+}
+
+// @has 'foo/struct.Inlined.html'
+// @has - '//*[@class="stab features"]' 'Requires the crate feature serde'
+pub use private::Inlined;
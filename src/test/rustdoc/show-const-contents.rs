@@ -48,9 +48,12 @@
 pub const MY_TYPE_WITH_STR: MyTypeWithStr = MyTypeWithStr("show this");
 
 // @has show_const_contents/constant.PI.html '= 3.14159265358979323846264338327950288f32;'
-// @has show_const_contents/constant.PI.html '; // 3.14159274f32'
+// @has show_const_contents/constant.PI.html '; // 3.1415927f32'
 pub use std::f32::consts::PI;
 
+// @has show_const_contents/constant.KILO_F64.html '= 1000.0 + 24.0; // 1_024.0f64'
+pub const KILO_F64: f64 = 1000.0 + 24.0;
+
 // @has show_const_contents/constant.MAX.html '= i32::MAX; // 2_147_483_647i32'
 #[allow(deprecated, deprecated_in_future)]
 pub use std::i32::MAX;
@@ -66,3 +69,51 @@ macro_rules! int_module {
 
 // @has show_const_contents/constant.ESCAPE.html //pre '= r#"<script>alert("ESCAPE");</script>"#;'
 pub const ESCAPE: &str = r#"<script>alert("ESCAPE");</script>"#;
+
+// @has show_const_contents/constant.CONST_CALC_BOOL.html '= !true; // false'
+pub const CONST_CALC_BOOL: bool = !true;
+
+// @has show_const_contents/constant.CONST_CALC_CHAR.html //pre "= ['\\n'][0]; // '\\n'"
+pub const CONST_CALC_CHAR: char = ['\n'][0];
+
+// @has show_const_contents/constant.CONST_CALC_F64.html '= 1.0 / 4.0; // 0.25f64'
+pub const CONST_CALC_F64: f64 = 1.0 / 4.0;
+
+// @has show_const_contents/constant.CONST_CALC_NAN.html '= 0.0 / 0.0; // f32::NAN'
+pub const CONST_CALC_NAN: f32 = 0.0 / 0.0;
+
+// @has show_const_contents/constant.CONST_STR_PATH.html //pre '= CONST_S; // "show this"'
+pub const CONST_STR_PATH: &str = CONST_S;
+
+// @has show_const_contents/constant.CONST_STR_CONCAT.html //pre '= "foobar";'
+pub const CONST_STR_CONCAT: &str = concat!("foo", "bar");
+
+pub enum Color {
+    Red,
+    Green = 10,
+    Blue = -1,
+}
+
+// @has show_const_contents/constant.COLOR_RED.html '= Color::Red;'
+// @!has show_const_contents/constant.COLOR_RED.html '; //'
+pub const COLOR_RED: Color = Color::Red;
+
+// @has show_const_contents/constant.COLOR_GREEN.html '= COLOR_GREEN_ALIAS; // Color::Green'
+pub const COLOR_GREEN: Color = COLOR_GREEN_ALIAS;
+pub const COLOR_GREEN_ALIAS: Color = Color::Green;
+
+// @has show_const_contents/constant.COLOR_BLUE.html '; // Color::Blue'
+pub const COLOR_BLUE: Color = if COLOR_BLUE_FIRST { Color::Blue } else { Color::Red };
+pub const COLOR_BLUE_FIRST: bool = true;
+
+pub mod shades {
+    pub enum Color {
+        Light,
+        Dark,
+    }
+}
+
+// The variant is printed with the path of its enum, which may differ from the one in scope.
+// @has show_const_contents/constant.SHADE.html '; // shades::Color::Dark'
+pub const SHADE: shades::Color =
+    if COLOR_BLUE_FIRST { shades::Color::Dark } else { shades::Color::Light };
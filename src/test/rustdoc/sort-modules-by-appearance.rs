@@ -11,3 +11,13 @@ pub mod module_a {}
 
 // @matches 'sort_modules_by_appearance/index.html' '(?s)module_b.*module_c.*module_a'
 // @matches 'sort_modules_by_appearance/sidebar-items.js' '"module_b".*"module_c".*"module_a"'
+
+pub fn zebra() {}
+
+pub struct Walrus;
+
+pub fn aardvark() {}
+
+// Unlike with `--item-order source`, the items aren't grouped by kind either.
+// @matches 'sort_modules_by_appearance/index.html' '(?s)zebra.*Walrus.*aardvark'
+// @has - '//h2[@id="functions-1"]' 'Functions'
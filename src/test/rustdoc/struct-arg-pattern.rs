@@ -1,10 +1,21 @@
 #![crate_name = "foo"]
 
-struct BodyId {
-    hir_id: usize,
+pub struct Config {
+    pub width: u32,
+    pub height: u32,
+    pub depth: u32,
 }
 
-// @has 'foo/fn.body_owner.html' '//*[@class="rust fn"]' 'pub fn body_owner(_: BodyId)'
-pub fn body_owner(BodyId { hir_id }: BodyId) {
-    // ...
-}
+pub struct Point(pub i32, pub i32, pub i32);
+
+// @has foo/fn.config.html '//*[@class="rust fn"]' 'pub fn config(Config { width, height: h, .. }: Config)'
+pub fn config(Config { width, height: h, .. }: Config) {}
+
+// @has foo/fn.config_unit.html '//*[@class="rust fn"]' 'pub fn config_unit(Config { .. }: Config)'
+pub fn config_unit(Config { .. }: Config) {}
+
+// @has foo/fn.point.html '//*[@class="rust fn"]' 'pub fn point(Point(x, _, z): Point)'
+pub fn point(Point(x, _, z): Point) {}
+
+// @has foo/fn.point_rest.html '//*[@class="rust fn"]' 'pub fn point_rest(Point(x, ..): Point)'
+pub fn point_rest(Point(x, ..): Point) {}
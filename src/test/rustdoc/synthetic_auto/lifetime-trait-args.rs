@@ -0,0 +1,28 @@
+// Bounds on the same trait which only differ in their lifetime arguments must not be merged
+// when deduplicating projection bounds.
+
+pub trait MyTrait<'a> {
+    type MyItem;
+}
+
+pub struct Inner<'a, T> {
+    field: &'a T,
+}
+
+unsafe impl<'a, T> Send for Inner<'a, T>
+where
+    T: MyTrait<'static, MyItem = bool>,
+    T: MyTrait<'a>,
+{
+}
+
+// @has lifetime_trait_args/struct.Foo.html
+// @has - '//*[@id="synthetic-implementations-list"]//h3[@class="code-header in-band"]' \
+// "MyTrait<'static, MyItem = bool>"
+// @has - '//*[@id="synthetic-implementations-list"]//h3[@class="code-header in-band"]' \
+// "MyTrait<'c>"
+// @!has - '//*[@id="synthetic-implementations-list"]//h3[@class="code-header in-band"]' \
+// "MyTrait<'static>"
+pub struct Foo<'c, K> {
+    inner_field: Inner<'c, K>,
+}
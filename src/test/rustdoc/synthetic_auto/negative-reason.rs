@@ -0,0 +1,35 @@
+// ignore-tidy-linelength
+
+use std::cell::Cell;
+use std::rc::Rc;
+
+// @has negative_reason/struct.Foo.html
+// @has - '//*[@id="synthetic-implementations-list"]//*[@class="stab auto-trait-blocker"]' \
+// 'Not implemented because of the field rc: Rc<T>'
+// @has - '//*[@id="synthetic-implementations-list"]//*[@class="stab auto-trait-blocker"]' \
+// 'Not implemented because of the fields rc: Rc<T>, cell: Cell<u8>'
+// @!has - '//*[@id="synthetic-implementations-list"]//*[@class="stab auto-trait-blocker"]' 'plain'
+pub struct Foo<T> {
+    pub rc: Rc<T>,
+    pub cell: Cell<u8>,
+    pub plain: u8,
+}
+
+// The private fields are counted, not named.
+// @has negative_reason/struct.Bar.html
+// @has - '//*[@id="synthetic-implementations-list"]//*[@class="stab auto-trait-blocker"]' \
+// 'Not implemented because of the field rc: Rc<u8> and a private field'
+// @!has - '//*[@id="synthetic-implementations-list"]//*[@class="stab auto-trait-blocker"]' 'cell'
+pub struct Bar {
+    pub rc: Rc<u8>,
+    cell: Cell<u8>,
+}
+
+// @has negative_reason/struct.Baz.html
+// @has - '//*[@id="synthetic-implementations-list"]//*[@class="stab auto-trait-blocker"]' \
+// 'Not implemented because of private fields'
+// @!has - '//*[@id="synthetic-implementations-list"]//*[@class="stab auto-trait-blocker"]' 'Rc'
+pub struct Baz {
+    rc: Rc<u8>,
+    other_rc: Rc<u16>,
+}
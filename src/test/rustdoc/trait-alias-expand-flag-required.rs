@@ -0,0 +1,10 @@
+// Tests that `--expand-trait-aliases` is required in order to show what trait aliases expand to.
+
+#![feature(trait_alias)]
+#![crate_name = "foo"]
+
+pub trait Alias = Clone + Default;
+
+// @has foo/fn.f.html '//pre[@class="rust fn"]' 'T: Alias'
+// @!has - '//pre[@class="rust fn"]' 'Clone'
+pub fn f<T: Alias>() {}
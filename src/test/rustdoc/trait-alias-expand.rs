@@ -0,0 +1,23 @@
+// aux-build:trait-alias-mention.rs
+// build-aux-docs
+// compile-flags: -Z unstable-options --expand-trait-aliases
+
+#![feature(trait_alias)]
+#![crate_name = "foo"]
+
+extern crate trait_alias_mention;
+
+// Trait aliases from other crates are inlined like traits.
+// @has foo/traitalias.SomeAlias.html
+// @has - '//pre[@class="rust trait-alias"]' 'trait SomeAlias = Debug + Copy;'
+pub use trait_alias_mention::SomeAlias;
+
+pub trait LocalAlias = Clone + Default;
+
+// @has foo/fn.local.html '//pre[@class="rust fn"]' 'T: LocalAlias /* = Clone + Default */'
+// @has - '//pre[@class="rust fn"]//a[@href="traitalias.LocalAlias.html"]' 'LocalAlias'
+// @has - '//pre[@class="rust fn"]//a[@class="trait"]' 'Default'
+pub fn local<T: LocalAlias>() {}
+
+// @has foo/fn.external.html '//pre[@class="rust fn"]' 'T: SomeAlias /* = Debug + Copy */'
+pub fn external<T: trait_alias_mention::SomeAlias>() {}
@@ -0,0 +1,30 @@
+#![crate_name = "foo"]
+
+pub struct Wrapper<T>(T);
+
+impl<T> Wrapper<T> {
+    pub fn unwrap_it(self) -> T {
+        self.0
+    }
+}
+
+impl Clone for Wrapper<u8> {
+    fn clone(&self) -> Self {
+        Wrapper(self.0)
+    }
+}
+
+// @has 'foo/type.Direct.html'
+// @!has - '//h2[@id="aliased-type"]' 'Aliased type'
+// @has - '//h2[@id="aliased-type-implementations"]' 'Implementations on Wrapper<u8>'
+// @has - '//*[@id="method.unwrap_it"]' 'pub fn unwrap_it(self) -> T'
+// @!has - '//*[@id="method.clone"]' 'fn clone'
+pub type Direct = Wrapper<u8>;
+
+// @has 'foo/type.Outer.html'
+// @has - '//h2[@id="aliased-type"]' 'Aliased type'
+// @has - '//pre[@class="rust typedef"]' 'Wrapper<u32>'
+// @has - '//h2[@id="aliased-type-implementations"]' 'Implementations on Wrapper<u32>'
+pub type Outer = Direct2;
+
+pub type Direct2 = Wrapper<u32>;
@@ -0,0 +1,20 @@
+// Links to re-exported enum variants should point to the variant's anchor on the enum page,
+// not to the top of the page.
+
+#![crate_name = "foo"]
+
+pub mod inner {
+    pub enum Local {
+        A,
+        B,
+    }
+}
+
+// @has foo/index.html
+// @has - '//*[@id="reexport.None"]//a[@href="{{channel}}/core/option/enum.Option.html#variant.None"]' 'None'
+#[doc(no_inline)]
+pub use std::option::Option::None;
+
+// @has - '//*[@id="reexport.A"]//a[@href="inner/enum.Local.html#variant.A"]' 'A'
+#[doc(no_inline)]
+pub use inner::Local::A;
@@ -0,0 +1,23 @@
+#![crate_name = "foo"]
+
+use std::fmt::{Debug, Display};
+use std::hash::Hash;
+
+// Predicates too long for a single line get one bound per line.
+// @has foo/fn.heavy.html
+// @count - '//pre[@class="rust fn"]//span[@class="where fmt-newline"]/br' 6
+// @has - '//pre[@class="rust fn"]//span[@class="where fmt-newline"]' '+ Hash'
+pub fn heavy<T, U>(_t: T, _u: U)
+where
+    T: Clone + Debug + Display + Hash + Iterator<Item = (u8, u16, u32, u64, u128)>,
+    U: Clone,
+{
+}
+
+// @has foo/fn.light.html
+// @count - '//pre[@class="rust fn"]//span[@class="where fmt-newline"]/br' 1
+pub fn light<T>(_t: T)
+where
+    T: Clone + Debug,
+{
+}
@@ -0,0 +1,10 @@
+// compile-flags: -Z unstable-options --write-index-part
+
+#![crate_name = "foo"]
+
+// @has index-parts/foo.json '"krate":"foo"'
+// @has - '"search_index":"\"foo\":'
+// @has - 'implementors/core/clone/trait.Clone.js'
+
+#[derive(Clone)]
+pub struct Bar;
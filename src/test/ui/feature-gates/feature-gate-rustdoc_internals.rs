@@ -1,3 +1,4 @@
+#![doc(item_order = "source")] //~ ERROR: `#[doc(item_order)]` is experimental
 #[doc(keyword = "match")] //~ ERROR: `#[doc(keyword)]` is meant for internal use only
 /// wonderful
 mod foo {}
@@ -7,4 +8,7 @@ trait Mine {}
 #[doc(tuple_variadic)]  //~ ERROR: `#[doc(tuple_variadic)]` is meant for internal use only
 impl<T> Mine for (T,) {}
 
+#[doc(no_synthetic_impls)] //~ ERROR: `#[doc(no_synthetic_impls)]` is experimental
+pub struct Opaque;
+
 fn main() {}
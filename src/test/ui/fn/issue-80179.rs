@@ -1,6 +1,6 @@
 // Functions with a type placeholder `_` as the return type should
 // show a function pointer suggestion when given a function item
-// and suggest how to return closures correctly from a function.
+// and an `impl Fn` suggestion when given a closure.
 // This is a regression test of #80179
 
 fn returns_i32() -> i32 {
@@ -18,10 +18,26 @@ fn returns_fn_ptr() -> _ {
 fn returns_closure() -> _ {
 //~^ ERROR the placeholder `_` is not allowed within types on item signatures for return types [E0121]
 //~| NOTE not allowed in type signatures
-//~| HELP consider using an `Fn`, `FnMut`, or `FnOnce` trait bound
-//~| NOTE for more information on `Fn` traits and closure types, see
-//        https://doc.rust-lang.org/book/ch13-01-closures.html
+//~| HELP replace with the correct return type
+//~| SUGGESTION impl Fn() -> i32
     || 0
 }
 
+fn returns_fn_ptr_in_tuple() -> _ {
+//~^ ERROR the placeholder `_` is not allowed within types on item signatures for return types [E0121]
+//~| NOTE not allowed in type signatures
+//~| HELP replace with the correct return type
+//~| SUGGESTION (fn() -> i32, u8)
+    (returns_i32, 0u8)
+}
+
+// The lifetime of the captured reference is missing from the suggestion, which is only a guess.
+fn returns_closure_capturing_reference(x: &i32) -> _ {
+//~^ ERROR the placeholder `_` is not allowed within types on item signatures for return types [E0121]
+//~| NOTE not allowed in type signatures
+//~| HELP replace with the correct return type
+//~| SUGGESTION impl Fn() -> i32
+    move || *x
+}
+
 fn main() {}
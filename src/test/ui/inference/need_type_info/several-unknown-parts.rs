@@ -0,0 +1,6 @@
+// Point at each part of the type of a binding which couldn't be inferred.
+
+fn main() {
+    let x = (Vec::new(), None);
+    //~^ ERROR type annotations needed
+}
@@ -0,0 +1,16 @@
+// run-rustfix
+#![allow(dead_code)]
+
+fn first<T>(x: &[T]) -> &'static T {
+    &x[0]
+    //~^ ERROR lifetime may not live long enough
+}
+
+struct Holder(u32);
+
+fn field(h: &Holder) -> &'static u32 {
+    &h.0
+    //~^ ERROR lifetime may not live long enough
+}
+
+fn main() {}
@@ -0,0 +1,8 @@
+// compile-flags: -Z type-label-length=20
+// With `-Z type-label-length`, the ADTs with type arguments which are short enough don't get a
+// note spelling out the mismatched types.
+
+fn main() {
+    let _: Option<u8> = 42u8; //~ ERROR mismatched types
+    let _: Result<Vec<u8>, String> = 42u8; //~ ERROR mismatched types
+}
@@ -0,0 +1,8 @@
+// When the value is already `Clone`, cloning it where it's moved is suggested along with the
+// `Copy` bound.
+
+fn duplicate<T: Clone>(t: T) -> (T, T) {
+    (t, t) //~ ERROR use of moved value: `t`
+}
+
+fn main() {}
@@ -0,0 +1,10 @@
+// run-rustfix
+#![allow(dead_code)]
+
+fn duplicate_item<I: Iterator>(mut it: I) -> (I::Item, I::Item) {
+    //~^ HELP consider restricting the associated type `<I as Iterator>::Item`
+    let x = it.next().unwrap();
+    (x, x) //~ use of moved value: `x`
+}
+
+fn main() {}
@@ -0,0 +1,11 @@
+// run-rustfix
+#![allow(dead_code)]
+#![feature(associated_type_bounds)]
+
+fn duplicate_item<I: Iterator>(mut it: I) -> (I::Item, I::Item) {
+    //~^ HELP consider further restricting this bound
+    let x = it.next().unwrap();
+    (x, x) //~ use of moved value: `x`
+}
+
+fn main() {}
@@ -0,0 +1,16 @@
+// run-rustfix
+#![allow(dead_code)]
+#![feature(associated_type_bounds)]
+
+trait Convert<T> {
+    type Output;
+    fn convert(&self, t: T) -> Self::Output;
+}
+
+fn convert_twice<C: Convert<Vec<u8>>>(c: C, v: Vec<u8>) -> (C::Output, C::Output) {
+    //~^ HELP consider restricting the associated type `<C as Convert<Vec<u8>>>::Output`
+    let x = c.convert(v);
+    (x, x) //~ use of moved value: `x`
+}
+
+fn main() {}
@@ -0,0 +1,8 @@
+// check-pass
+// compile-flags: -Z allow-doc-primitive
+
+#[doc(primitive = "usize")]
+/// Some docs
+mod usize {}
+
+fn main() {}
@@ -0,0 +1,31 @@
+// force-host
+// no-prefer-dynamic
+#![crate_type = "proc-macro"]
+
+extern crate proc_macro;
+
+use proc_macro::{Group, Span, TokenStream, TokenTree};
+
+// Returns the input with all its tokens resolved at the call site, so their spans come from the
+// expansion while still pointing into the input.
+#[proc_macro]
+pub fn call_site_spans(input: TokenStream) -> TokenStream {
+    respan(input)
+}
+
+fn respan(stream: TokenStream) -> TokenStream {
+    stream
+        .into_iter()
+        .map(|tt| {
+            let span = tt.span().resolved_at(Span::call_site());
+            let mut tt = match tt {
+                TokenTree::Group(group) => {
+                    TokenTree::Group(Group::new(group.delimiter(), respan(group.stream())))
+                }
+                tt => tt,
+            };
+            tt.set_span(span);
+            tt
+        })
+        .collect()
+}
@@ -0,0 +1,12 @@
+// Check that the `'_` bound is suggested for trait objects nested in the return type.
+
+use std::any::Any;
+
+fn foo<T: Any>(value: &T) -> (u8, [Box<dyn Any>; 1]) {
+    (0, [Box::new(value) as Box<dyn Any>])
+    //~^ ERROR lifetime may not live long enough
+}
+
+fn main() {
+    let _ = foo(&5);
+}
@@ -0,0 +1,9 @@
+// run-rustfix
+// The predicates for several params at the end of the `where` clause are a single edit.
+#![allow(dead_code)]
+
+fn duplicate_tup<A, B, C>(t: (A, B), _c: C) -> ((A, B), (A, B)) where C: Copy {
+    (t, t) //~ ERROR use of moved value: `t`
+}
+
+fn main() {}
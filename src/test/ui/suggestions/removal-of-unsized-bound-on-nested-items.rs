@@ -0,0 +1,23 @@
+// Check that the `?Sized` bound is found on the generics of the item which declares the type
+// parameter, when the error comes from an item nested in it or from a generic associated type.
+#![feature(generic_associated_types)]
+
+struct Wrapper<T>(T);
+
+trait Trait {
+    type Assoc<T: ?Sized>;
+}
+
+impl Trait for () {
+    type Assoc<T: ?Sized> = Wrapper<T>;
+    //~^ ERROR the size for values of type `T` cannot be known at compilation time
+}
+
+struct S<T: ?Sized>(Box<T>);
+
+impl<T: ?Sized> S<T> {
+    fn take(self, t: T) {}
+    //~^ ERROR the size for values of type `T` cannot be known at compilation time
+}
+
+fn main() {}
@@ -0,0 +1,20 @@
+// run-rustfix
+// Bounds on the associated types of the type params of an impl are suggested on the impl, as the
+// method of a trait impl can't require more than the trait does.
+#![allow(dead_code)]
+
+trait Run {
+    fn run(&self);
+}
+
+struct Runner<I>(I);
+
+impl<I: Iterator> Run for Runner<I> {
+    fn run(&self) {
+        is_send::<I::Item>(); //~ ERROR E0277
+    }
+}
+
+fn is_send<T: Send>() {}
+
+fn main() {}
@@ -0,0 +1,13 @@
+// run-rustfix
+#![allow(dead_code)]
+
+use std::fmt::Debug;
+
+fn debug<T: Debug + ?Sized>(_: &T) {}
+
+fn use_impl_sized(val: &impl Sized) {
+    //~^ HELP consider further restricting this bound
+    debug(val); //~ ERROR `impl Sized` doesn't implement `Debug`
+}
+
+fn main() {}
@@ -0,0 +1,13 @@
+// Check that `where Self: Sized` is suggested on the trait methods which need `Self` to be sized.
+
+trait Trait {
+    fn by_value(self) {}
+    //~^ ERROR the size for values of type `Self` cannot be known at compilation time
+
+    fn returns_self() -> Self {
+        //~^ ERROR the size for values of type `Self` cannot be known at compilation time
+        loop {}
+    }
+}
+
+fn main() {}
@@ -0,0 +1,40 @@
+// compile-flags: --error-format=json
+// Restricting a type param is only machine-applicable when the users of its item can't break.
+
+#![allow(dead_code)]
+
+pub trait Bound {}
+
+fn needs_bound<T: Bound>(_: T) {}
+
+fn private_fn<T>(t: T) {
+    needs_bound(t);
+    //~^ ERROR the trait bound `T: Bound` is not satisfied
+}
+
+mod private_mod {
+    pub fn not_exported<T>(t: T) {
+        super::needs_bound(t);
+        //~^ ERROR the trait bound `T: Bound` is not satisfied
+    }
+}
+
+pub fn exported<T>(t: T) {
+    needs_bound(t);
+    //~^ ERROR the trait bound `T: Bound` is not satisfied
+}
+
+pub trait Trait {
+    fn method<T>(t: T);
+}
+
+struct Private;
+
+impl Trait for Private {
+    fn method<T>(t: T) {
+        needs_bound(t);
+        //~^ ERROR the trait bound `T: Bound` is not satisfied
+    }
+}
+
+fn main() {}
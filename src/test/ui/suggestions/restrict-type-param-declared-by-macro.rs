@@ -0,0 +1,16 @@
+// The bound can't be added to a type param declared by a macro, so the macro is pointed out.
+
+fn needs_copy<T: Copy>(_: T) {}
+
+macro_rules! make_fn {
+    ($name:ident) => {
+        fn $name<T>(t: T) {
+            needs_copy(t);
+            //~^ ERROR the trait bound `T: Copy` is not satisfied
+        }
+    };
+}
+
+make_fn!(foo);
+
+fn main() {}
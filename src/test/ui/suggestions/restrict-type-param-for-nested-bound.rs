@@ -0,0 +1,27 @@
+// Suggest the bounds on the type params in scope which make a bound on another type hold, as
+// found by the trait solver.
+
+#[derive(Clone)]
+struct Inner<T> {
+    value: T,
+}
+
+struct Outer<T> {
+    inner: Inner<T>,
+}
+
+impl<T> Outer<T>
+where
+    Inner<T>: Clone,
+{
+    fn duplicate(&self) -> Self {
+        Outer { inner: self.inner.clone() }
+    }
+}
+
+fn duplicate<T>(outer: Outer<T>) -> Outer<T> {
+    outer.duplicate()
+    //~^ ERROR the method `duplicate` exists for struct `Outer<T>`, but its trait bounds were not satisfied
+}
+
+fn main() {}
@@ -0,0 +1,10 @@
+// The bound fixing several errors of a function is only suggested for the first one.
+
+fn is_send<T: Send>(val: T) {}
+
+fn use_twice<S>(a: S, b: S) {
+    is_send(a); //~ ERROR `S` cannot be sent between threads safely
+    is_send(b); //~ ERROR `S` cannot be sent between threads safely
+}
+
+fn main() {}
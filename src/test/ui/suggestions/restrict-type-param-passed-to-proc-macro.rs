@@ -0,0 +1,19 @@
+// run-rustfix
+// aux-build:call-site-spans.rs
+// The type param is only resolved in the expansion of the macro, but the bound can still be added
+// where it's written in the input of the macro.
+
+extern crate call_site_spans;
+
+use call_site_spans::call_site_spans;
+
+fn needs_copy<T: Copy>(_: T) {}
+
+call_site_spans! {
+    fn foo<T>(t: T) {
+        needs_copy(t);
+        //~^ ERROR the trait bound `T: Copy` is not satisfied
+    }
+}
+
+fn main() {}
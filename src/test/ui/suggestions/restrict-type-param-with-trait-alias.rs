@@ -0,0 +1,21 @@
+// run-rustfix
+// Bounds which make up a trait alias are suggested as the alias.
+#![feature(trait_alias)]
+#![allow(dead_code)]
+
+trait Trait {}
+trait CopyTrait = Copy + Trait;
+
+struct S<T>(T);
+impl<T: Trait + Clone> Clone for S<T> {
+    fn clone(&self) -> Self {
+        Self(self.0.clone())
+    }
+}
+impl<T: Trait + Copy> Copy for S<T> {}
+
+fn duplicate<T>(t: S<T>) -> (S<T>, S<T>) {
+    (t, t) //~ ERROR use of moved value: `t`
+}
+
+fn main() {}
@@ -0,0 +1,20 @@
+// Check that the binder of a higher-ranked bound is kept in front of the whole predicate when
+// the self type mentions its lifetimes.
+
+trait Trait {}
+
+struct S<T>(T);
+
+impl<'a> Trait for &'a S<u8> {}
+
+fn test<T>()
+where
+    for<'a> &'a T: Trait,
+{
+}
+
+fn caller<T>() {
+    test::<S<T>>(); //~ ERROR the trait bound `for<'a> &'a S<T>: Trait` is not satisfied
+}
+
+fn main() {}
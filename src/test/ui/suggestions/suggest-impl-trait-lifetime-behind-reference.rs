@@ -0,0 +1,18 @@
+// run-rustfix
+
+use std::fmt::Debug;
+
+fn foo(d: &impl Debug) {
+//~^ HELP consider adding an explicit lifetime bound...
+    bar(d);
+//~^ ERROR the parameter type `impl Debug` may not live long enough
+//~| NOTE ...so that the type `impl Debug` will meet its required lifetime bounds
+}
+
+fn bar<T: Debug + 'static>(d: &T) {
+    println!("{:?}", d)
+}
+
+fn main() {
+  foo(&"hi");
+}
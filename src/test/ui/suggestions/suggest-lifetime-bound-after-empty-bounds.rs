@@ -0,0 +1,14 @@
+// run-rustfix
+
+fn foo<T:>(d: T) {
+//~^ HELP consider adding an explicit lifetime bound...
+    bar(d);
+//~^ ERROR the parameter type `T` may not live long enough
+//~| NOTE ...so that the type `T` will meet its required lifetime bounds
+}
+
+fn bar<T: 'static>(_: T) {}
+
+fn main() {
+    foo("hi");
+}
@@ -0,0 +1,19 @@
+// An opaque type nested in the bounds of a type alias is suggested as the path of the associated
+// type it's the value of.
+
+#![feature(type_alias_impl_trait)]
+
+use std::fmt::Debug;
+
+type Foo = impl Iterator<Item = impl Debug>;
+
+fn foo() -> Foo {
+    std::iter::once(1)
+}
+
+fn bar() -> _ {
+    //~^ ERROR the placeholder `_` is not allowed within types on item signatures for return types
+    foo().next().unwrap()
+}
+
+fn main() {}
@@ -0,0 +1,10 @@
+// compile-flags: -Z diagnostic-arg-type-length-limit=2
+// The types given to translated diagnostic messages are cut short after the number of types set
+// by `-Z diagnostic-arg-type-length-limit`, closing the brackets which were still open.
+
+fn foo() -> Option<Vec<Result<u8, u16>>> {
+    1
+    //~^ ERROR mismatched types
+}
+
+fn main() {}
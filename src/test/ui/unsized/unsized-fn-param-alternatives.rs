@@ -0,0 +1,8 @@
+// run-rustfix
+// Removing the `?Sized` bound is suggested first, and it's the only fix applied automatically:
+// borrowing or boxing the argument changes the function for all of its callers.
+#![crate_type = "lib"]
+#![allow(unused)]
+
+pub fn show<T: ?Sized + std::fmt::Debug>(t: T) {}
+//~^ ERROR the size for values of type `T` cannot be known at compilation time
@@ -40,5 +40,8 @@ fn g1<X: ?Sized>(x: X) {}
 fn g2<X: ?Sized + T>(x: X) {}
 //~^ ERROR the size for values of type
 
+fn h<X: ?Sized>() -> X { loop {} }
+//~^ ERROR the size for values of type
+
 pub fn main() {
 }
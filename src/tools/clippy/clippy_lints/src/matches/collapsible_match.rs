@@ -1,4 +1,4 @@
-use clippy_utils::diagnostics::span_lint_and_then;
+use clippy_utils::diagnostics::{lint_arms, span_lint_and_then};
 use clippy_utils::higher::IfLetOrMatch;
 use clippy_utils::visitors::is_local_used;
 use clippy_utils::{is_lang_ctor, is_unit_expr, path_to_local, peel_blocks_with_stmt, peel_ref_operators, SpanlessEq};
@@ -13,8 +13,8 @@
 
 pub(super) fn check_match<'tcx>(cx: &LateContext<'tcx>, arms: &'tcx [Arm<'_>]) {
     if let Some(els_arm) = arms.iter().rfind(|arm| arm_is_wild_like(cx, arm)) {
-        for arm in arms {
-            check_arm(cx, true, arm.pat, arm.body, arm.guard.as_ref(), Some(els_arm.body));
+        for (idx, arm) in arms.iter().enumerate() {
+            check_arm(cx, Some(idx), arm.pat, arm.body, arm.guard.as_ref(), Some(els_arm.body));
         }
     }
 }
@@ -25,12 +25,12 @@ pub(super) fn check_if_let<'tcx>(
     body: &'tcx Expr<'_>,
     else_expr: Option<&'tcx Expr<'_>>,
 ) {
-    check_arm(cx, false, pat, body, None, else_expr);
+    check_arm(cx, None, pat, body, None, else_expr);
 }
 
 fn check_arm<'tcx>(
     cx: &LateContext<'tcx>,
-    outer_is_match: bool,
+    outer_arm: Option<usize>,
     outer_pat: &'tcx Pat<'tcx>,
     outer_then_body: &'tcx Expr<'tcx>,
     outer_guard: Option<&'tcx Guard<'tcx>>,
@@ -84,7 +84,7 @@ fn check_arm<'tcx>(
             let msg = format!(
                 "this `{}` can be collapsed into the outer `{}`",
                 if matches!(inner, IfLetOrMatch::Match(..)) { "match" } else { "if let" },
-                if outer_is_match { "match" } else { "if let" },
+                if outer_arm.is_some() { "match" } else { "if let" },
             );
             span_lint_and_then(
                 cx,
@@ -92,6 +92,9 @@ fn check_arm<'tcx>(
                 inner_expr.span,
                 &msg,
                 |diag| {
+                    if let Some(idx) = outer_arm {
+                        lint_arms(diag, &[idx]);
+                    }
                     let mut help_span = MultiSpan::from_spans(vec![binding_span, inner_then_pat.span]);
                     help_span.push_span_label(binding_span, "replace this binding");
                     help_span.push_span_label(inner_then_pat.span, "with this pattern");
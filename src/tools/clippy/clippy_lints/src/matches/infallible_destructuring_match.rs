@@ -1,3 +1,4 @@
+use clippy_utils::check_proc_macro::is_from_proc_macro;
 use clippy_utils::diagnostics::span_lint_and_sugg;
 use clippy_utils::source::snippet_with_applicability;
 use clippy_utils::{path_to_local_id, peel_blocks, strip_pat_refs};
@@ -12,6 +13,7 @@ pub(crate) fn check(cx: &LateContext<'_>, local: &Local<'_>) -> bool {
         if !local.span.from_expansion();
         if let Some(expr) = local.init;
         if let ExprKind::Match(target, arms, MatchSource::Normal) = expr.kind;
+        if !is_from_proc_macro(cx, expr);
         if arms.len() == 1 && arms[0].guard.is_none();
         if let PatKind::TupleStruct(
             QPath::Resolved(None, variant_name), args, _) = arms[0].pat.kind;
@@ -8,7 +8,7 @@
 
 use super::MATCH_AS_REF;
 
-pub(crate) fn check(cx: &LateContext<'_>, ex: &Expr<'_>, arms: &[Arm<'_>], expr: &Expr<'_>) {
+pub(crate) fn check(cx: &LateContext<'_>, ex: &Expr<'_>, arms: &[Arm<'_>], expr: &Expr<'_>) -> bool {
     if arms.len() == 2 && arms[0].guard.is_none() && arms[1].guard.is_none() {
         let arm_ref: Option<BindingAnnotation> = if is_none_arm(cx, &arms[0]) {
             is_ref_some_arm(cx, &arms[1])
@@ -56,8 +56,10 @@ pub(crate) fn check(cx: &LateContext<'_>, ex: &Expr<'_>, arms: &[Arm<'_>], expr:
                 ),
                 applicability,
             );
+            return true;
         }
     }
+    false
 }
 
 // Checks if arm has the form `None => None`
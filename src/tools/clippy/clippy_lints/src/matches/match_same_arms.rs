@@ -1,4 +1,4 @@
-use clippy_utils::diagnostics::span_lint_and_then;
+use clippy_utils::diagnostics::{lint_arms, span_lint_and_then};
 use clippy_utils::source::snippet;
 use clippy_utils::{path_to_local, search_same, SpanlessEq, SpanlessHash};
 use core::cmp::Ordering;
@@ -110,6 +110,7 @@ pub(super) fn check<'tcx>(cx: &LateContext<'tcx>, arms: &'tcx [Arm<'_>]) {
                 arm1.span,
                 "this match arm has an identical body to the `_` wildcard arm",
                 |diag| {
+                    lint_arms(diag, &[i, j]);
                     diag.span_suggestion(arm1.span, "try removing the arm", "", Applicability::MaybeIncorrect)
                         .help("or try changing either arm body")
                         .span_note(arm2.span, "`_` wildcard arm here");
@@ -129,6 +130,7 @@ pub(super) fn check<'tcx>(cx: &LateContext<'tcx>, arms: &'tcx [Arm<'_>]) {
                 keep_arm.span,
                 "this match arm has an identical body to another arm",
                 |diag| {
+                    lint_arms(diag, &[i, j]);
                     let move_pat_snip = snippet(cx, move_arm.pat.span, "<pat2>");
                     let keep_pat_snip = snippet(cx, keep_arm.pat.span, "<pat1>");
 
@@ -1,4 +1,4 @@
-use clippy_utils::diagnostics::span_lint_and_sugg;
+use clippy_utils::diagnostics::{lint_arms, span_lint_and_then};
 use clippy_utils::ty::is_type_diagnostic_item;
 use rustc_ast::ast::LitKind;
 use rustc_errors::Applicability;
@@ -32,8 +32,8 @@ pub(super) fn check<'tcx>(cx: &LateContext<'tcx>, scrutinee: &'tcx Expr<'_>, arm
             visitor.visit_expr(scrutinee);
 
             if let Some(case_method) = visitor.case_method {
-                if let Some((bad_case_span, bad_case_sym)) = verify_case(&case_method, arms) {
-                    lint(cx, &case_method, bad_case_span, bad_case_sym.as_str());
+                if let Some((bad_case_idx, bad_case_span, bad_case_sym)) = verify_case(&case_method, arms) {
+                    lint(cx, &case_method, bad_case_idx, bad_case_span, bad_case_sym.as_str());
                 }
             }
         }
@@ -79,7 +79,7 @@ fn get_case_method(segment_ident_str: &str) -> Option<CaseMethod> {
     }
 }
 
-fn verify_case<'a>(case_method: &'a CaseMethod, arms: &'a [Arm<'_>]) -> Option<(Span, Symbol)> {
+fn verify_case<'a>(case_method: &'a CaseMethod, arms: &'a [Arm<'_>]) -> Option<(usize, Span, Symbol)> {
     let case_check = match case_method {
         CaseMethod::LowerCase => |input: &str| -> bool { input.chars().all(|c| c.to_lowercase().next() == Some(c)) },
         CaseMethod::AsciiLowerCase => |input: &str| -> bool { !input.chars().any(|c| c.is_ascii_uppercase()) },
@@ -87,7 +87,7 @@ fn verify_case<'a>(case_method: &'a CaseMethod, arms: &'a [Arm<'_>]) -> Option<(
         CaseMethod::AsciiUppercase => |input: &str| -> bool { !input.chars().any(|c| c.is_ascii_lowercase()) },
     };
 
-    for arm in arms {
+    for (idx, arm) in arms.iter().enumerate() {
         if_chain! {
             if let PatKind::Lit(Expr {
                                 kind: ExprKind::Lit(lit),
@@ -97,7 +97,7 @@ fn verify_case<'a>(case_method: &'a CaseMethod, arms: &'a [Arm<'_>]) -> Option<(
             let input = symbol.as_str();
             if !case_check(input);
             then {
-                return Some((lit.span, symbol));
+                return Some((idx, lit.span, symbol));
             }
         }
     }
@@ -105,7 +105,7 @@ fn verify_case<'a>(case_method: &'a CaseMethod, arms: &'a [Arm<'_>]) -> Option<(
     None
 }
 
-fn lint(cx: &LateContext<'_>, case_method: &CaseMethod, bad_case_span: Span, bad_case_str: &str) {
+fn lint(cx: &LateContext<'_>, case_method: &CaseMethod, bad_case_idx: usize, bad_case_span: Span, bad_case_str: &str) {
     let (method_str, suggestion) = match case_method {
         CaseMethod::LowerCase => ("to_lowercase", bad_case_str.to_lowercase()),
         CaseMethod::AsciiLowerCase => ("to_ascii_lowercase", bad_case_str.to_ascii_lowercase()),
@@ -113,13 +113,19 @@ fn lint(cx: &LateContext<'_>, case_method: &CaseMethod, bad_case_span: Span, bad
         CaseMethod::AsciiUppercase => ("to_ascii_uppercase", bad_case_str.to_ascii_uppercase()),
     };
 
-    span_lint_and_sugg(
+    span_lint_and_then(
         cx,
         MATCH_STR_CASE_MISMATCH,
         bad_case_span,
         "this `match` arm has a differing case than its expression",
-        &*format!("consider changing the case of this arm to respect `{}`", method_str),
-        format!("\"{}\"", suggestion),
-        Applicability::MachineApplicable,
+        |diag| {
+            lint_arms(diag, &[bad_case_idx]);
+            diag.span_suggestion(
+                bad_case_span,
+                &*format!("consider changing the case of this arm to respect `{}`", method_str),
+                format!("\"{}\"", suggestion),
+                Applicability::MachineApplicable,
+            );
+        },
     );
 }
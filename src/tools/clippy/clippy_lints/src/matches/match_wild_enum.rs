@@ -1,4 +1,4 @@
-use clippy_utils::diagnostics::span_lint_and_sugg;
+use clippy_utils::diagnostics::{lint_arms, span_lint_and_then};
 use clippy_utils::ty::is_type_diagnostic_item;
 use clippy_utils::{is_refutable, peel_hir_pat_refs, recurse_or_patterns};
 use rustc_errors::Applicability;
@@ -25,20 +25,20 @@ pub(crate) fn check(cx: &LateContext<'_>, ex: &Expr<'_>, arms: &[Arm<'_>]) {
 
     // First pass - check for violation, but don't do much book-keeping because this is hopefully
     // the uncommon case, and the book-keeping is slightly expensive.
-    let mut wildcard_span = None;
+    let mut wildcard = None;
     let mut wildcard_ident = None;
     let mut has_non_wild = false;
-    for arm in arms {
+    for (idx, arm) in arms.iter().enumerate() {
         match peel_hir_pat_refs(arm.pat).0.kind {
-            PatKind::Wild => wildcard_span = Some(arm.pat.span),
+            PatKind::Wild => wildcard = Some((idx, arm.pat.span)),
             PatKind::Binding(_, _, ident, None) => {
-                wildcard_span = Some(arm.pat.span);
+                wildcard = Some((idx, arm.pat.span));
                 wildcard_ident = Some(ident);
             },
             _ => has_non_wild = true,
         }
     }
-    let wildcard_span = match wildcard_span {
+    let (wildcard_idx, wildcard_span) = match wildcard {
         Some(x) if has_non_wild => x,
         _ => return,
     };
@@ -133,14 +133,20 @@ pub(crate) fn check(cx: &LateContext<'_>, ex: &Expr<'_>, arms: &[Arm<'_>]) {
 
     match missing_variants.as_slice() {
         [] => (),
-        [x] if !adt_def.is_variant_list_non_exhaustive() && !has_hidden => span_lint_and_sugg(
+        [x] if !adt_def.is_variant_list_non_exhaustive() && !has_hidden => span_lint_and_then(
             cx,
             MATCH_WILDCARD_FOR_SINGLE_VARIANTS,
             wildcard_span,
             "wildcard matches only a single variant and will also match any future added variants",
-            "try this",
-            format_suggestion(x),
-            Applicability::MaybeIncorrect,
+            |diag| {
+                lint_arms(diag, &[wildcard_idx]);
+                diag.span_suggestion(
+                    wildcard_span,
+                    "try this",
+                    format_suggestion(x),
+                    Applicability::MaybeIncorrect,
+                );
+            },
         ),
         variants => {
             let mut suggestions: Vec<_> = variants.iter().copied().map(format_suggestion).collect();
@@ -151,15 +157,15 @@ pub(crate) fn check(cx: &LateContext<'_>, ex: &Expr<'_>, arms: &[Arm<'_>]) {
                 "wildcard match will also match any future added variants"
             };
 
-            span_lint_and_sugg(
-                cx,
-                WILDCARD_ENUM_MATCH_ARM,
-                wildcard_span,
-                message,
-                "try this",
-                suggestions.join(" | "),
-                Applicability::MaybeIncorrect,
-            );
+            span_lint_and_then(cx, WILDCARD_ENUM_MATCH_ARM, wildcard_span, message, |diag| {
+                lint_arms(diag, &[wildcard_idx]);
+                diag.span_suggestion(
+                    wildcard_span,
+                    "try this",
+                    suggestions.join(" | "),
+                    Applicability::MaybeIncorrect,
+                );
+            });
         },
     };
 }
@@ -1,13 +1,17 @@
-use clippy_utils::source::{snippet_opt, span_starts_with, walk_span_to_context};
-use clippy_utils::{higher, in_constant, meets_msrv, msrvs};
+use clippy_utils::check_proc_macro::{is_from_proc_macro, is_pat_from_proc_macro};
+use clippy_utils::source::{snippet_opt, walk_span_to_context};
+use clippy_utils::{higher, in_constant, is_lint_allowed, meets_msrv, msrvs};
+use rustc_hir::def_id::LOCAL_CRATE;
 use rustc_hir::{Arm, Expr, ExprKind, Local, MatchSource, Pat};
 use rustc_lexer::{tokenize, TokenKind};
-use rustc_lint::{LateContext, LateLintPass, LintContext};
+use rustc_lint::{LateContext, LateLintPass, Lint, LintContext};
 use rustc_middle::lint::in_external_macro;
 use rustc_semver::RustcVersion;
 use rustc_session::{declare_tool_lint, impl_lint_pass};
 use rustc_span::{Span, SpanData, SyntaxContext};
 
+use profile::{timed, Profile};
+
 mod collapsible_match;
 mod infallible_destructuring_match;
 mod manual_map;
@@ -24,6 +28,7 @@
 mod match_wild_err_arm;
 mod needless_match;
 mod overlapping_arms;
+mod profile;
 mod redundant_pattern_match;
 mod rest_pat_in_fully_bound_struct;
 mod significant_drop_in_scrutinee;
@@ -912,6 +917,7 @@
 pub struct Matches {
     msrv: Option<RustcVersion>,
     infallible_destructuring_match_linted: bool,
+    profile: Option<Profile>,
 }
 
 impl Matches {
@@ -919,6 +925,7 @@ impl Matches {
     pub fn new(msrv: Option<RustcVersion>) -> Self {
         Self {
             msrv,
+            profile: Profile::from_env(),
             ..Matches::default()
         }
     }
@@ -953,104 +960,224 @@ pub fn new(msrv: Option<RustcVersion>) -> Self {
 
 impl<'tcx> LateLintPass<'tcx> for Matches {
     fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>) {
+        if let Some(matches_macro) = higher::MatchesMacro::hir(cx, expr) {
+            // The `match` is built by the macro, only the user written pattern can be linted.
+            if !in_external_macro(cx.sess(), matches_macro.span) {
+                timed!(
+                    self.profile,
+                    "wild_in_or_pats",
+                    wild_in_or_pats::check_matches_macro(cx, &matches_macro)
+                );
+            }
+            return;
+        }
         if in_external_macro(cx.sess(), expr.span) {
             return;
         }
         let from_expansion = expr.span.from_expansion();
 
         if let ExprKind::Match(ex, arms, source) = expr.kind {
-            if source == MatchSource::Normal && !span_starts_with(cx, expr.span, "match") {
+            if source == MatchSource::Normal && is_from_proc_macro(cx, expr) {
                 return;
             }
             if matches!(source, MatchSource::Normal | MatchSource::ForLoopDesugar) {
-                significant_drop_in_scrutinee::check(cx, expr, ex, source);
+                timed!(
+                    self.profile,
+                    "significant_drop_in_scrutinee",
+                    significant_drop_in_scrutinee::check(cx, expr, ex, source)
+                );
             }
 
-            collapsible_match::check_match(cx, arms);
+            timed!(
+                self.profile,
+                "collapsible_match",
+                collapsible_match::check_match(cx, arms)
+            );
             if !from_expansion {
                 // These don't depend on a relationship between multiple arms
-                match_wild_err_arm::check(cx, ex, arms);
-                wild_in_or_pats::check(cx, arms);
+                timed!(
+                    self.profile,
+                    "match_wild_err_arm",
+                    match_wild_err_arm::check(cx, ex, arms)
+                );
+                timed!(self.profile, "wild_in_or_pats", wild_in_or_pats::check(cx, arms));
             }
 
             if source == MatchSource::TryDesugar {
-                try_err::check(cx, expr, ex);
+                timed!(self.profile, "try_err", try_err::check(cx, expr, ex));
             }
 
             if !from_expansion && !contains_cfg_arm(cx, expr, ex, arms) {
                 if source == MatchSource::Normal {
                     if !(meets_msrv(self.msrv, msrvs::MATCHES_MACRO)
-                        && match_like_matches::check_match(cx, expr, ex, arms))
+                        && timed!(
+                            self.profile,
+                            "match_like_matches",
+                            match_like_matches::check_match(cx, expr, ex, arms)
+                        ))
                     {
-                        match_same_arms::check(cx, arms);
+                        timed!(self.profile, "match_same_arms", match_same_arms::check(cx, arms));
                     }
 
-                    redundant_pattern_match::check_match(cx, expr, ex, arms);
-                    single_match::check(cx, ex, arms, expr);
-                    match_bool::check(cx, ex, arms, expr);
-                    overlapping_arms::check(cx, ex, arms);
-                    match_wild_enum::check(cx, ex, arms);
-                    match_as_ref::check(cx, ex, arms, expr);
-                    needless_match::check_match(cx, ex, arms, expr);
-                    match_on_vec_items::check(cx, ex);
-                    match_str_case_mismatch::check(cx, ex, arms);
+                    timed!(self.profile, "single_match", single_match::check(cx, ex, arms, expr));
+                    timed!(self.profile, "match_bool", match_bool::check(cx, ex, arms, expr));
+                    timed!(self.profile, "overlapping_arms", overlapping_arms::check(cx, ex, arms));
+                    timed!(self.profile, "match_wild_enum", match_wild_enum::check(cx, ex, arms));
+                    timed!(self.profile, "match_on_vec_items", match_on_vec_items::check(cx, ex));
+                    timed!(
+                        self.profile,
+                        "match_str_case_mismatch",
+                        match_str_case_mismatch::check(cx, ex, arms)
+                    );
 
-                    if !in_constant(cx, expr.hir_id) {
-                        manual_unwrap_or::check(cx, expr, ex, arms);
-                        manual_map::check_match(cx, expr, ex, arms);
+                    // The following lints all replace the whole `match` expression. Only the first one
+                    // which applies gets to emit its suggestion, the edits would overlap otherwise and
+                    // `cargo clippy --fix` would fail to apply them.
+                    let rewritten = linted(cx, NEEDLESS_MATCH, expr, || {
+                        timed!(
+                            self.profile,
+                            "needless_match",
+                            needless_match::check_match(cx, ex, arms, expr)
+                        )
+                    }) || linted(cx, REDUNDANT_PATTERN_MATCHING, expr, || {
+                        timed!(
+                            self.profile,
+                            "redundant_pattern_match",
+                            redundant_pattern_match::check_match(cx, expr, ex, arms)
+                        )
+                    }) || linted(cx, MATCH_AS_REF, expr, || {
+                        timed!(self.profile, "match_as_ref", match_as_ref::check(cx, ex, arms, expr))
+                    });
+
+                    if !rewritten
+                        && !linted(cx, MANUAL_UNWRAP_OR, expr, || {
+                            timed!(
+                                self.profile,
+                                "manual_unwrap_or",
+                                manual_unwrap_or::check(cx, expr, ex, arms, self.msrv)
+                            )
+                        })
+                        // The closure `manual_map` suggests can't be called in a constant context.
+                        && !in_constant(cx, expr.hir_id)
+                    {
+                        timed!(self.profile, "manual_map", manual_map::check_match(cx, expr, ex, arms));
                     }
 
                     if self.infallible_destructuring_match_linted {
                         self.infallible_destructuring_match_linted = false;
                     } else {
-                        match_single_binding::check(cx, ex, arms, expr);
+                        timed!(
+                            self.profile,
+                            "match_single_binding",
+                            match_single_binding::check(cx, ex, arms, expr)
+                        );
                     }
                 }
-                match_ref_pats::check(cx, ex, arms.iter().map(|el| el.pat), expr);
+                timed!(
+                    self.profile,
+                    "match_ref_pats",
+                    match_ref_pats::check(cx, ex, arms.iter().map(|el| el.pat), expr)
+                );
             }
         } else if let Some(if_let) = higher::IfLet::hir(cx, expr) {
-            collapsible_match::check_if_let(cx, if_let.let_pat, if_let.if_then, if_let.if_else);
+            if is_from_proc_macro(cx, expr) {
+                return;
+            }
+            timed!(
+                self.profile,
+                "collapsible_match",
+                collapsible_match::check_if_let(cx, if_let.let_pat, if_let.if_then, if_let.if_else)
+            );
             if !from_expansion {
                 if let Some(else_expr) = if_let.if_else {
                     if meets_msrv(self.msrv, msrvs::MATCHES_MACRO) {
-                        match_like_matches::check_if_let(
-                            cx,
-                            expr,
-                            if_let.let_pat,
-                            if_let.let_expr,
-                            if_let.if_then,
-                            else_expr,
+                        timed!(
+                            self.profile,
+                            "match_like_matches",
+                            match_like_matches::check_if_let(
+                                cx,
+                                expr,
+                                if_let.let_pat,
+                                if_let.let_expr,
+                                if_let.if_then,
+                                else_expr,
+                            )
                         );
                     }
                     if !in_constant(cx, expr.hir_id) {
-                        manual_map::check_if_let(cx, expr, if_let.let_pat, if_let.let_expr, if_let.if_then, else_expr);
+                        timed!(
+                            self.profile,
+                            "manual_map",
+                            manual_map::check_if_let(
+                                cx,
+                                expr,
+                                if_let.let_pat,
+                                if_let.let_expr,
+                                if_let.if_then,
+                                else_expr,
+                            )
+                        );
                     }
                 }
-                redundant_pattern_match::check_if_let(
-                    cx,
-                    expr,
-                    if_let.let_pat,
-                    if_let.let_expr,
-                    if_let.if_else.is_some(),
+                timed!(
+                    self.profile,
+                    "redundant_pattern_match",
+                    redundant_pattern_match::check_if_let(
+                        cx,
+                        expr,
+                        if_let.let_pat,
+                        if_let.let_expr,
+                        if_let.if_else.is_some(),
+                    )
+                );
+                timed!(
+                    self.profile,
+                    "needless_match",
+                    needless_match::check_if_let(cx, expr, &if_let)
                 );
-                needless_match::check_if_let(cx, expr, &if_let);
             }
-        } else if !from_expansion {
-            redundant_pattern_match::check(cx, expr);
+        } else if !from_expansion && !is_from_proc_macro(cx, expr) {
+            timed!(
+                self.profile,
+                "redundant_pattern_match",
+                redundant_pattern_match::check(cx, expr)
+            );
         }
     }
 
     fn check_local(&mut self, cx: &LateContext<'tcx>, local: &'tcx Local<'_>) {
-        self.infallible_destructuring_match_linted |= infallible_destructuring_match::check(cx, local);
+        self.infallible_destructuring_match_linted |= timed!(
+            self.profile,
+            "infallible_destructuring_match",
+            infallible_destructuring_match::check(cx, local)
+        );
     }
 
     fn check_pat(&mut self, cx: &LateContext<'tcx>, pat: &'tcx Pat<'_>) {
-        rest_pat_in_fully_bound_struct::check(cx, pat);
+        if is_pat_from_proc_macro(cx, pat) {
+            return;
+        }
+        timed!(
+            self.profile,
+            "rest_pat_in_fully_bound_struct",
+            rest_pat_in_fully_bound_struct::check(cx, pat)
+        );
+    }
+
+    fn check_crate_post(&mut self, cx: &LateContext<'tcx>) {
+        if let Some(profile) = &self.profile {
+            profile.print(cx.tcx.crate_name(LOCAL_CRATE).as_str());
+        }
     }
 
     extract_msrv_attr!(LateContext);
 }
 
+/// Runs `check` unless `lint` is allowed at `expr`, returning whether it emitted a lint.
+fn linted(cx: &LateContext<'_>, lint: &'static Lint, expr: &Expr<'_>, check: impl FnOnce() -> bool) -> bool {
+    !is_lint_allowed(cx, lint, expr.hir_id) && check()
+}
+
 /// Checks if there are any arms with a `#[cfg(..)]` attribute.
 fn contains_cfg_arm(cx: &LateContext<'_>, e: &Expr<'_>, scrutinee: &Expr<'_>, arms: &[Arm<'_>]) -> bool {
     let Some(scrutinee_span) = walk_span_to_context(scrutinee.span, SyntaxContext::root()) else {
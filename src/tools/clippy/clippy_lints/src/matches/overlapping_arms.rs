@@ -1,5 +1,5 @@
 use clippy_utils::consts::{constant, constant_full_int, miri_to_const, FullInt};
-use clippy_utils::diagnostics::span_lint_and_note;
+use clippy_utils::diagnostics::{lint_arms, span_lint_and_then};
 use core::cmp::Ordering;
 use rustc_hir::{Arm, Expr, PatKind, RangeEnd};
 use rustc_lint::LateContext;
@@ -14,14 +14,14 @@ pub(crate) fn check<'tcx>(cx: &LateContext<'tcx>, ex: &'tcx Expr<'_>, arms: &'tc
         let ranges = all_ranges(cx, arms, cx.typeck_results().expr_ty(ex));
         if !ranges.is_empty() {
             if let Some((start, end)) = overlapping(&ranges) {
-                span_lint_and_note(
-                    cx,
-                    MATCH_OVERLAPPING_ARM,
-                    start.span,
-                    "some ranges overlap",
-                    Some(end.span),
-                    "overlaps with this",
-                );
+                span_lint_and_then(cx, MATCH_OVERLAPPING_ARM, start.span, "some ranges overlap", |diag| {
+                    let overlapping_arms: Vec<_> = [start.span, end.span]
+                        .into_iter()
+                        .filter_map(|span| arms.iter().position(|arm| arm.pat.span == span))
+                        .collect();
+                    lint_arms(diag, &overlapping_arms);
+                    diag.span_note(end.span, "overlaps with this");
+                });
             }
         }
     }
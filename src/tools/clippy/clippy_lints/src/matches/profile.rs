@@ -0,0 +1,53 @@
+use rustc_data_structures::fx::FxHashMap;
+use std::env;
+use std::time::{Duration, Instant};
+
+/// Time spent in each of the sub-lint checks of the `Matches` pass.
+///
+/// Enabled by setting the `CLIPPY_PROFILE_MATCHES` environment variable. The profile is printed to
+/// stderr once the whole crate has been checked.
+#[derive(Default)]
+pub(super) struct Profile {
+    timings: FxHashMap<&'static str, (Duration, u32)>,
+}
+
+impl Profile {
+    pub(super) fn from_env() -> Option<Self> {
+        env::var_os("CLIPPY_PROFILE_MATCHES").map(|_| Self::default())
+    }
+
+    pub(super) fn time<T>(&mut self, name: &'static str, f: impl FnOnce() -> T) -> T {
+        let start = Instant::now();
+        let res = f();
+        let (total, calls) = self.timings.entry(name).or_default();
+        *total += start.elapsed();
+        *calls += 1;
+        res
+    }
+
+    pub(super) fn print(&self, krate: &str) {
+        let mut timings: Vec<_> = self.timings.iter().collect();
+        timings.sort_by(|(a_name, (a, _)), (b_name, (b, _))| b.cmp(a).then_with(|| a_name.cmp(b_name)));
+
+        let total: Duration = timings.iter().map(|(_, (time, _))| *time).sum();
+        eprintln!("clippy::matches profile for `{}` ({:.3}ms total):", krate, as_ms(total));
+        for (name, (time, calls)) in timings {
+            eprintln!("  {:<40} {:>10.3}ms {:>8} calls", name, as_ms(*time), calls);
+        }
+    }
+}
+
+fn as_ms(d: Duration) -> f64 {
+    d.as_secs_f64() * 1000.0
+}
+
+/// Runs `$e`, adding the elapsed time to the entry for `$name` when profiling is enabled.
+macro_rules! timed {
+    ($profile:expr, $name:literal, $e:expr) => {
+        match &mut $profile {
+            Some(profile) => profile.time($name, || $e),
+            None => $e,
+        }
+    };
+}
+pub(super) use timed;
@@ -1,24 +1,40 @@
-use clippy_utils::diagnostics::span_lint_and_help;
+use clippy_utils::diagnostics::{lint_arms, span_lint_and_then};
+use clippy_utils::higher::MatchesMacro;
 use clippy_utils::is_wild;
-use rustc_hir::{Arm, PatKind};
+use rustc_hir::{Arm, Pat, PatKind};
 use rustc_lint::LateContext;
+use rustc_span::Span;
 
 use super::WILDCARD_IN_OR_PATTERNS;
 
 pub(crate) fn check(cx: &LateContext<'_>, arms: &[Arm<'_>]) {
-    for arm in arms {
-        if let PatKind::Or(fields) = arm.pat.kind {
-            // look for multiple fields in this arm that contains at least one Wild pattern
-            if fields.len() > 1 && fields.iter().any(is_wild) {
-                span_lint_and_help(
-                    cx,
-                    WILDCARD_IN_OR_PATTERNS,
-                    arm.pat.span,
-                    "wildcard pattern covers any other pattern as it will match anyway",
-                    None,
-                    "consider handling `_` separately",
-                );
-            }
+    for (idx, arm) in arms.iter().enumerate() {
+        check_pat(cx, arm.pat, arm.pat.span, idx);
+    }
+}
+
+/// Checks the pattern of a `matches!` invocation. The or-pattern itself is built by the macro, so
+/// the lint is emitted on the span covering its alternatives instead.
+pub(crate) fn check_matches_macro(cx: &LateContext<'_>, matches_macro: &MatchesMacro<'_>) {
+    if let PatKind::Or([first, .., last]) = matches_macro.pat.kind {
+        check_pat(cx, matches_macro.pat, first.span.to(last.span), 0);
+    }
+}
+
+fn check_pat(cx: &LateContext<'_>, pat: &Pat<'_>, span: Span, idx: usize) {
+    if let PatKind::Or(fields) = pat.kind {
+        // look for multiple fields in this arm that contains at least one Wild pattern
+        if fields.len() > 1 && fields.iter().any(is_wild) {
+            span_lint_and_then(
+                cx,
+                WILDCARD_IN_OR_PATTERNS,
+                span,
+                "wildcard pattern covers any other pattern as it will match anyway",
+                |diag| {
+                    lint_arms(diag, &[idx]);
+                    diag.help("consider handling `_` separately");
+                },
+            );
         }
     }
 }
@@ -0,0 +1,189 @@
+//! This module handles checking if an expression or pattern was generated by a proc-macro.
+//!
+//! Proc-macros are free to re-use the spans of their input tokens for the code they generate. The
+//! resulting expressions don't have a macro expansion context, so they pass the usual
+//! `from_expansion` checks, while their span points at source text which has nothing to do with
+//! the lowered expression. Linting such code produces suggestions the user can't act on.
+//!
+//! The search is done by checking the leading and trailing text of the span against the text
+//! the node would start and end with if it was written as-is in the source. e.g. a `match`
+//! expression starts with `match` and ends with `}`.
+
+use rustc_ast::ast::LitKind;
+use rustc_hir::{
+    Block, BlockCheckMode, Expr, ExprKind, LoopSource, MatchSource, Pat, PatKind, QPath, UnOp, UnsafeSource,
+};
+use rustc_lint::{LateContext, LintContext};
+use rustc_span::symbol::{kw, Symbol};
+use rustc_span::Span;
+
+/// The type of search pattern to use.
+#[derive(Clone, Copy)]
+enum SearchPat {
+    /// A single string.
+    Str(&'static str),
+    /// The string representation of the symbol.
+    Sym(Symbol),
+    /// Any decimal or hexadecimal digit depending on the location.
+    Num,
+}
+
+/// Checks if the start and the end of the span's text matches the patterns. This will return false
+/// if the span crosses multiple files or if source is not available.
+fn span_matches_pat(cx: &LateContext<'_>, span: Span, start_pat: SearchPat, end_pat: SearchPat) -> bool {
+    let pos = cx.sess().source_map().lookup_byte_offset(span.lo());
+    let Some(ref src) = pos.sf.src else {
+        return false;
+    };
+    let end = span.hi() - pos.sf.start_pos;
+    src.get(pos.pos.0 as usize..end.0 as usize).map_or(false, |s| {
+        // Spans can be wrapped in a mixture of parenthesis, whitespace, and trailing commas.
+        let start_str = s.trim_start_matches(|c: char| c.is_whitespace() || c == '(');
+        let end_str = s.trim_end_matches(|c: char| c.is_whitespace() || c == ')' || c == ',');
+        (match start_pat {
+            SearchPat::Str(text) => start_str.starts_with(text),
+            SearchPat::Sym(sym) => start_str.starts_with(sym.as_str()),
+            SearchPat::Num => start_str.starts_with(|c: char| c.is_ascii_digit()),
+        } && match end_pat {
+            SearchPat::Str(text) => end_str.ends_with(text),
+            SearchPat::Sym(sym) => end_str.ends_with(sym.as_str()),
+            SearchPat::Num => end_str.ends_with(|c: char| c.is_ascii_hexdigit()),
+        })
+    })
+}
+
+/// Get the search patterns to use for the given literal
+fn lit_search_pat(lit: &LitKind) -> (SearchPat, SearchPat) {
+    match lit {
+        LitKind::Str(_, _) => (SearchPat::Str("\""), SearchPat::Str("\"")),
+        LitKind::Char(_) => (SearchPat::Str("'"), SearchPat::Str("'")),
+        LitKind::Int(..) | LitKind::Float(..) => (SearchPat::Num, SearchPat::Str("")),
+        LitKind::Bool(true) => (SearchPat::Str("true"), SearchPat::Str("true")),
+        LitKind::Bool(false) => (SearchPat::Str("false"), SearchPat::Str("false")),
+        _ => (SearchPat::Str(""), SearchPat::Str("")),
+    }
+}
+
+/// Get the search patterns to use for the given path
+fn qpath_search_pat(path: &QPath<'_>) -> (SearchPat, SearchPat) {
+    match path {
+        QPath::Resolved(ty, path) => {
+            let start = if ty.is_some() {
+                SearchPat::Str("<")
+            } else {
+                path.segments.first().map_or(SearchPat::Str(""), |seg| {
+                    if seg.ident.name == kw::PathRoot {
+                        SearchPat::Str("::")
+                    } else {
+                        SearchPat::Sym(seg.ident.name)
+                    }
+                })
+            };
+            let end = path.segments.last().map_or(SearchPat::Str(""), |seg| {
+                if seg.args.is_some() {
+                    SearchPat::Str(">")
+                } else {
+                    SearchPat::Sym(seg.ident.name)
+                }
+            });
+            (start, end)
+        },
+        QPath::TypeRelative(_, name) => (SearchPat::Str(""), SearchPat::Sym(name.ident.name)),
+        QPath::LangItem(..) => (SearchPat::Str(""), SearchPat::Str("")),
+    }
+}
+
+/// Get the search patterns to use for the given expression
+fn expr_search_pat(cx: &LateContext<'_>, e: &Expr<'_>) -> (SearchPat, SearchPat) {
+    match e.kind {
+        ExprKind::Box(e) => (SearchPat::Str("box"), expr_search_pat(cx, e).1),
+        ExprKind::ConstBlock(_) => (SearchPat::Str("const"), SearchPat::Str("}")),
+        ExprKind::Tup([]) => (SearchPat::Str(")"), SearchPat::Str("(")),
+        ExprKind::Unary(UnOp::Deref, e) => (SearchPat::Str("*"), expr_search_pat(cx, e).1),
+        ExprKind::Unary(UnOp::Not, e) => (SearchPat::Str("!"), expr_search_pat(cx, e).1),
+        ExprKind::Unary(UnOp::Neg, e) => (SearchPat::Str("-"), expr_search_pat(cx, e).1),
+        ExprKind::Lit(ref lit) => lit_search_pat(&lit.node),
+        ExprKind::Array(_) | ExprKind::Repeat(..) => (SearchPat::Str("["), SearchPat::Str("]")),
+        ExprKind::Call(e, []) => (expr_search_pat(cx, e).0, SearchPat::Str("(")),
+        ExprKind::MethodCall(_, [e], _) => (expr_search_pat(cx, e).0, SearchPat::Str("(")),
+        ExprKind::Call(first, [.., last])
+        | ExprKind::MethodCall(_, [first, .., last], _)
+        | ExprKind::Binary(_, first, last)
+        | ExprKind::Tup([first, .., last])
+        | ExprKind::Assign(first, last, _)
+        | ExprKind::AssignOp(_, first, last) => (expr_search_pat(cx, first).0, expr_search_pat(cx, last).1),
+        ExprKind::Tup([e]) | ExprKind::DropTemps(e) => expr_search_pat(cx, e),
+        ExprKind::Cast(e, _) | ExprKind::Type(e, _) => (expr_search_pat(cx, e).0, SearchPat::Str("")),
+        ExprKind::Let(let_expr) => (SearchPat::Str("let"), expr_search_pat(cx, let_expr.init).1),
+        ExprKind::If(..) => (SearchPat::Str("if"), SearchPat::Str("}")),
+        ExprKind::Loop(_, Some(_), _, _) | ExprKind::Block(_, Some(_)) => (SearchPat::Str("'"), SearchPat::Str("}")),
+        ExprKind::Loop(_, None, LoopSource::Loop, _) => (SearchPat::Str("loop"), SearchPat::Str("}")),
+        ExprKind::Loop(_, None, LoopSource::While, _) => (SearchPat::Str("while"), SearchPat::Str("}")),
+        ExprKind::Match(_, _, MatchSource::Normal) => (SearchPat::Str("match"), SearchPat::Str("}")),
+        ExprKind::Match(e, _, MatchSource::TryDesugar) => (expr_search_pat(cx, e).0, SearchPat::Str("?")),
+        ExprKind::Match(e, _, MatchSource::AwaitDesugar) => (expr_search_pat(cx, e).0, SearchPat::Str("await")),
+        ExprKind::Closure { body, .. } => (
+            SearchPat::Str(""),
+            expr_search_pat(cx, &cx.tcx.hir().body(body).value).1,
+        ),
+        ExprKind::Block(
+            Block {
+                rules: BlockCheckMode::UnsafeBlock(UnsafeSource::UserProvided),
+                ..
+            },
+            None,
+        ) => (SearchPat::Str("unsafe"), SearchPat::Str("}")),
+        ExprKind::Block(_, None) => (SearchPat::Str("{"), SearchPat::Str("}")),
+        ExprKind::Field(e, name) => (expr_search_pat(cx, e).0, SearchPat::Sym(name.name)),
+        ExprKind::Index(e, _) => (expr_search_pat(cx, e).0, SearchPat::Str("]")),
+        ExprKind::Path(ref path) => qpath_search_pat(path),
+        ExprKind::AddrOf(_, _, e) => (SearchPat::Str("&"), expr_search_pat(cx, e).1),
+        ExprKind::Break(_, None) => (SearchPat::Str("break"), SearchPat::Str("")),
+        ExprKind::Break(_, Some(e)) => (SearchPat::Str("break"), expr_search_pat(cx, e).1),
+        ExprKind::Continue(_) => (SearchPat::Str("continue"), SearchPat::Str("")),
+        ExprKind::Ret(None) => (SearchPat::Str("return"), SearchPat::Str("return")),
+        ExprKind::Ret(Some(e)) => (SearchPat::Str("return"), expr_search_pat(cx, e).1),
+        ExprKind::Struct(path, _, _) => (qpath_search_pat(path).0, SearchPat::Str("}")),
+        _ => (SearchPat::Str(""), SearchPat::Str("")),
+    }
+}
+
+/// Get the search patterns to use for the given pattern
+fn pat_search_pat(pat: &Pat<'_>) -> (SearchPat, SearchPat) {
+    match pat.kind {
+        PatKind::Wild => (SearchPat::Str("_"), SearchPat::Str("_")),
+        PatKind::Struct(ref path, _, _) => (qpath_search_pat(path).0, SearchPat::Str("}")),
+        PatKind::TupleStruct(ref path, _, _) => (qpath_search_pat(path).0, SearchPat::Str(")")),
+        PatKind::Path(ref path) => qpath_search_pat(path),
+        PatKind::Or([first, .., last]) => (pat_search_pat(first).0, pat_search_pat(last).1),
+        PatKind::Slice(..) => (SearchPat::Str("["), SearchPat::Str("]")),
+        PatKind::Box(p) => (SearchPat::Str("box"), pat_search_pat(p).1),
+        PatKind::Ref(p, _) => (SearchPat::Str("&"), pat_search_pat(p).1),
+        _ => (SearchPat::Str(""), SearchPat::Str("")),
+    }
+}
+
+/// Checks if the expression was most likely generated by a proc-macro.
+///
+/// This compares the leading and trailing text of the expression's span with the tokens the
+/// expression kind is written with, e.g. a `match` expression must start with `match` and end with
+/// `}`. Spans from macro expansions aren't considered here, use `from_expansion` for those.
+///
+/// Note that the patterns are those of the source syntax, so desugared expressions are matched
+/// against the syntax they were desugared from (e.g. a `while` loop or the `?` operator).
+pub fn is_from_proc_macro(cx: &LateContext<'_>, e: &Expr<'_>) -> bool {
+    if e.span.from_expansion() {
+        return false;
+    }
+    let (start_pat, end_pat) = expr_search_pat(cx, e);
+    !span_matches_pat(cx, e.span, start_pat, end_pat)
+}
+
+/// Checks if the pattern was most likely generated by a proc-macro. See [`is_from_proc_macro`].
+pub fn is_pat_from_proc_macro(cx: &LateContext<'_>, pat: &Pat<'_>) -> bool {
+    if pat.span.from_expansion() {
+        return false;
+    }
+    let (start_pat, end_pat) = pat_search_pat(pat);
+    !span_matches_pat(cx, pat.span, start_pat, end_pat)
+}
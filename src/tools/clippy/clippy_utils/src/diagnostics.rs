@@ -29,6 +29,26 @@ fn docs_link(diag: &mut Diagnostic, lint: &'static Lint) {
     }
 }
 
+/// Adds the name of the lint and the lowest applicability of the suggestions of `diag` to its JSON
+/// output, so that tools can triage lints without parsing the messages.
+fn lint_metadata(diag: &mut Diagnostic, lint: &'static Lint) {
+    let name = lint.name_lower();
+    diag.set_tool_metadata("lint", &name.strip_prefix("clippy::").unwrap_or(&name));
+    let applicability = diag
+        .suggestions
+        .iter()
+        .flatten()
+        .map(|sugg| sugg.applicability)
+        .max_by_key(|&applicability| applicability as u8);
+    diag.set_tool_metadata("applicability", &applicability);
+}
+
+/// Adds the indices of the match arms a lint is about to the JSON output of `diag`, next to the
+/// data added to every lint emission.
+pub fn lint_arms(diag: &mut Diagnostic, arms: &[usize]) {
+    diag.set_tool_metadata("arms", &arms);
+}
+
 /// Emit a basic lint message with a `msg` and a `span`.
 ///
 /// This is the most primitive of our lint emission methods and can
@@ -50,6 +70,7 @@ pub fn span_lint<T: LintContext>(cx: &T, lint: &'static Lint, sp: impl Into<Mult
     cx.struct_span_lint(lint, sp, |diag| {
         let mut diag = diag.build(msg);
         docs_link(&mut diag, lint);
+        lint_metadata(&mut diag, lint);
         diag.emit();
     });
 }
@@ -90,6 +111,7 @@ pub fn span_lint_and_help<'a, T: LintContext>(
             diag.help(help);
         }
         docs_link(&mut diag, lint);
+        lint_metadata(&mut diag, lint);
         diag.emit();
     });
 }
@@ -133,6 +155,7 @@ pub fn span_lint_and_note<'a, T: LintContext>(
             diag.note(note);
         }
         docs_link(&mut diag, lint);
+        lint_metadata(&mut diag, lint);
         diag.emit();
     });
 }
@@ -151,6 +174,7 @@ pub fn span_lint_and_then<C, S, F>(cx: &C, lint: &'static Lint, sp: S, msg: &str
         let mut diag = diag.build(msg);
         f(&mut diag);
         docs_link(&mut diag, lint);
+        lint_metadata(&mut diag, lint);
         diag.emit();
     });
 }
@@ -165,6 +189,7 @@ pub fn span_lint_hir(
     cx.tcx.struct_span_lint_hir(lint, hir_id, sp, |diag| {
         let mut diag = diag.build(msg);
         docs_link(&mut diag, lint);
+        lint_metadata(&mut diag, lint);
         diag.emit();
     });
 }
@@ -181,6 +206,7 @@ pub fn span_lint_hir_and_then(
         let mut diag = diag.build(msg);
         f(&mut diag);
         docs_link(&mut diag, lint);
+        lint_metadata(&mut diag, lint);
         diag.emit();
     });
 }
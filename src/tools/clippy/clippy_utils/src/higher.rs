@@ -3,6 +3,7 @@
 #![deny(clippy::missing_docs_in_private_items)]
 
 use crate::consts::{constant_simple, Constant};
+use crate::macros::macro_backtrace;
 use crate::ty::is_type_diagnostic_item;
 use crate::{is_expn_of, match_def_path, paths};
 use if_chain::if_chain;
@@ -264,6 +265,55 @@ fn get_field<'c>(name: &str, fields: &'c [hir::ExprField<'_>]) -> Option<&'c hir
     }
 }
 
+/// The arguments of a `matches!` invocation.
+///
+/// `matches!(scrutinee, pat if guard)` expands to
+/// ```rust,ignore
+/// match scrutinee {
+///     pat if guard => true,
+///     _ => false,
+/// }
+/// ```
+pub struct MatchesMacro<'hir> {
+    /// `matches!` scrutinee
+    pub scrutinee: &'hir Expr<'hir>,
+    /// `matches!` pattern, or-patterns are not split up
+    pub pat: &'hir Pat<'hir>,
+    /// `matches!` guard
+    pub guard: Option<&'hir Expr<'hir>>,
+    /// Span of the whole `matches!` call
+    pub span: Span,
+}
+
+impl<'hir> MatchesMacro<'hir> {
+    /// Parses the `match` expression produced by a `matches!` invocation.
+    pub fn hir(cx: &LateContext<'_>, expr: &Expr<'hir>) -> Option<Self> {
+        if_chain! {
+            if let ExprKind::Match(scrutinee, [arm, wild_arm], MatchSource::Normal) = expr.kind;
+            if let Some(macro_call) = macro_backtrace(expr.span).next();
+            if expr.span.ctxt().outer_expn() == macro_call.expn;
+            if cx.tcx.is_diagnostic_item(sym::matches_macro, macro_call.def_id);
+            if let ExprKind::Lit(ref lit) = arm.body.kind;
+            if let ast::LitKind::Bool(true) = lit.node;
+            if let hir::PatKind::Wild = wild_arm.pat.kind;
+            then {
+                let guard = match arm.guard {
+                    Some(hir::Guard::If(guard)) => Some(guard),
+                    Some(hir::Guard::IfLet(_)) => return None,
+                    None => None,
+                };
+                return Some(Self {
+                    scrutinee,
+                    pat: arm.pat,
+                    guard,
+                    span: macro_call.span,
+                });
+            }
+        }
+        None
+    }
+}
+
 /// Represent the pre-expansion arguments of a `vec!` invocation.
 pub enum VecArgs<'a> {
     /// `vec![elem; len]`
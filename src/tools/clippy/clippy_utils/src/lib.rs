@@ -38,6 +38,7 @@
 
 pub mod ast_utils;
 pub mod attrs;
+pub mod check_proc_macro;
 pub mod comparisons;
 pub mod consts;
 pub mod diagnostics;
@@ -100,7 +101,7 @@
 use rustc_span::hygiene::{ExpnKind, MacroKind};
 use rustc_span::source_map::original_sp;
 use rustc_span::sym;
-use rustc_span::symbol::{kw, Symbol};
+use rustc_span::symbol::{kw, Ident, Symbol};
 use rustc_span::{Span, DUMMY_SP};
 use rustc_target::abi::Integer;
 
@@ -231,6 +232,39 @@ pub fn in_constant(cx: &LateContext<'_>, id: HirId) -> bool {
     }
 }
 
+/// Checks if the inherent method `name` of the ADT `ty` can be called at `id`.
+///
+/// Outside of a constant context this is always the case. Inside of one the method has to be a
+/// `const fn` which is either const-stable as of `msrv`, or whose const feature gate is enabled in
+/// the current crate. Use this instead of bailing out on [`in_constant`] when suggesting a method
+/// call.
+pub fn is_method_callable_at(
+    cx: &LateContext<'_>,
+    id: HirId,
+    ty: Ty<'_>,
+    name: &str,
+    msrv: Option<RustcVersion>,
+) -> bool {
+    if !in_constant(cx, id) {
+        return true;
+    }
+    let rustc_ty::Adt(adt, _) = ty.kind() else {
+        return false;
+    };
+    cx.tcx
+        .inherent_impls(adt.did())
+        .iter()
+        .find_map(|&impl_id| {
+            cx.tcx.associated_items(impl_id).find_by_name_and_kind(
+                cx.tcx,
+                Ident::from_str(name),
+                rustc_ty::AssocKind::Fn,
+                impl_id,
+            )
+        })
+        .map_or(false, |assoc| qualify_min_const_fn::is_const_fn(cx.tcx, assoc.def_id, msrv))
+}
+
 /// Checks if a `QPath` resolves to a constructor of a `LangItem`.
 /// For example, use this to check whether a function call or a pattern is `Some(..)`.
 pub fn is_lang_ctor(cx: &LateContext<'_>, qpath: &QPath<'_>, lang_item: LangItem) -> bool {
@@ -350,7 +350,9 @@ fn check_terminator<'a, 'tcx>(
     }
 }
 
-fn is_const_fn(tcx: TyCtxt<'_>, def_id: DefId, msrv: Option<RustcVersion>) -> bool {
+/// Checks if the function is a `const fn` which can be called from a constant context, given the
+/// MSRV and the features enabled in the current crate.
+pub fn is_const_fn(tcx: TyCtxt<'_>, def_id: DefId, msrv: Option<RustcVersion>) -> bool {
     tcx.is_const_fn(def_id)
         && tcx.lookup_const_stability(def_id).map_or(true, |const_stab| {
             if let rustc_attr::StabilityLevel::Stable { since } = const_stab.level {
@@ -364,7 +366,7 @@ fn is_const_fn(tcx: TyCtxt<'_>, def_id: DefId, msrv: Option<RustcVersion>) -> bo
                 )
             } else {
                 // Unstable const fn with the feature enabled.
-                msrv.is_none()
+                msrv.is_none() && tcx.features().active(const_stab.feature)
             }
         })
 }
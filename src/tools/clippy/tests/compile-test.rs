@@ -13,6 +13,7 @@
 use std::fs;
 use std::io;
 use std::path::{Path, PathBuf};
+use std::process::Command;
 use std::sync::LazyLock;
 use test_utils::IS_RUSTC_TEST_SUITE;
 
@@ -179,6 +180,7 @@ fn run_ui() {
     );
     compiletest::run_tests(&config);
     check_rustfix_coverage();
+    compile_rustfix_strict_fixed(&config);
 }
 
 fn run_internal_tests() {
@@ -381,17 +383,160 @@ fn compile_test() {
     "write_with_newline.rs",
 ];
 
+/// Lint modules whose UI tests all have to be checked by rustfix, with the fixed code compiled by
+/// `compile_rustfix_strict_fixed`. Tests exercising one of their lints can only be listed in
+/// `RUSTFIX_COVERAGE_KNOWN_EXCEPTIONS` if they are also listed in `RUSTFIX_STRICT_KNOWN_EXCEPTIONS`.
+const RUSTFIX_STRICT_LINT_MODULES: &[&str] = &["matches"];
+
+/// Tests of the lints of `RUSTFIX_STRICT_LINT_MODULES` that predate the requirement.
+const RUSTFIX_STRICT_KNOWN_EXCEPTIONS: &[&str] = &["match_bool.rs"];
+
+/// Collects the names of the lints of `module`, as registered in the generated
+/// `clippy_lints/src/lib.register_lints.rs`.
+fn module_lint_names(module: &str) -> Vec<String> {
+    let path = Path::new("clippy_lints/src/lib.register_lints.rs");
+    let contents = fs::read_to_string(path).unwrap_or_else(|e| panic!("failed to read `{}`: {}", path.display(), e));
+    let prefix = format!("{}::", module);
+    contents
+        .lines()
+        .filter_map(|line| line.trim().strip_prefix(&prefix)?.strip_suffix(','))
+        .map(str::to_lowercase)
+        .collect()
+}
+
+/// Checks if the test expects `clippy::{lint}` to fire: the lint is enabled by a `warn`, `deny` or
+/// `forbid` attribute of the test file, or it's reported in the `.stderr` file, which covers the
+/// lints that are enabled by default. Lints that are only allowed don't count.
+fn expects_lint(contents: &str, stderr: &str, lint: &str) -> bool {
+    let name = format!("clippy::{}", lint);
+    let enabled_by_attr = ["warn(", "deny(", "forbid("].iter().any(|level| {
+        contents.match_indices(level).any(|(i, _)| {
+            let before = contents[..i].trim_end();
+            (before.ends_with("#[") || before.ends_with("#!["))
+                && contents[i + level.len()..]
+                    .split(')')
+                    .next()
+                    .map_or(false, |lints| lints.split(',').any(|l| l.trim() == name))
+        })
+    });
+    // The `-D clippy::lint-name` notes use dashes, the `#[deny(clippy::lint_name)]` ones underscores.
+    enabled_by_attr
+        || [name.clone(), name.replace('_', "-")].iter().any(|needle| {
+            stderr.match_indices(needle.as_str()).any(|(i, _)| {
+                !stderr[i + needle.len()..].starts_with(|c: char| c.is_ascii_alphanumeric() || c == '_' || c == '-')
+            })
+        })
+}
+
+/// Reads the `.stderr` file of the test, which doesn't exist if nothing is reported.
+fn read_expected_stderr(rs_path: &Path) -> String {
+    fs::read_to_string(rs_path.with_extension("stderr")).unwrap_or_default()
+}
+
+/// Compiles the `.fixed` files of the tests of the lints of `RUSTFIX_STRICT_LINT_MODULES`, failing
+/// if any of the suggestions applied by rustfix doesn't build. Lints are capped to warnings, the
+/// fixed code only has to compile.
+fn compile_rustfix_strict_fixed(config: &compiletest::Config) {
+    let strict_lints: Vec<_> = RUSTFIX_STRICT_LINT_MODULES
+        .iter()
+        .flat_map(|module| module_lint_names(module))
+        .collect();
+    let out_dir = config.build_base.join("rustfix-strict");
+    fs::create_dir_all(&out_dir).unwrap();
+
+    for entry in fs::read_dir(&config.src_base).unwrap() {
+        let rs_path = entry.unwrap().path();
+        let fixed_path = rs_path.with_extension("fixed");
+        if rs_path.extension() != Some(OsStr::new("rs")) || !fixed_path.exists() {
+            continue;
+        }
+        let path_str = rs_path.to_str().unwrap();
+        if !config.filters.is_empty() && !config.filters.iter().any(|filter| path_str.contains(filter.as_str())) {
+            continue;
+        }
+        let contents = fs::read_to_string(&rs_path).unwrap();
+        let stderr = read_expected_stderr(&rs_path);
+        if !strict_lints.iter().any(|lint| expects_lint(&contents, &stderr, lint)) {
+            continue;
+        }
+
+        let rustcflags = config.target_rustcflags.as_deref().unwrap_or_default();
+        let mut cmd = Command::new(&config.rustc_path);
+        cmd.arg(&fixed_path)
+            .args(rustcflags.split_whitespace())
+            .arg("--cap-lints=warn")
+            .arg("--crate-name")
+            .arg(rs_path.file_stem().unwrap())
+            .arg("--out-dir")
+            .arg(&out_dir);
+        let edition = header_value(&contents, "edition").or(config.edition.as_deref());
+        if let Some(edition) = edition {
+            cmd.arg("--edition").arg(edition);
+        }
+        if let Some(flags) = header_value(&contents, "compile-flags") {
+            cmd.args(flags.split_whitespace());
+        }
+        // The auxiliary crates were built by compiletest.
+        let aux_dir = config
+            .build_base
+            .join(rs_path.file_stem().unwrap())
+            .with_extension("aux");
+        if aux_dir.exists() {
+            cmd.arg("-L").arg(aux_dir);
+        }
+        let output = cmd.output().unwrap();
+        assert!(
+            output.status.success(),
+            "`{}` doesn't compile after applying the suggestions of rustfix:\n{}",
+            fixed_path.display(),
+            String::from_utf8_lossy(&output.stderr),
+        );
+    }
+}
+
+/// Gets the value of a `// {name}: value` header of the test file.
+fn header_value<'a>(contents: &'a str, name: &str) -> Option<&'a str> {
+    contents
+        .lines()
+        .take_while(|line| line.starts_with("//"))
+        .find_map(|line| {
+            line.strip_prefix("//")?
+                .trim_start()
+                .strip_prefix(name)?
+                .strip_prefix(':')
+        })
+        .map(str::trim)
+}
+
 fn check_rustfix_coverage() {
     let missing_coverage_path = Path::new("target/debug/test/ui/rustfix_missing_coverage.txt");
 
     if let Ok(missing_coverage_contents) = std::fs::read_to_string(missing_coverage_path) {
         assert!(RUSTFIX_COVERAGE_KNOWN_EXCEPTIONS.iter().is_sorted_by_key(Path::new));
 
+        let strict_lints: Vec<_> = RUSTFIX_STRICT_LINT_MODULES
+            .iter()
+            .flat_map(|module| module_lint_names(module))
+            .collect();
+
         for rs_path in missing_coverage_contents.lines() {
             if Path::new(rs_path).starts_with("tests/ui/crashes") {
                 continue;
             }
             let filename = Path::new(rs_path).strip_prefix("tests/ui/").unwrap();
+            let contents = fs::read_to_string(rs_path).unwrap();
+            let stderr = read_expected_stderr(Path::new(rs_path));
+            if !RUSTFIX_STRICT_KNOWN_EXCEPTIONS.iter().any(|e| Path::new(e) == filename) {
+                if let Some(lint) = strict_lints.iter().find(|lint| expects_lint(&contents, &stderr, lint)) {
+                    panic!(
+                        "`{}` tests `clippy::{}` and runs `MachineApplicable` diagnostics but is missing a \
+                        `run-rustfix` annotation. All suggestions of this lint need to be checked by rustfix, \
+                        please add `// run-rustfix` at the top of the file and move any unfixable cases to a \
+                        separate test.",
+                        rs_path, lint,
+                    );
+                }
+            }
             assert!(
                 RUSTFIX_COVERAGE_KNOWN_EXCEPTIONS
                     .binary_search_by_key(&filename, Path::new)
@@ -423,6 +568,17 @@ fn rustfix_coverage_known_exceptions_accuracy() {
     }
 }
 
+#[test]
+fn rustfix_strict_lint_modules_accuracy() {
+    for module in RUSTFIX_STRICT_LINT_MODULES {
+        assert!(
+            !module_lint_names(module).is_empty(),
+            "no lints found in the `{}` module",
+            module
+        );
+    }
+}
+
 /// Restores an env var on drop
 #[must_use]
 struct VarGuard {
@@ -0,0 +1,21 @@
+// run-rustfix
+#![feature(const_option_ext)]
+#![allow(dead_code)]
+
+// lint, `Option::unwrap_or` is callable in a const fn with `const_option_ext` enabled
+const fn const_fn_option_unwrap_or(x: Option<i32>) -> i32 {
+    match x {
+        Some(i) => i,
+        None => 42,
+    }
+}
+
+// don't lint, `Result::unwrap_or` is gated behind `const_result_drop`
+const fn const_fn_result_unwrap_or(x: Result<i32, i32>) -> i32 {
+    match x {
+        Ok(i) => i,
+        Err(_) => 42,
+    }
+}
+
+fn main() {}
@@ -0,0 +1,13 @@
+// run-rustfix
+#![warn(clippy::needless_match, clippy::manual_map)]
+#![allow(dead_code)]
+
+// Both `needless_match` and `manual_map` apply here, only the former should suggest a fix.
+fn option_match(x: Option<i32>) {
+    let _: Option<i32> = match x {
+        Some(a) => Some(a),
+        None => None,
+    };
+}
+
+fn main() {}
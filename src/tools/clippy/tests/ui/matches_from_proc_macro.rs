@@ -0,0 +1,28 @@
+// aux-build: proc_macro_with_span.rs
+
+#![warn(
+    clippy::redundant_pattern_matching,
+    clippy::match_like_matches_macro,
+    clippy::rest_pat_in_fully_bound_structs
+)]
+
+extern crate proc_macro_with_span;
+use proc_macro_with_span::with_span;
+
+struct S {
+    a: u32,
+}
+
+fn main() {
+    let x = Some(0);
+
+    // Don't lint, the spans don't point at the generated code
+    let _ = with_span!(span if let Some(_) = x { true } else { false });
+    let _ = with_span!(span match x { Some(_) => true, _ => false });
+
+    let mut v = vec![0];
+    with_span!(span while let Some(_) = v.pop() {});
+
+    let s = S { a: 0 };
+    let _ = with_span!(span match s { S { a, .. } => a });
+}
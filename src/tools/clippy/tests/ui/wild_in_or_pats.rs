@@ -33,4 +33,6 @@ fn main() {
             dbg!("matched (bar or) wild");
         },
     };
+    #[allow(unreachable_patterns)]
+    let _ = matches!("foo", "bar" | _);
 }